@@ -44,7 +44,7 @@ impl<'a> Ident<'a> {
     }
 }
 
-impl<'a> std::fmt::Display for Ident<'a> {
+impl std::fmt::Display for Ident<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.fragment())
     }
@@ -58,7 +58,7 @@ impl<'a> From<&'a str> for Ident<'a> {
 
 /// Ident Serializer
 #[cfg(feature = "codec")]
-impl<'a> Serialize for Ident<'a> {
+impl Serialize for Ident<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -227,12 +227,37 @@ impl GetLocation for FunctionName<'_> {
     }
 }
 
-impl<'a> std::fmt::Display for FunctionName<'a> {
+impl std::fmt::Display for FunctionName<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0.fragment())
     }
 }
 
+/// `TypeParameterName` type parameter name element of AST, used for
+/// generic `Function` type parameters declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct TypeParameterName<'a>(#[cfg_attr(feature = "codec", serde(borrow))] Ident<'a>);
+
+impl<'a> TypeParameterName<'a> {
+    #[must_use]
+    pub const fn new(name: Ident<'a>) -> Self {
+        Self(name)
+    }
+}
+
+impl GetLocation for TypeParameterName<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.0.location_line(), self.0.location_offset())
+    }
+}
+
+impl GetName for TypeParameterName<'_> {
+    fn name(&self) -> String {
+        (*self.0.fragment()).to_string()
+    }
+}
+
 /// `ParameterName` parameter name element of AST, used for `Function`
 /// parameters declaration.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -280,6 +305,31 @@ impl GetName for ValueName<'_> {
     }
 }
 
+/// `LoopLabel` loop label name element of AST. Used to name the loop
+/// and to `break`/`continue` outer loop from nested loops.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LoopLabel<'a>(#[cfg_attr(feature = "codec", serde(borrow))] Ident<'a>);
+
+impl<'a> LoopLabel<'a> {
+    #[must_use]
+    pub const fn new(name: Ident<'a>) -> Self {
+        Self(name)
+    }
+}
+
+impl GetLocation for LoopLabel<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.0.location_line(), self.0.location_offset())
+    }
+}
+
+impl GetName for LoopLabel<'_> {
+    fn name(&self) -> String {
+        (*self.0.fragment()).to_string()
+    }
+}
+
 /// `CodeLocation` code location of source for AST elements.
 /// Contains: `line` nad `position`.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -308,6 +358,12 @@ impl CodeLocation {
     }
 }
 
+impl GetLocation for CodeLocation {
+    fn location(&self) -> CodeLocation {
+        self.clone()
+    }
+}
+
 /// `PrimitiveTypes` primitive types elements of AST.
 /// It's represent basic (primitive) types.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -358,6 +414,9 @@ impl GetName for PrimitiveTypes {
 /// Used for expression value declaration. The basic entity is:
 /// - value name of struct type
 /// - value struct type attribute
+/// - nested attributes path, if attribute type is struct (for
+///   example, for `line.start.x` attribute is `start`, and path
+///   is `[x]`)
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 pub struct ExpressionStructValue<'a> {
@@ -366,6 +425,8 @@ pub struct ExpressionStructValue<'a> {
     pub name: ValueName<'a>,
     /// Attribute name of struct typed value
     pub attribute: ValueName<'a>,
+    /// Nested attributes path of struct typed attribute
+    pub path: Vec<ValueName<'a>>,
 }
 
 /// `StructType` struct type basic element used for `StructTypes`.
@@ -406,7 +467,188 @@ impl GetLocation for StructTypes<'_> {
     }
 }
 
-impl<'a> GetName for StructTypes<'a> {
+impl GetName for StructTypes<'_> {
+    fn name(&self) -> String {
+        (*self.name.fragment()).to_string()
+    }
+}
+
+/// `GenericStructTypes` generic struct type element of AST.
+///
+/// Declare struct type with type parameters. Attributes types can
+/// contain type parameters. Concrete struct types are instantiated
+/// by `StructInstanceType`. Const parameters, like `N` in
+/// `Buffer<T, N>`, are array lengths of `ParameterArray` attributes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct GenericStructTypes<'a> {
+    /// Struct type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Type and const parameters
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub type_parameters: Vec<GenericParameter<'a>>,
+    /// Struct type attributes
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub attributes: Vec<StructType<'a>>,
+}
+
+impl GetLocation for GenericStructTypes<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl GetName for GenericStructTypes<'_> {
+    fn name(&self) -> String {
+        (*self.name.fragment()).to_string()
+    }
+}
+
+/// `GenericParameter` parameter of generic struct type: type
+/// parameter `T` or const parameter `N` in `Buffer<T, N>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum GenericParameter<'a> {
+    /// Type parameter: `T`
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Type(TypeParameterName<'a>),
+    /// Const parameter of array length: `N`
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Const(TypeParameterName<'a>),
+}
+
+impl GetLocation for GenericParameter<'_> {
+    fn location(&self) -> CodeLocation {
+        match self {
+            Self::Type(name) | Self::Const(name) => name.location(),
+        }
+    }
+}
+
+impl GetName for GenericParameter<'_> {
+    fn name(&self) -> String {
+        match self {
+            Self::Type(name) | Self::Const(name) => name.name(),
+        }
+    }
+}
+
+/// `GenericArgument` argument of generic struct type instance: type
+/// argument `u8` or const argument `4` in `Buffer<u8, 4>`. Const
+/// argument is primitive value or constant.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum GenericArgument<'a> {
+    /// Type argument
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Type(Type<'a>),
+    /// Const argument
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Const(ConstantValue<'a>),
+}
+
+impl GetName for GenericArgument<'_> {
+    fn name(&self) -> String {
+        match self {
+            Self::Type(ty) => ty.name(),
+            Self::Const(ConstantValue::Constant(name)) => name.name(),
+            Self::Const(ConstantValue::Value(value)) => format!("{value:?}"),
+        }
+    }
+}
+
+/// `StructInstanceType` instance of generic struct type for type
+/// arguments: `Pair<u8, i32>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct StructInstanceType<'a> {
+    /// Generic struct type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Type and const arguments
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub type_arguments: Vec<GenericArgument<'a>>,
+}
+
+impl GetLocation for StructInstanceType<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl GetName for StructInstanceType<'_> {
+    fn name(&self) -> String {
+        format!(
+            "{}<{}>",
+            self.name.fragment(),
+            self.type_arguments
+                .iter()
+                .map(GetName::name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// `EnumVariant` enum type variant element of AST.
+/// Used for `EnumTypes`. Variant without payload is unit variant.
+/// It contains basic elements:
+/// - variant name
+/// - variant payload types
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct EnumVariant<'a> {
+    /// Variant name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Variant payload types
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub payload: Vec<Type<'a>>,
+}
+
+impl GetLocation for EnumVariant<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl GetName for EnumVariant<'_> {
+    fn name(&self) -> String {
+        (*self.name.fragment()).to_string()
+    }
+}
+
+/// `EnumTypes` enum (tagged-union) type element of AST.
+/// The basic entity is:
+/// - enum type name
+/// - enum variants
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct EnumTypes<'a> {
+    /// Enum type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Enum variants
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub variants: Vec<EnumVariant<'a>>,
+}
+
+impl GetLocation for EnumTypes<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl GetName for EnumTypes<'_> {
     fn name(&self) -> String {
         (*self.name.fragment()).to_string()
     }
@@ -416,7 +658,14 @@ impl<'a> GetName for StructTypes<'a> {
 /// Basic entity that represents types. Basic type entity is:
 /// - Primitive types
 /// - Struct types
+/// - Enum types
 /// - Arrays
+/// - Tuples
+/// - Pointers and references
+/// - Type parameters of generic functions
+/// - Instances of generic struct types
+/// - Function pointers
+/// - Arrays with const parameter length
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "codec",
@@ -427,21 +676,98 @@ pub enum Type<'a> {
     Primitive(PrimitiveTypes),
     #[cfg_attr(feature = "codec", serde(borrow))]
     Struct(StructTypes<'a>),
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Enum(EnumTypes<'a>),
     Array(Box<Self>, u32),
-}
-
-impl<'a> GetName for Type<'a> {
+    Tuple(Vec<Self>),
+    /// Raw pointer: `*T`
+    Pointer(Box<Self>),
+    /// Reference: `&T`
+    Reference(Box<Self>),
+    /// Mutable reference: `&mut T`
+    MutableReference(Box<Self>),
+    /// Type parameter of generic function: `T`
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Parameter(TypeParameterName<'a>),
+    /// Instance of generic struct type: `Pair<T, U>`
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    StructInstance(StructInstanceType<'a>),
+    /// Function pointer with parameters types and result type:
+    /// `fn(T, U) -> R`
+    Function(Vec<Self>, Box<Self>),
+    /// Array with const parameter length of generic struct type:
+    /// `[T; N]`
+    ParameterArray(
+        Box<Self>,
+        #[cfg_attr(feature = "codec", serde(borrow))] TypeParameterName<'a>,
+    ),
+}
+
+impl GetName for Type<'_> {
     fn name(&self) -> String {
         match self {
             Self::Primitive(primitive) => primitive.name(),
             Self::Struct(struct_type) => (*struct_type.name.fragment()).to_string(),
+            Self::Enum(enum_type) => (*enum_type.name.fragment()).to_string(),
             Self::Array(array_type, size) => {
                 format!("[{:?};{:?}]", array_type.name(), size)
             }
+            Self::Tuple(types) => format!(
+                "({})",
+                types
+                    .iter()
+                    .map(GetName::name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Pointer(ty) => format!("*{}", ty.name()),
+            Self::Reference(ty) => format!("&{}", ty.name()),
+            Self::MutableReference(ty) => format!("&mut {}", ty.name()),
+            Self::Parameter(name) => name.name(),
+            Self::StructInstance(instance) => instance.name(),
+            Self::Function(parameters, result_type) => format!(
+                "fn({}) -> {}",
+                parameters
+                    .iter()
+                    .map(GetName::name)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                result_type.name()
+            ),
+            Self::ParameterArray(array_type, size) => {
+                format!("[{:?};{}]", array_type.name(), size.name())
+            }
         }
     }
 }
 
+/// `TypeAlias` type alias declaration element of AST: `type Meters = f64;`.
+///
+/// Alias is referred by name as struct type, and transparent for
+/// types comparison.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct TypeAlias<'a> {
+    /// Type alias name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Aliased type
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub alias_type: Type<'a>,
+}
+
+impl GetLocation for TypeAlias<'_> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl GetName for TypeAlias<'_> {
+    fn name(&self) -> String {
+        (*self.name.fragment()).to_string()
+    }
+}
+
 /// `ConstantValue` constant value element of AST.
 /// Used for `ConstantExpression`. Constant value basic entities:
 /// - `constant` it can contain other constant
@@ -459,6 +785,7 @@ pub enum ConstantValue<'a> {
 }
 
 /// `ConstantExpression` constant expression element of AST.
+///
 /// Used to declare constants.
 /// The constant expression value based on other constant or primitive values.
 /// Constant Expression can contain optional expression operation with other
@@ -471,7 +798,7 @@ pub struct ConstantExpression<'a> {
     #[cfg_attr(feature = "codec", serde(borrow))]
     pub value: ConstantValue<'a>,
     /// Constant expression optional expression operation with other constant expression declarations.
-    pub operation: Option<(ExpressionOperations, Box<ConstantExpression<'a>>)>,
+    pub operation: Option<(ExpressionOperations, Box<Self>)>,
 }
 
 /// `Constant` constant declaration element of AST.
@@ -517,13 +844,16 @@ pub struct FunctionParameter<'a> {
 
 /// `FunctionStatement` it's one of the most basic element of AST.
 /// Basic entity of program logic. It contains function declaration and
-/// function body.
+/// function body. Function with type parameters is generic function.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 pub struct FunctionStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
     /// Function name
     #[cfg_attr(feature = "codec", serde(borrow))]
     pub name: FunctionName<'a>,
+    /// Type parameters of generic function
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub type_parameters: Vec<TypeParameterName<'a>>,
     /// Function parameters
     pub parameters: Vec<FunctionParameter<'a>>,
     /// Function result type
@@ -543,12 +873,20 @@ impl<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> FunctionStatem
     ) -> Self {
         Self {
             name,
+            type_parameters: vec![],
             parameters,
             result_type,
             body,
             _marker: PhantomData,
         }
     }
+
+    /// Set type parameters of generic function
+    #[must_use]
+    pub fn with_type_parameters(mut self, type_parameters: Vec<TypeParameterName<'a>>) -> Self {
+        self.type_parameters = type_parameters;
+        self
+    }
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
@@ -624,6 +962,22 @@ impl PrimitiveValue {
 /// - `FunctionCall` - function call (with parameters) of expression
 /// - `StructValue` - value of expression based on `Struct` types.
 /// - `Expression` - expression representation (sub-branch)
+/// - `UnaryOperation` - unary operation for expression
+/// - `Cast` - cast expression to primitive type
+/// - `ArrayValue` - array literal of expressions
+/// - `ArrayElement` - access to array element by index
+/// - `StructLiteral` - struct value construction with named fields
+/// - `MethodCall` - struct method call
+/// - `EnumVariant` - enum variant construction
+/// - `If` - value-yielding if expression
+/// - `Loop` - value-yielding loop expression, value yielded by `break value`
+/// - `TupleValue` - tuple literal of expressions
+/// - `TupleElement` - access to tuple element by constant index
+/// - `AddressOf` - reference to value: `&value` or `&mut value`
+/// - `Dereference` - load value by pointer or reference: `*value`
+/// - `FunctionValue` - function as value: pointer to the function
+/// - `IndirectCall` - call function by function pointer: `callee(parameters)`
+/// - `Closure` - closure, that captures values of enclosing states
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "codec",
@@ -642,6 +996,39 @@ pub enum ExpressionValue<'a, I: SemanticContextInstruction, E: ExtendedExpressio
     StructValue(ExpressionStructValue<'a>),
     /// Expression representation (sub-branch)
     Expression(Box<Expression<'a, I, E>>),
+    /// Unary operation for expression
+    UnaryOperation(ExpressionUnaryOperation<'a, I, E>),
+    /// Cast expression to primitive type
+    Cast(ExpressionCast<'a, I, E>),
+    /// Array literal of expressions
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    ArrayValue(ExpressionArrayValue<'a, I, E>),
+    /// Access to array element by index
+    ArrayElement(ExpressionArrayElement<'a, I, E>),
+    /// Struct value construction with named fields
+    StructLiteral(ExpressionStructLiteral<'a, I, E>),
+    /// Struct method call
+    MethodCall(ExpressionMethodCall<'a, I, E>),
+    /// Enum variant construction
+    EnumVariant(ExpressionEnumVariant<'a, I, E>),
+    /// Value-yielding if expression
+    If(Box<ExpressionIf<'a, I, E>>),
+    /// Value-yielding loop expression
+    Loop(ExpressionLoop<'a, I, E>),
+    /// Tuple literal of expressions
+    TupleValue(ExpressionTupleValue<'a, I, E>),
+    /// Access to tuple element by constant index
+    TupleElement(ExpressionTupleElement<'a, I, E>),
+    /// Reference to value
+    AddressOf(ExpressionAddressOf<'a>),
+    /// Load value by pointer or reference
+    Dereference(ExpressionDereference<'a, I, E>),
+    /// Function as value: pointer to the function
+    FunctionValue(FunctionName<'a>),
+    /// Call function by function pointer
+    IndirectCall(ExpressionIndirectCall<'a, I, E>),
+    /// Closure declaration
+    Closure(Box<ExpressionClosure<'a, I, E>>),
     /// Extended expression
     ExtendedExpression(Box<E>),
     #[cfg_attr(feature = "codec", serde(skip))]
@@ -700,8 +1087,382 @@ impl ExpressionOperations {
     }
 }
 
-/// `Expression` element of AST is basic entity that
-/// represent expression, and optionally expression with optional
+/// `UnaryOperations` unary operation element of AST.
+/// Used for `ExpressionUnaryOperation` as operation over single
+/// expression:
+/// - `Minus` - numeric negation: `-x`
+/// - `Not` - logical negation: `!x`
+/// - `BitwiseNot` - bitwise inversion: `~x`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum UnaryOperations {
+    Minus,
+    Not,
+    BitwiseNot,
+}
+
+/// `ExpressionUnaryOperation` expression unary operation element of AST.
+/// Used for expression value declaration. The basic entity is:
+/// - unary operation
+/// - expression, that operation applied to
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionUnaryOperation<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Unary operation
+    pub operation: UnaryOperations,
+    /// Expression for unary operation
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub expression: Box<Expression<'a, I, E>>,
+}
+
+/// `ExpressionCast` expression cast element of AST.
+/// Used for expression value declaration as `expression as type`.
+/// The basic entity is:
+/// - expression, that should be converted
+/// - primitive type to convert expression to
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionCast<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Expression for cast
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub expression: Box<Expression<'a, I, E>>,
+    /// Type to cast expression to
+    pub cast_type: PrimitiveTypes,
+}
+
+/// `ExpressionArrayValue` expression array literal element of AST.
+/// Used for expression value declaration as `[a, b, c]`.
+/// The basic entity is:
+/// - array elements expressions
+/// - array literal location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionArrayValue<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Array elements expressions
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub values: Vec<Expression<'a, I, E>>,
+    /// Array literal location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionArrayValue<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `ExpressionArrayElement` expression array element element of AST.
+/// Used for expression value declaration as `array[index]`.
+/// The basic entity is:
+/// - expression of array type
+/// - expression of element index
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionArrayElement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Array expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub array: Box<Expression<'a, I, E>>,
+    /// Array element index expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub index: Box<Expression<'a, I, E>>,
+}
+
+/// `ExpressionTupleValue` expression tuple literal element of AST.
+/// Used for expression value declaration as `(a, b, c)`.
+/// The basic entity is:
+/// - tuple elements expressions
+/// - tuple literal location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionTupleValue<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Tuple elements expressions
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub values: Vec<Expression<'a, I, E>>,
+    /// Tuple literal location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionTupleValue<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `ExpressionTupleElement` expression tuple element element of AST.
+/// Used for expression value declaration as `tuple.0`.
+/// The basic entity is:
+/// - expression of tuple type
+/// - constant element index
+/// - element access location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionTupleElement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Tuple expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub tuple: Box<Expression<'a, I, E>>,
+    /// Tuple element index
+    pub index: u32,
+    /// Element access location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionTupleElement<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `ExpressionAddressOf` expression address-of element of AST.
+/// Used for expression value declaration as `&value` or
+/// `&mut value`. The basic entity is:
+/// - value name
+/// - mutability flag of reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionAddressOf<'a> {
+    /// Referenced value name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub value: ValueName<'a>,
+    /// Mutable reference flag
+    pub mutable: bool,
+}
+
+impl GetLocation for ExpressionAddressOf<'_> {
+    fn location(&self) -> CodeLocation {
+        self.value.location()
+    }
+}
+
+/// `ExpressionDereference` expression dereference element of AST.
+/// Used for expression value declaration as `*value`.
+/// The basic entity is:
+/// - expression of pointer or reference type
+/// - dereference location
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionDereference<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Pointer expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub expression: Box<Expression<'a, I, E>>,
+    /// Dereference location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionDereference<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `ExpressionIndirectCall` expression indirect call element of AST.
+/// Used for expression value declaration as `callee(parameters)`.
+/// The basic entity is:
+/// - expression of function pointer type
+/// - function parameters expressions
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionIndirectCall<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Function pointer expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub callee: Box<Expression<'a, I, E>>,
+    /// Function parameters, represented through expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub parameters: Vec<Expression<'a, I, E>>,
+}
+
+/// `ExpressionClosure` expression closure element of AST.
+/// Used for expression value declaration as `|parameters| -> result_type { body }`.
+///
+/// Closure body can use values of enclosing states, that captured
+/// by closure. Closure is called by `ExpressionIndirectCall`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionClosure<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Closure parameters
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub parameters: Vec<FunctionParameter<'a>>,
+    /// Closure result type
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub result_type: Type<'a>,
+    /// Closure body statements
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<BodyStatement<'a, I, E>>,
+    /// Closure location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionClosure<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `StructLiteralField` struct literal field element of AST.
+/// Used for `ExpressionStructLiteral` as named field initialization.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct StructLiteralField<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Struct attribute name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: ValueName<'a>,
+    /// Struct attribute value expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub value: Expression<'a, I, E>,
+}
+
+/// `ExpressionStructLiteral` expression struct literal element of AST.
+/// Used for expression value declaration as `Type { field: value }`.
+/// The basic entity is:
+/// - struct type name
+/// - struct fields initialization
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionStructLiteral<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Struct type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Struct fields initialization
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub fields: Vec<StructLiteralField<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionStructLiteral<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+/// `ExpressionEnumVariant` expression enum variant element of AST.
+/// Used for expression value declaration as `Type::Variant(payload)`.
+/// The basic entity is:
+/// - enum type name
+/// - variant name
+/// - variant payload values, empty for unit variant
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionEnumVariant<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Enum type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Enum variant name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub variant: Ident<'a>,
+    /// Variant payload values
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub payload: Vec<Expression<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionEnumVariant<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+/// `ExpressionBranch` value-yielding branch element of AST.
+/// Branch body statements followed by the branch result value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionBranch<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Branch body statements
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<IfBodyStatement<'a, I, E>>,
+    /// Branch result value
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub value: Box<Expression<'a, I, E>>,
+}
+
+/// `ExpressionIf` expression if element of AST.
+/// Used for expression value declaration as
+/// `if condition { value } else { value }`.
+/// Contains entities:
+/// - if condition
+/// - if branch
+/// - else branch, required as both branches should yield value.
+///   Else-if is if expression in the else branch value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionIf<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// If condition
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub condition: IfCondition<'a, I, E>,
+    /// If branch
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: ExpressionBranch<'a, I, E>,
+    /// Else branch
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub else_body: ExpressionBranch<'a, I, E>,
+}
+
+/// `ExpressionLoop` expression loop element of AST.
+/// Used for expression value declaration as `loop { body }`, value
+/// yielded by `break value`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionLoop<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Loop body statements
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<LoopBodyStatement<'a, I, E>>,
+    /// Loop location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionLoop<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `ExpressionMethodCall` expression method call element of AST.
+/// Used for expression value declaration as `value.method(args)`.
+/// The basic entity is:
+/// - receiver expression of struct type
+/// - method name
+/// - method parameters, except receiver
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionMethodCall<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Receiver expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub receiver: Box<Expression<'a, I, E>>,
+    /// Method name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: FunctionName<'a>,
+    /// Method parameters
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub parameters: Vec<Expression<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ExpressionMethodCall<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.name.location()
+    }
+}
+
+/// `Expression` element of AST.
+///
+/// Basic entity that represent expression, and optionally expression with optional
 /// operations with other expression. So it can be expression tree
 /// with expression operations.
 #[derive(Debug, Clone, PartialEq)]
@@ -711,7 +1472,7 @@ pub struct Expression<'a, I: SemanticContextInstruction, E: ExtendedExpression<I
     #[cfg_attr(feature = "codec", serde(borrow))]
     pub expression_value: ExpressionValue<'a, I, E>,
     /// Optional expression operation with other expression value
-    pub operation: Option<(ExpressionOperations, Box<Expression<'a, I, E>>)>,
+    pub operation: Option<(ExpressionOperations, Box<Self>)>,
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation for Expression<'_, I, E> {
@@ -749,15 +1510,81 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetName for LetBin
     }
 }
 
+/// `LetTupleName` value name element of `LetTupleBinding` pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LetTupleName<'a> {
+    /// Value name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: ValueName<'a>,
+    /// Mutability flag of binding
+    pub mutable: bool,
+}
+
+/// `LetTupleBinding` let binding with tuple pattern element of AST.
+///
+/// Destructure tuple value to the values: `let (a, mut b) = value`.
+/// Each tuple element is bound to the value of the pattern with the
+/// same index.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize), serde(tag = "type"))]
+pub struct LetTupleBinding<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Value names of tuple pattern
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub names: Vec<LetTupleName<'a>>,
+    /// Optional type of tuple value
+    pub value_type: Option<Type<'a>>,
+    /// Tuple value expression to destructure
+    pub value: Box<Expression<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for LetTupleBinding<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.names
+            .first()
+            .map_or_else(|| self.value.location(), |name| name.name.location())
+    }
+}
+
+/// `PlaceAccess` place access element of AST. Used for `Binding`
+/// target as access path from the binding value:
+/// - `Field` - access to struct field: `value.field`
+/// - `Index` - access to array element: `value[index]`
+/// - `Deref` - access to pointer or reference target: `*value`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum PlaceAccess<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Access to struct field
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    Field(ValueName<'a>),
+    /// Access to array element by index
+    Index(Box<Expression<'a, I, E>>),
+    /// Access to pointer or reference target
+    Deref,
+}
+
 /// `Binding` binding element of AST. Basic entity
 /// for `values` re-declaration, to bind new values for already
 /// declared values.
+///
+/// Binding target is place: value itself, or
+/// value fields and elements by access path (for example,
+/// `value.field[index]`).
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 pub struct Binding<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
     /// Binding value name
     #[cfg_attr(feature = "codec", serde(borrow))]
     pub name: ValueName<'a>,
+    /// Access path to binding target. Empty for value itself
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub path: Vec<PlaceAccess<'a, I, E>>,
     /// Value expression as result of binding
     pub value: Box<Expression<'a, I, E>>,
 }
@@ -860,7 +1687,7 @@ pub struct ExpressionLogicCondition<'a, I: SemanticContextInstruction, E: Extend
     #[cfg_attr(feature = "codec", serde(borrow))]
     pub left: ExpressionCondition<'a, I, E>,
     /// Optional right side contain logic operation to other `ExpressionLogicCondition`
-    pub right: Option<(LogicCondition, Box<ExpressionLogicCondition<'a, I, E>>)>,
+    pub right: Option<(LogicCondition, Box<Self>)>,
 }
 
 /// `IfCondition` if-condition control flow element of AST.
@@ -898,7 +1725,7 @@ pub struct IfStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<
     /// If-else-body statement - body of else-condition success
     pub else_statement: Option<IfBodyStatements<'a, I, E>>,
     /// Else-if-body statement - body of else-if-condition success
-    pub else_if_statement: Option<Box<IfStatement<'a, I, E>>>,
+    pub else_if_statement: Option<Box<Self>>,
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
@@ -910,6 +1737,153 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
     }
 }
 
+/// `WhileStatement` while statement AST element.
+/// Loop with condition, that checked before each loop step.
+/// Contains entities:
+/// - optional loop label
+/// - loop condition
+/// - loop body
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct WhileStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Loop label
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub label: Option<LoopLabel<'a>>,
+    /// Loop condition
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub condition: IfCondition<'a, I, E>,
+    /// Loop body
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<LoopBodyStatement<'a, I, E>>,
+}
+
+/// `LabeledLoopStatement` labeled loop statement AST element.
+/// Loop with label name, that can be used for `break` and
+/// `continue` of the loop from nested loops.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LabeledLoopStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Loop label
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub label: LoopLabel<'a>,
+    /// Loop body
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<LoopBodyStatement<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for LabeledLoopStatement<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.label.location()
+    }
+}
+
+/// `ForStatement` for statement AST element.
+/// Counted loop over integer range `start..end` with optional step.
+/// Contains entities:
+/// - optional loop label
+/// - induction value name
+/// - range start and end
+/// - range step, default is 1
+/// - loop body
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ForStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Loop label
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub label: Option<LoopLabel<'a>>,
+    /// Induction value name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: ValueName<'a>,
+    /// Range start
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub start: Expression<'a, I, E>,
+    /// Range end, excluded from range
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub end: Expression<'a, I, E>,
+    /// Range step, should be a non-zero constant. Negative step
+    /// counts down
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub step: Option<Expression<'a, I, E>>,
+    /// Loop body
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: Vec<LoopBodyStatement<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for ForStatement<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.name.location()
+    }
+}
+
+/// `MatchPattern` match arm pattern element of AST.
+/// Match arm patterns:
+/// - `Value` - primitive literal value
+/// - `EnumVariant` - enum variant name of enum type value
+/// - `Wildcard` - any value
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum MatchPattern<'a> {
+    /// Primitive literal value
+    Value(PrimitiveValue),
+    /// Enum variant name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    EnumVariant(Ident<'a>),
+    /// Wildcard pattern
+    Wildcard,
+}
+
+/// `MatchArm` match statement arm element of AST.
+/// Contains arm pattern, arm body and arm pattern location.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct MatchArm<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Arm pattern
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub pattern: MatchPattern<'a>,
+    /// Arm body statement
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub body: IfBodyStatements<'a, I, E>,
+    /// Arm pattern location
+    pub location: CodeLocation,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation for MatchArm<'_, I, E> {
+    fn location(&self) -> CodeLocation {
+        self.location.clone()
+    }
+}
+
+/// `MatchStatement` match statement AST element.
+/// Multi-way branch by the value. Contains entities:
+/// - match value expression
+/// - match arms
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct MatchStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Match value expression
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub value: Expression<'a, I, E>,
+    /// Match arms
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub arms: Vec<MatchArm<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation
+    for MatchStatement<'_, I, E>
+{
+    fn location(&self) -> CodeLocation {
+        self.value.location()
+    }
+}
+
 /// `BodyStatement` one of the basic AST elements.
 /// It's part of Function body.
 #[derive(Debug, Clone, PartialEq)]
@@ -922,14 +1896,26 @@ pub enum BodyStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<
     /// Let-binding function declaration
     #[cfg_attr(feature = "codec", serde(borrow))]
     LetBinding(LetBinding<'a, I, E>),
+    /// Let-binding with tuple pattern
+    LetTupleBinding(LetTupleBinding<'a, I, E>),
     /// Binding function declaration
     Binding(Binding<'a, I, E>),
     /// Function call
     FunctionCall(FunctionCall<'a, I, E>),
     /// If-condition control flow statement
-    If(IfStatement<'a, I, E>),
+    If(Box<IfStatement<'a, I, E>>),
     /// Loop control flow statement
     Loop(Vec<LoopBodyStatement<'a, I, E>>),
+    /// Labeled loop control flow statement
+    LabeledLoop(LabeledLoopStatement<'a, I, E>),
+    /// While loop control flow statement
+    While(Box<WhileStatement<'a, I, E>>),
+    /// For loop control flow statement
+    For(Box<ForStatement<'a, I, E>>),
+    /// Match control flow statement
+    Match(MatchStatement<'a, I, E>),
+    /// Nested block statement with own scope
+    Block(Vec<Self>),
     /// Expression statement
     Expression(Expression<'a, I, E>),
     /// Return statement
@@ -947,28 +1933,46 @@ pub enum BodyStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<
 pub enum IfBodyStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
     #[cfg_attr(feature = "codec", serde(borrow))]
     LetBinding(LetBinding<'a, I, E>),
+    LetTupleBinding(LetTupleBinding<'a, I, E>),
     Binding(Binding<'a, I, E>),
     FunctionCall(FunctionCall<'a, I, E>),
-    If(IfStatement<'a, I, E>),
+    If(Box<IfStatement<'a, I, E>>),
     Loop(Vec<LoopBodyStatement<'a, I, E>>),
+    LabeledLoop(LabeledLoopStatement<'a, I, E>),
+    While(Box<WhileStatement<'a, I, E>>),
+    For(Box<ForStatement<'a, I, E>>),
+    Match(MatchStatement<'a, I, E>),
+    Block(Vec<Self>),
     Return(Expression<'a, I, E>),
 }
 
 /// `IfLoopBodyStatement` statement of loop-if-body elements tree of AST.
 /// Used as body statement of If-control flow in the `Loop` AST element.
+///
+/// `BreakAt` is `break` with source location for errors reporting.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "codec", serde(tag = "type", content = "content"))]
 pub enum IfLoopBodyStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
     #[cfg_attr(feature = "codec", serde(borrow))]
     LetBinding(LetBinding<'a, I, E>),
+    LetTupleBinding(LetTupleBinding<'a, I, E>),
     Binding(Binding<'a, I, E>),
     FunctionCall(FunctionCall<'a, I, E>),
-    If(IfStatement<'a, I, E>),
+    If(Box<IfStatement<'a, I, E>>),
     Loop(Vec<LoopBodyStatement<'a, I, E>>),
+    LabeledLoop(LabeledLoopStatement<'a, I, E>),
+    While(Box<WhileStatement<'a, I, E>>),
+    For(Box<ForStatement<'a, I, E>>),
+    Match(MatchStatement<'a, I, E>),
+    Block(Vec<Self>),
     Return(Expression<'a, I, E>),
     Break,
     Continue,
+    BreakTo(LoopLabel<'a>),
+    ContinueTo(LoopLabel<'a>),
+    BreakValue(Expression<'a, I, E>),
+    BreakAt(CodeLocation),
 }
 
 /// `IfBodyStatements` set of elements in the AST, that represents
@@ -987,6 +1991,8 @@ pub enum IfBodyStatements<'a, I: SemanticContextInstruction, E: ExtendedExpressi
 
 /// `LoopBodyStatement` statement of loop-body elements tree of AST.
 /// Used as body statement of loop-control flow.
+///
+/// `BreakAt` is `break` with source location for errors reporting.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "codec",
@@ -996,13 +2002,50 @@ pub enum IfBodyStatements<'a, I: SemanticContextInstruction, E: ExtendedExpressi
 pub enum LoopBodyStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
     #[cfg_attr(feature = "codec", serde(borrow))]
     LetBinding(LetBinding<'a, I, E>),
+    LetTupleBinding(LetTupleBinding<'a, I, E>),
     Binding(Binding<'a, I, E>),
     FunctionCall(FunctionCall<'a, I, E>),
-    If(IfStatement<'a, I, E>),
-    Loop(Vec<LoopBodyStatement<'a, I, E>>),
+    If(Box<IfStatement<'a, I, E>>),
+    Loop(Vec<Self>),
+    LabeledLoop(LabeledLoopStatement<'a, I, E>),
+    While(Box<WhileStatement<'a, I, E>>),
+    For(Box<ForStatement<'a, I, E>>),
+    Match(MatchStatement<'a, I, E>),
+    Block(Vec<Self>),
     Return(Expression<'a, I, E>),
     Break,
     Continue,
+    BreakTo(LoopLabel<'a>),
+    ContinueTo(LoopLabel<'a>),
+    BreakValue(Expression<'a, I, E>),
+    BreakAt(CodeLocation),
+}
+
+/// `StructImpl` struct methods declaration element of AST.
+/// Declare methods for the struct type (`impl`-like declaration).
+/// Method is function, that first parameter is receiver with the
+/// struct type.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct StructImpl<'a, I: SemanticContextInstruction, E: ExtendedExpression<I>> {
+    /// Struct type name
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub name: Ident<'a>,
+    /// Struct methods
+    #[cfg_attr(feature = "codec", serde(borrow))]
+    pub methods: Vec<FunctionStatement<'a, I, E>>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetLocation for StructImpl<'_, I, E> {
+    fn location(&self) -> CodeLocation {
+        CodeLocation::new(self.name.location_line(), self.name.location_offset())
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> GetName for StructImpl<'_, I, E> {
+    fn name(&self) -> String {
+        (*self.name.fragment()).to_string()
+    }
 }
 
 /// `MainStatement` main AST statement for all elements.
@@ -1020,8 +2063,16 @@ pub enum MainStatement<'a, I: SemanticContextInstruction, E: ExtendedExpression<
     Constant(Constant<'a>),
     /// Type declaration
     Types(StructTypes<'a>),
+    /// Generic struct type declaration
+    GenericTypes(GenericStructTypes<'a>),
+    /// Type alias declaration
+    TypeAlias(TypeAlias<'a>),
+    /// Enum type declaration
+    Enum(EnumTypes<'a>),
     /// Function declaration and function body-statement
     Function(FunctionStatement<'a, I, E>),
+    /// Struct methods declaration
+    Impl(StructImpl<'a, I, E>),
 }
 
 /// # Main
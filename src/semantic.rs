@@ -11,26 +11,39 @@
 //! - `Errors` - semantic analyzes errors.z
 
 use crate::ast::{self, CodeLocation, GetLocation, GetName, MAX_PRIORITY_LEVEL_FOR_EXPRESSIONS};
-use crate::types::block_state::BlockState;
+use crate::types::alloca::AllocaValues;
+use crate::types::block_state::{BlockState, LoopLabels};
+use crate::types::condition::{Condition, ConditionTruthiness, MatchPattern};
 use crate::types::expression::{
-    Expression, ExpressionResult, ExpressionResultValue, ExpressionStructValue,
+    Expression, ExpressionAddressOf, ExpressionIndirectCall, ExpressionOperations,
+    ExpressionResult, ExpressionResultValue, ExpressionStructValue,
 };
 use crate::types::semantic::{
     ExtendedExpression, GlobalSemanticContext, SemanticContext, SemanticContextInstruction,
-    SemanticStack,
+    SemanticStack, SemanticStackContext,
+};
+use crate::types::types::{
+    EnumTypes, GenericArgument, GenericParameter, GenericStructTypes, PrimitiveTypes,
+    StructAttributeType, StructInstanceType, StructTypes, Type, TypeAttributes, TypeClass,
+    TypeName, TypeParameterName,
 };
-use crate::types::types::{Type, TypeName};
 use crate::types::{
-    error, Binding, Constant, ConstantName, Function, FunctionCall, FunctionName,
-    FunctionParameter, FunctionStatement, InnerValueName, LabelName, LetBinding, Value,
+    error, Binding, Closure, ClosureCapture, Constant, ConstantName, ConstantValue, Function,
+    FunctionCall, FunctionInstantiation, FunctionName, FunctionParameter, FunctionStatement,
+    GenericFunction, InnerValueName, LabelName, LetBinding, LetTupleBinding, PlaceIndex,
+    PrimitiveValue, Value, ValueName,
 };
 #[cfg(feature = "codec")]
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::rc::Rc;
 
+/// Max depth of generic functions instantiations, when generic
+/// function instantiation requested from other instantiation body.
+pub const MAX_INSTANTIATION_DEPTH: usize = 64;
+
 /// # Global State
 /// Global state can contains state declarations of:
 /// - Constants
@@ -49,8 +62,17 @@ pub struct GlobalState<I: SemanticContextInstruction> {
     pub constants: HashMap<ConstantName, Constant>,
     /// Types declarations
     pub types: HashMap<TypeName, Type>,
+    /// Generic struct types declarations
+    pub generic_types: HashMap<TypeName, GenericStructTypes>,
     /// Functions declarations
     pub functions: HashMap<FunctionName, Function>,
+    /// Generic functions declarations
+    pub generic_functions: HashMap<FunctionName, GenericFunction>,
+    /// Concrete instantiations of generic functions by instantiated
+    /// function name
+    pub instantiations: HashMap<FunctionName, FunctionInstantiation>,
+    /// Closures declarations
+    pub closures: HashMap<FunctionName, Closure>,
     /// Context as Semantic Stack Context results contains basic semantic
     /// result tree for Global context state.
     pub context: SemanticStack<I>,
@@ -62,6 +84,7 @@ pub struct GlobalState<I: SemanticContextInstruction> {
 ///   most important - context results of Semantic State stack, that can be
 ///   used for post-verification and/or Codegen.
 /// - `Context` stack for `Block state` of each functions body state
+/// - `Closure context` stack for `Block state` of each closures body state
 /// - `Error State` contains errors stack as result of Semantic analyzer
 #[derive(Debug)]
 #[cfg_attr(feature = "codec", derive(Serialize))]
@@ -75,8 +98,49 @@ where
     /// Context for all `Block State` stack that related to concrete functions body.
     #[cfg_attr(feature = "codec", serde(skip))]
     pub context: Vec<Rc<RefCell<BlockState<I>>>>,
+    /// Context for closures body states, in the same order as closures
+    /// declarations in `Global State` context.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    pub closure_context: Vec<Rc<RefCell<BlockState<I>>>>,
     /// Error state results stack
     pub errors: Vec<error::StateErrorResult>,
+    /// Truthiness policy for single expression if-condition.
+    /// By default only `bool` is accepted.
+    pub condition_truthiness: ConditionTruthiness,
+    /// Short-circuit semantics for logic conditions: right part of
+    /// logic condition calculated only when it's required.
+    /// By default both parts of logic conditions are calculated.
+    pub logic_condition_short_circuit: bool,
+    /// Struct methods names declared in `impl` blocks. Methods are
+    /// part of the struct type, so they are collected before types.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    struct_methods: HashMap<TypeName, HashMap<String, FunctionName>>,
+    /// Declared types, that inner types aren't resolved yet, with
+    /// locations of declaration elements: enum variants or type alias.
+    /// Types are declared before inner types resolved, so types can
+    /// refer to types declared later.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    pending_types: HashMap<TypeName, Vec<CodeLocation>>,
+    /// Type aliases, that aliased types currently resolved, in
+    /// resolution order with aliases locations. Used to detect
+    /// cyclic type aliases.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    resolving_aliases: Vec<(TypeName, CodeLocation)>,
+    /// Type arguments of type parameters for generic function, that
+    /// currently analyzed.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    type_arguments: HashMap<TypeParameterName, Type>,
+    /// Generic functions instantiations, that body not analyzed yet,
+    /// with instantiation depth and instantiation location.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    pending_instantiations: Vec<(FunctionName, usize, CodeLocation)>,
+    /// Instantiation depth of generic function, that currently analyzed.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    instantiation_depth: usize,
+    /// Values of the function, that currently analyzed, that should be
+    /// allocated in memory.
+    #[cfg_attr(feature = "codec", serde(skip))]
+    alloca_values: AllocaValues,
     phantom: PhantomData<E>,
 }
 
@@ -101,12 +165,26 @@ where
         Self {
             global: GlobalState {
                 functions: HashMap::new(),
+                generic_functions: HashMap::new(),
+                instantiations: HashMap::new(),
+                closures: HashMap::new(),
                 types: HashMap::new(),
+                generic_types: HashMap::new(),
                 constants: HashMap::new(),
                 context: SemanticStack::new(),
             },
             context: Vec::new(),
+            closure_context: Vec::new(),
             errors: Vec::new(),
+            condition_truthiness: ConditionTruthiness::default(),
+            logic_condition_short_circuit: false,
+            struct_methods: HashMap::new(),
+            pending_types: HashMap::new(),
+            resolving_aliases: Vec::new(),
+            type_arguments: HashMap::new(),
+            pending_instantiations: Vec::new(),
+            instantiation_depth: 0,
+            alloca_values: AllocaValues::default(),
             phantom: PhantomData,
         }
     }
@@ -122,7 +200,8 @@ where
     }
 
     /// Check is value type exists in `Global State`.
-    /// `Primitive` type always return true. For other cases if type doesn't
+    /// `Primitive` type always return true. Type parameter exists only
+    /// in generic function scope. For other cases if type doesn't
     /// exist in `Global State`, add errors to `Error State` and return `false` result.
     fn check_type_exists(
         &mut self,
@@ -130,10 +209,63 @@ where
         val_name: &impl ToString,
         location: &impl GetLocation,
     ) -> bool {
-        if let Type::Primitive(_) = type_name {
-            return true;
-        }
-        if !self.global.types.contains_key(&type_name.name()) {
+        let is_exists = match type_name {
+            Type::Primitive(_) => return true,
+            // Tuple type is anonymous, and exists if all elements
+            // types exist
+            Type::Tuple(types) => {
+                return types
+                    .iter()
+                    .all(|ty| self.check_type_exists(ty, val_name, location));
+            }
+            Type::Array(ty, _)
+            | Type::Pointer(ty)
+            | Type::Reference(ty)
+            | Type::MutableReference(ty) => {
+                return self.check_type_exists(ty, val_name, location);
+            }
+            Type::Parameter(name) => self.type_arguments.contains_key(name),
+            // Function pointer type is anonymous, and exists if
+            // parameters and result types exist
+            Type::Function(parameters, result_type) => {
+                return parameters
+                    .iter()
+                    .chain(std::iter::once(result_type.as_ref()))
+                    .all(|ty| self.check_type_exists(ty, val_name, location));
+            }
+            // Instance of generic struct type with type parameters isn't
+            // instantiated, and exists if its arguments match generic
+            // struct type parameters
+            Type::StructInstance(instance) => {
+                let is_generic = self
+                    .global
+                    .generic_types
+                    .get(&instance.name.clone().into())
+                    .is_some_and(|generic| {
+                        generic.type_parameters.len() == instance.type_arguments.len()
+                            && generic
+                                .type_parameters
+                                .iter()
+                                .zip(&instance.type_arguments)
+                                .all(|arguments| {
+                                    matches!(
+                                        arguments,
+                                        (GenericParameter::Type(_), GenericArgument::Type(_))
+                                            | (
+                                                GenericParameter::Const(_),
+                                                GenericArgument::Const(_)
+                                            )
+                                    )
+                                })
+                    });
+                if is_generic {
+                    return self.check_generic_arguments(instance, val_name, location);
+                }
+                false
+            }
+            _ => self.global.types.contains_key(&type_name.name()),
+        };
+        if !is_exists {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::TypeNotFound,
                 val_name.to_string(),
@@ -144,6 +276,193 @@ where
         true
     }
 
+    /// Check generic struct type instance arguments: type arguments
+    /// should exist, and const arguments should be constant.
+    fn check_generic_arguments(
+        &mut self,
+        instance: &StructInstanceType,
+        val_name: &impl ToString,
+        location: &impl GetLocation,
+    ) -> bool {
+        for argument in &instance.type_arguments {
+            match argument {
+                GenericArgument::Type(ty) => {
+                    if !self.check_type_exists(ty, val_name, location) {
+                        return false;
+                    }
+                }
+                GenericArgument::Const(value) => {
+                    if self.const_argument(value).is_none() {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ConstArgumentNotConstant,
+                            instance.to_string(),
+                            location.location(),
+                        ));
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Resolve type to declared type. Struct type refers by name to
+    /// declared struct type, that contains struct methods, to enum type
+    /// or to type alias. Enum type resolved by name to declared enum
+    /// type with resolved variants payload types. Type parameter
+    /// resolved to type argument of current generic function. Instance
+    /// of generic struct type resolved to concrete struct type.
+    fn resolve_type(&mut self, ty: &Type) -> Type {
+        match ty {
+            Type::StructInstance(instance) => {
+                let type_arguments = instance
+                    .type_arguments
+                    .iter()
+                    .map(|argument| match argument {
+                        GenericArgument::Type(ty) => {
+                            GenericArgument::Type(self.resolve_type(ty).without_aliases())
+                        }
+                        GenericArgument::Const(_) => argument.clone(),
+                    })
+                    .collect();
+                self.struct_instance(StructInstanceType {
+                    name: instance.name.clone(),
+                    type_arguments,
+                })
+            }
+            Type::Parameter(name) => self
+                .type_arguments
+                .get(name)
+                .map_or_else(|| ty.clone(), Clone::clone),
+            Type::Array(ty, size) => Type::Array(Box::new(self.resolve_type(ty)), *size),
+            Type::Tuple(types) => {
+                Type::Tuple(types.iter().map(|ty| self.resolve_type(ty)).collect())
+            }
+            Type::Pointer(ty) => Type::Pointer(Box::new(self.resolve_type(ty))),
+            Type::Reference(ty) => Type::Reference(Box::new(self.resolve_type(ty))),
+            Type::MutableReference(ty) => Type::MutableReference(Box::new(self.resolve_type(ty))),
+            Type::Function(parameters, result_type) => Type::Function(
+                parameters.iter().map(|ty| self.resolve_type(ty)).collect(),
+                Box::new(self.resolve_type(result_type)),
+            ),
+            Type::Struct(ty) => {
+                let type_name: TypeName = ty.name.clone().into();
+                self.resolve_declared_type(&type_name);
+                match self.global.types.get(&type_name) {
+                    Some(declared @ (Type::Struct(_) | Type::Enum(_) | Type::Alias(..))) => {
+                        declared.clone()
+                    }
+                    _ => Type::Struct(ty.clone()),
+                }
+            }
+            Type::Enum(ty) => {
+                let type_name: TypeName = ty.name.clone().into();
+                self.resolve_declared_type(&type_name);
+                match self.global.types.get(&type_name) {
+                    Some(declared @ Type::Enum(_)) => declared.clone(),
+                    _ => Type::Enum(ty.clone()),
+                }
+            }
+            ty => ty.clone(),
+        }
+    }
+
+    /// Instantiate generic struct type for concrete type arguments.
+    /// Struct instance declared in `Global State` types once, with
+    /// mangled name `Name<type_arguments>`, where const arguments are
+    /// folded to values. If type arguments contain type parameters,
+    /// const arguments aren't constant, arguments don't match
+    /// parameters, or generic struct type not found, instance isn't
+    /// instantiated.
+    fn struct_instance(&mut self, instance: StructInstanceType) -> Type {
+        let generic = self
+            .global
+            .generic_types
+            .get(&instance.name.clone().into())
+            .cloned();
+        let Some(generic) = generic.filter(|generic| {
+            generic.type_parameters.len() == instance.type_arguments.len()
+                && !instance
+                    .type_arguments
+                    .iter()
+                    .any(GenericArgument::has_type_parameters)
+        }) else {
+            return Type::StructInstance(instance);
+        };
+        let mut type_arguments: HashMap<TypeParameterName, Type> = HashMap::new();
+        let mut const_arguments: HashMap<TypeParameterName, u32> = HashMap::new();
+        let mut arguments = vec![];
+        for (parameter, argument) in generic.type_parameters.iter().zip(&instance.type_arguments) {
+            match (parameter, argument) {
+                (GenericParameter::Type(name), GenericArgument::Type(ty)) => {
+                    type_arguments.insert(name.clone(), ty.clone());
+                    arguments.push(argument.clone());
+                }
+                (GenericParameter::Const(name), GenericArgument::Const(value)) => {
+                    let Some(value) = self.const_argument(value) else {
+                        return Type::StructInstance(instance);
+                    };
+                    const_arguments.insert(name.clone(), value);
+                    arguments.push(GenericArgument::value(value));
+                }
+                _ => return Type::StructInstance(instance),
+            }
+        }
+        // Instance with constants in const arguments is the same
+        // instance as with their values
+        let instance = StructInstanceType {
+            name: instance.name,
+            type_arguments: arguments,
+        };
+        let type_name: TypeName = instance.to_string().into();
+        if let Some(ty) = self.global.types.get(&type_name) {
+            return ty.clone();
+        }
+        let mut struct_type = StructTypes {
+            name: type_name.to_string(),
+            attributes: generic.attributes,
+            methods: HashMap::new(),
+            instance: Some(instance),
+        };
+        for attr in struct_type.attributes.values_mut() {
+            attr.attr_type = attr
+                .attr_type
+                .substitute_type_parameters(&type_arguments, &const_arguments);
+        }
+        // Struct instance declared before attributes types resolved,
+        // for attributes with the same struct instance type
+        self.global
+            .types
+            .insert(type_name.clone(), Type::Struct(struct_type.clone()));
+        for attr in struct_type.attributes.values_mut() {
+            attr.attr_type = self.resolve_type(&attr.attr_type);
+        }
+        self.global
+            .types
+            .insert(type_name, Type::Struct(struct_type.clone()));
+        self.global.context.types(struct_type.clone());
+        Type::Struct(struct_type)
+    }
+
+    /// Fold const argument of generic struct type instance to array
+    /// length. Const argument is non-negative integer value or
+    /// declared constant with such value.
+    fn const_argument(&self, value: &ConstantValue) -> Option<u32> {
+        let mut value = value;
+        loop {
+            match value {
+                ConstantValue::Value(value) => return u32::try_from(value.integer_value()?).ok(),
+                ConstantValue::Constant(name) => {
+                    let constant = self.global.constants.get(name)?;
+                    if constant.constant_value.operation.is_some() {
+                        return None;
+                    }
+                    value = &constant.constant_value.value;
+                }
+            }
+        }
+    }
+
     /// Run semantic analyzer that covers all flow for AST.
     /// It's do not return any results, but fill results fir the `Semantic State`.
     ///
@@ -152,31 +471,128 @@ where
         // For functions - fetch only declaration for fast-forward
         // identification for using it in functions body.
 
-        // First pass is Imports and Types
+        // First pass is Imports and Types. Struct methods names and
+        // generic struct types are collected before struct types, as
+        // struct types can contain instances of generic struct types.
+        for main in data {
+            match main {
+                ast::MainStatement::Impl(struct_impl) => self.struct_impl_methods(struct_impl),
+                ast::MainStatement::GenericTypes(generic_types) => {
+                    self.generic_types(generic_types);
+                }
+                _ => (),
+            }
+        }
+        // Types are declared before their inner types resolved, so
+        // types can refer to types declared later.
         for main in data {
             match main {
                 ast::MainStatement::Import(import) => self.import(import),
-                ast::MainStatement::Types(types) => self.types(types),
+                ast::MainStatement::Types(types) => {
+                    self.struct_type_declaration(types);
+                }
+                ast::MainStatement::Enum(enum_types) => {
+                    self.enum_type_declaration(enum_types);
+                }
+                ast::MainStatement::TypeAlias(type_alias) => {
+                    self.type_alias_declaration(type_alias);
+                }
+                _ => (),
+            }
+        }
+        for main in data {
+            match main {
+                ast::MainStatement::Types(types) => {
+                    self.resolve_declared_type(&types.name().into());
+                }
+                ast::MainStatement::Enum(enum_types) => {
+                    self.resolve_declared_type(&enum_types.name().into());
+                }
+                ast::MainStatement::TypeAlias(type_alias) => {
+                    self.resolve_declared_type(&type_alias.name().into());
+                }
                 _ => (),
             }
         }
-        // Declaration pass for Constants and Functions
+        self.struct_methods.clear();
+        // Declaration pass for Constants, Functions and Struct methods
         for main in data {
             match main {
                 ast::MainStatement::Constant(constant) => self.constant(constant),
                 ast::MainStatement::Function(function) => self.function_declaration(function),
+                ast::MainStatement::Impl(struct_impl) => self.struct_impl(struct_impl),
                 _ => (),
             }
         }
 
         // After getting all functions declarations, fetch only functions body
         for main in data {
-            if let ast::MainStatement::Function(function) = main {
+            match main {
+                ast::MainStatement::Function(function) => self.function_body(function),
+                ast::MainStatement::Impl(struct_impl) => {
+                    for method in &struct_impl.methods {
+                        self.function_body(method);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // Generic functions instantiations are requested from
+        // functions bodies, including other instantiations bodies. So
+        // analyze instantiations bodies until all of them are analyzed.
+        // Each iteration analyzes deeper instantiations, and depth is
+        // limited, so iterations count is limited by max depth.
+        let generic_functions: Vec<_> = data
+            .iter()
+            .filter_map(|main| match main {
+                ast::MainStatement::Function(function) if !function.type_parameters.is_empty() => {
+                    Some(function)
+                }
+                _ => None,
+            })
+            .collect();
+        for _ in 0..=MAX_INSTANTIATION_DEPTH {
+            self.undeclared_instantiations(&generic_functions);
+            if self.pending_instantiations.is_empty() {
+                break;
+            }
+            for function in &generic_functions {
                 self.function_body(function);
             }
         }
     }
 
+    /// Report pending instantiations of generic functions, that
+    /// declaration isn't found in generic functions, and remove them
+    /// from pending instantiations, as their bodies can't be analyzed.
+    fn undeclared_instantiations(
+        &mut self,
+        generic_functions: &[&ast::FunctionStatement<'_, I, E>],
+    ) {
+        let (declared, undeclared): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.pending_instantiations)
+                .into_iter()
+                .partition(|(instantiation_name, ..)| {
+                    self.global
+                        .instantiations
+                        .get(instantiation_name)
+                        .is_some_and(|instantiation| {
+                            generic_functions.iter().any(|function| {
+                                FunctionName::from(function.name()) == instantiation.function
+                            })
+                        })
+                });
+        self.pending_instantiations = declared;
+        for (instantiation_name, _, location) in undeclared {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::FunctionNotFound,
+                instantiation_name.to_string(),
+                location,
+            ));
+        }
+    }
+
     /// Import analyzer (TBD)
     #[allow(clippy::unused_self, clippy::unnecessary_wraps)]
     pub fn import(&self, data: &ast::ImportPath<'_>) {
@@ -189,7 +605,147 @@ where
     /// Currently only one type kind: Structs. And types can't be part of
     /// the `Block State`.
     pub fn types(&mut self, data: &ast::StructTypes<'_>) {
-        if self.global.types.contains_key(&data.name().into()) {
+        if self.struct_type_declaration(data) {
+            self.resolve_declared_type(&data.name().into());
+        }
+    }
+
+    /// Declare struct type in `Global State` with unresolved
+    /// attributes types. Return `false` if struct type is invalid.
+    fn struct_type_declaration(&mut self, data: &ast::StructTypes<'_>) -> bool {
+        if self.global.types.contains_key(&data.name().into())
+            || self.global.generic_types.contains_key(&data.name().into())
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeAlreadyExist,
+                data.name(),
+                data.location(),
+            ));
+            return false;
+        }
+        if u32::try_from(data.attributes.len()).is_err() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::StructAttributesTooMany,
+                data.name(),
+                data.location(),
+            ));
+            return false;
+        }
+        let mut struct_type: StructTypes = data.clone().into();
+        struct_type.methods = self
+            .struct_methods
+            .get(&data.name().into())
+            .cloned()
+            .unwrap_or_default();
+        self.global
+            .types
+            .insert(data.name().into(), Type::Struct(struct_type));
+        self.pending_types.insert(data.name().into(), vec![]);
+        true
+    }
+
+    /// Resolve inner types of the declared type: struct attributes
+    /// types, enum variants payload types or aliased type. Types, that
+    /// inner types refer to, are resolved first. Invalid enum type or
+    /// type alias is removed from `Global State`. Type aliases, that
+    /// refer to itself through other aliases, are removed from `Global
+    /// State` as cyclic.
+    fn resolve_declared_type(&mut self, type_name: &TypeName) {
+        if let Some(position) = self
+            .resolving_aliases
+            .iter()
+            .position(|(name, _)| name == type_name)
+        {
+            self.type_alias_cycle(position);
+            return;
+        }
+        // Type is removed from pending types before its inner types
+        // resolved, so recursive types refer to unresolved type
+        let Some(locations) = self.pending_types.remove(type_name) else {
+            return;
+        };
+        let Some(ty) = self.global.types.get(type_name).cloned() else {
+            return;
+        };
+        let ty = match ty {
+            Type::Struct(mut struct_type) => {
+                // Struct type breaks aliases chain
+                let resolving_aliases = std::mem::take(&mut self.resolving_aliases);
+                for attr in struct_type.attributes.values_mut() {
+                    attr.attr_type = self.resolve_type(&attr.attr_type);
+                }
+                self.resolving_aliases = resolving_aliases;
+                self.global.context.types(struct_type.clone());
+                Type::Struct(struct_type)
+            }
+            Type::Enum(mut enum_type) => {
+                let mut is_valid = true;
+                // Enum type breaks aliases chain
+                let resolving_aliases = std::mem::take(&mut self.resolving_aliases);
+                for (variant, location) in enum_type.variants.iter_mut().zip(&locations) {
+                    let variant_name = format!("{type_name}::{}", variant.name);
+                    for ty in &mut variant.payload {
+                        *ty = self.resolve_type(ty);
+                        is_valid = self.check_type_exists(ty, &variant_name, location) && is_valid;
+                    }
+                }
+                self.resolving_aliases = resolving_aliases;
+                if !is_valid {
+                    self.global.types.remove(type_name);
+                    return;
+                }
+                self.global.context.enum_types(enum_type.clone());
+                Type::Enum(enum_type)
+            }
+            Type::Alias(name, alias_type) => {
+                self.resolving_aliases
+                    .push((type_name.clone(), locations[0].clone()));
+                let alias_type = self.resolve_type(&alias_type);
+                self.resolving_aliases.pop();
+                // Cyclic type alias already removed
+                if !self.global.types.contains_key(type_name) {
+                    return;
+                }
+                if !self.check_type_exists(&alias_type, &name, &locations[0]) {
+                    self.global.types.remove(type_name);
+                    return;
+                }
+                Type::Alias(name, Box::new(alias_type))
+            }
+            ty => ty,
+        };
+        self.global.types.insert(type_name.clone(), ty);
+    }
+
+    /// Report cyclic type aliases, that starts from the resolving
+    /// alias at `position`, and remove cycle aliases from `Global State`.
+    fn type_alias_cycle(&mut self, position: usize) {
+        let cycle = self.resolving_aliases[position..].to_vec();
+        let (type_name, location) = cycle[0].clone();
+        let cycle_names = cycle
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .chain([type_name.to_string()])
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        self.add_error(error::StateErrorResult::new(
+            error::StateErrorKind::TypeAliasCycle,
+            cycle_names,
+            location,
+        ));
+        for (name, _) in cycle {
+            self.global.types.remove(&name);
+        }
+    }
+
+    /// Generic struct types declaration analyzer. Add generic struct
+    /// type to `Global State`. Type parameters should be unique.
+    /// Generic struct type is declared as struct type only for concrete
+    /// instances.
+    pub fn generic_types(&mut self, data: &ast::GenericStructTypes<'_>) {
+        if self.global.types.contains_key(&data.name().into())
+            || self.global.generic_types.contains_key(&data.name().into())
+        {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::TypeAlreadyExist,
                 data.name(),
@@ -197,9 +753,110 @@ where
             ));
             return;
         }
-        let struct_type = Type::Struct(data.clone().into());
-        self.global.types.insert(struct_type.name(), struct_type);
-        self.global.context.types(data.clone().into());
+        if u32::try_from(data.attributes.len()).is_err() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::StructAttributesTooMany,
+                data.name(),
+                data.location(),
+            ));
+            return;
+        }
+        let mut type_parameters = HashSet::new();
+        for type_parameter in &data.type_parameters {
+            if !type_parameters.insert(type_parameter.name()) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::TypeParameterAlreadyExist,
+                    format!("{}<{}>", data.name(), type_parameter.name()),
+                    type_parameter.location(),
+                ));
+                return;
+            }
+        }
+        self.global
+            .generic_types
+            .insert(data.name().into(), data.clone().into());
+    }
+
+    /// Type alias declaration analyzer. Add type alias to `Global State`
+    /// types. Aliased type should exist.
+    pub fn type_alias(&mut self, data: &ast::TypeAlias<'_>) {
+        if self.type_alias_declaration(data) {
+            self.resolve_declared_type(&data.name().into());
+        }
+    }
+
+    /// Declare type alias in `Global State` with unresolved aliased
+    /// type. Return `false` if type alias is invalid.
+    fn type_alias_declaration(&mut self, data: &ast::TypeAlias<'_>) -> bool {
+        if self.global.types.contains_key(&data.name().into())
+            || self.global.generic_types.contains_key(&data.name().into())
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeAlreadyExist,
+                data.name(),
+                data.location(),
+            ));
+            return false;
+        }
+        self.global.types.insert(
+            data.name().into(),
+            Type::Alias(data.name(), Box::new(data.alias_type.clone().into())),
+        );
+        self.pending_types
+            .insert(data.name().into(), vec![data.location()]);
+        true
+    }
+
+    /// Enum types declaration analyzer. Add enum type to `Global State`.
+    /// Enum variants names should be unique. Variant discriminant is
+    /// variant index in declaration order.
+    pub fn enum_types(&mut self, data: &ast::EnumTypes<'_>) {
+        if self.enum_type_declaration(data) {
+            self.resolve_declared_type(&data.name().into());
+        }
+    }
+
+    /// Declare enum type in `Global State` with unresolved variants
+    /// payload types. Return `false` if enum type is invalid.
+    fn enum_type_declaration(&mut self, data: &ast::EnumTypes<'_>) -> bool {
+        if self.global.types.contains_key(&data.name().into())
+            || self.global.generic_types.contains_key(&data.name().into())
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeAlreadyExist,
+                data.name(),
+                data.location(),
+            ));
+            return false;
+        }
+        if u32::try_from(data.variants.len()).is_err() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::EnumVariantsTooMany,
+                data.name(),
+                data.location(),
+            ));
+            return false;
+        }
+        let mut variants = HashSet::new();
+        for variant in &data.variants {
+            if !variants.insert(variant.name()) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::EnumVariantAlreadyExist,
+                    format!("{}::{}", data.name(), variant.name()),
+                    variant.location(),
+                ));
+                return false;
+            }
+        }
+        let enum_type: EnumTypes = data.clone().into();
+        self.global
+            .types
+            .insert(data.name().into(), Type::Enum(enum_type));
+        self.pending_types.insert(
+            data.name().into(),
+            data.variants.iter().map(GetLocation::location).collect(),
+        );
+        true
     }
 
     /// Check constant value expression.
@@ -264,15 +921,98 @@ where
 
     /// Function declaration analyze. Add it to Global State/M
     pub fn function_declaration(&mut self, data: &ast::FunctionStatement<'_, I, E>) {
-        if self.global.functions.contains_key(&data.name().into()) {
+        if data.type_parameters.is_empty() {
+            self.declare_function(data, data.name().into());
+        } else {
+            self.generic_function_declaration(data);
+        }
+    }
+
+    /// Generic function declaration analyze. Type parameters should be
+    /// unique. Generic function added to `Global State` generic
+    /// functions, and declared as function only for concrete
+    /// instantiations.
+    fn generic_function_declaration(&mut self, data: &ast::FunctionStatement<'_, I, E>) {
+        let name: FunctionName = data.name().into();
+        if self.global.functions.contains_key(&name)
+            || self.global.generic_functions.contains_key(&name)
+        {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::FunctionAlreadyExist,
-                data.name(),
+                name.to_string(),
                 data.location(),
             ));
             return;
         }
-        let func_decl: FunctionStatement = data.clone().into();
+        let mut type_parameters: Vec<TypeParameterName> = vec![];
+        for type_parameter in &data.type_parameters {
+            let type_parameter_name: TypeParameterName = type_parameter.clone().into();
+            if type_parameters.contains(&type_parameter_name) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::TypeParameterAlreadyExist,
+                    format!("{name}<{type_parameter_name}>"),
+                    type_parameter.location(),
+                ));
+                return;
+            }
+            type_parameters.push(type_parameter_name);
+        }
+        // Type parameters are declared only in generic function scope
+        self.type_arguments = type_parameters
+            .iter()
+            .map(|ty| (ty.clone(), Type::Parameter(ty.clone())))
+            .collect();
+        let inner_type = self.resolve_type(&data.result_type.clone().into());
+        let mut is_valid = self.check_type_exists(&inner_type, &name, data);
+        let mut parameters = vec![];
+        for param in &data.parameters {
+            let func_param: FunctionParameter = param.clone().into();
+            let parameter_type = self.resolve_type(&func_param.parameter_type);
+            is_valid = self.check_type_exists(&parameter_type, &func_param, data) && is_valid;
+            parameters.push(parameter_type);
+        }
+        self.type_arguments.clear();
+        if !is_valid {
+            return;
+        }
+        self.global.generic_functions.insert(
+            name.clone(),
+            GenericFunction {
+                inner_name: name,
+                type_parameters,
+                inner_type,
+                parameters,
+            },
+        );
+    }
+
+    /// Declare function in `Global State` with specific function
+    /// name. Function name can be different from function
+    /// declaration name, for example for struct methods.
+    ///
+    /// ## Return
+    /// Is function declared
+    fn declare_function(
+        &mut self,
+        data: &ast::FunctionStatement<'_, I, E>,
+        name: FunctionName,
+    ) -> bool {
+        if self.global.functions.contains_key(&name)
+            || self.global.generic_functions.contains_key(&name)
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::FunctionAlreadyExist,
+                name.to_string(),
+                data.location(),
+            ));
+            return false;
+        }
+        let mut func_decl: FunctionStatement = data.clone().into();
+        func_decl.name = name.clone();
+        func_decl.result_type = self.resolve_type(&func_decl.result_type);
+        for param in &mut func_decl.parameters {
+            param.parameter_type = self.resolve_type(&param.parameter_type);
+        }
         let mut force_quite =
             !self.check_type_exists(&func_decl.result_type, &func_decl.name, data);
 
@@ -287,39 +1027,94 @@ where
             .collect();
         // Force quite if errors
         if force_quite {
-            return;
+            return false;
         }
         self.global.functions.insert(
-            data.name().into(),
+            name,
             Function {
-                inner_name: func_decl.name,
-                inner_type: func_decl.result_type,
+                inner_name: func_decl.name.clone(),
+                inner_type: func_decl.result_type.clone(),
                 parameters,
             },
         );
-        self.global
-            .context
-            .function_declaration(data.clone().into());
+        self.global.context.function_declaration(func_decl);
+        true
     }
 
-    /// Init function parameters.
-    /// It's init function parameters as values, same as let-binding.
-    /// And add instructions to `SemanticStack`.
-    fn init_func_params(
-        &mut self,
-        function_state: &Rc<RefCell<BlockState<I>>>,
-        fn_params: &Vec<ast::FunctionParameter<'_>>,
-    ) {
-        for fn_param in fn_params {
-            let func_param: FunctionParameter = fn_param.clone().into();
-            let arg_name = func_param.clone().to_string();
+    /// Collect struct methods names from `impl` block. Methods names
+    /// are added to struct type when struct type is declared.
+    fn struct_impl_methods(&mut self, data: &ast::StructImpl<'_, I, E>) {
+        let type_name: TypeName = data.name().into();
+        let methods = self.struct_methods.entry(type_name.clone()).or_default();
+        for method in &data.methods {
+            let method_name = method.name();
+            methods
+                .entry(method_name.clone())
+                .or_insert_with(|| format!("{type_name}.{method_name}").into());
+        }
+    }
 
-            // Find value in current state and parent states
-            let value = function_state
-                .borrow()
-                .get_value_name(&arg_name.clone().into());
-            // Calculate `inner_name` as unique for current and all parent states
-            let inner_name: InnerValueName = if value.is_none() {
+    /// Struct methods declaration analyzer. Methods declared as
+    /// functions in `Global State` with name `Type.method`. Struct
+    /// type already contains methods names. First method parameter
+    /// is receiver, and it should have the struct type.
+    pub fn struct_impl(&mut self, data: &ast::StructImpl<'_, I, E>) {
+        let type_name: TypeName = data.name().into();
+        let Some(Type::Struct(struct_type)) = self.global.types.get(&type_name).cloned() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeNotFound,
+                data.name(),
+                data.location(),
+            ));
+            return;
+        };
+        for method in &data.methods {
+            let method_name = method.name();
+            let fn_name: FunctionName = format!("{type_name}.{method_name}").into();
+            if self.global.functions.contains_key(&fn_name) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::MethodAlreadyExist,
+                    format!("{type_name}.{method_name}"),
+                    method.location(),
+                ));
+                continue;
+            }
+            // Check method receiver type
+            let receiver_type: Option<Type> = method
+                .parameters
+                .first()
+                .map(|param| self.resolve_type(&param.parameter_type.clone().into()));
+            if receiver_type.as_ref() != Some(&Type::Struct(struct_type.clone())) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::MethodReceiverWrongType,
+                    format!("{type_name}.{method_name}"),
+                    method.location(),
+                ));
+                continue;
+            }
+            self.declare_function(method, fn_name);
+        }
+    }
+
+    /// Init function parameters.
+    /// It's init function parameters as values, same as let-binding.
+    /// And add instructions to `SemanticStack`.
+    fn init_func_params(
+        &mut self,
+        function_state: &Rc<RefCell<BlockState<I>>>,
+        fn_params: &Vec<ast::FunctionParameter<'_>>,
+    ) {
+        for fn_param in fn_params {
+            let mut func_param: FunctionParameter = fn_param.clone().into();
+            func_param.parameter_type = self.resolve_type(&func_param.parameter_type);
+            let arg_name = func_param.clone().to_string();
+
+            // Find value in current state and parent states
+            let value = function_state
+                .borrow()
+                .get_value_name(&arg_name.clone().into());
+            // Calculate `inner_name` as unique for current and all parent states
+            let inner_name: InnerValueName = if value.is_none() {
                 // if value not found in all states check and set
                 // `inner_value` from value name
                 // NOTE: value number not incremented
@@ -339,7 +1134,9 @@ where
                 inner_name: inner_name.clone(),
                 inner_type: func_param.parameter_type.clone(),
                 mutable: false,
-                alloca: false,
+                alloca: self
+                    .alloca_values
+                    .is_alloca(&arg_name.clone().into(), false),
                 malloc: false,
             };
             // Value inserted only to current state by Value name and Value data
@@ -359,15 +1156,86 @@ where
     /// Function body analyze.
     /// It is basic execution entity for program flow.
     /// It's operate sub analyze for function elements. It's contain
-    /// Body State for current and child states.
+    /// Body State for current and child states. For generic function
+    /// analyzed bodies of its instantiations.
     pub fn function_body(&mut self, data: &ast::FunctionStatement<'_, I, E>) {
+        if data.type_parameters.is_empty() {
+            self.function_body_statements(data);
+        } else {
+            self.generic_function_body(data);
+        }
+    }
+
+    /// Generic function body analyze for each pending instantiation of
+    /// generic function. Type parameters substituted with instantiation
+    /// type arguments. Instantiation is declared in `Global State`
+    /// context before its body analyzed, so declaration and body
+    /// contexts have the same order as for other functions.
+    fn generic_function_body(&mut self, data: &ast::FunctionStatement<'_, I, E>) {
+        let name: FunctionName = data.name().into();
+        let (instantiations, pending): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.pending_instantiations)
+                .into_iter()
+                .partition(|(instantiation_name, ..)| {
+                    self.global
+                        .instantiations
+                        .get(instantiation_name)
+                        .is_some_and(|instantiation| instantiation.function == name)
+                });
+        self.pending_instantiations = pending;
+        for (instantiation_name, depth, _) in instantiations {
+            let type_arguments = self.global.instantiations[&instantiation_name]
+                .type_arguments
+                .clone();
+            self.type_arguments = data
+                .type_parameters
+                .iter()
+                .map(|ty| ty.clone().into())
+                .zip(type_arguments)
+                .collect();
+            self.instantiation_depth = depth;
+
+            let mut func_decl: FunctionStatement = data.clone().into();
+            func_decl.name = instantiation_name;
+            func_decl.type_parameters = vec![];
+            func_decl.result_type = self.resolve_type(&func_decl.result_type);
+            for param in &mut func_decl.parameters {
+                param.parameter_type = self.resolve_type(&param.parameter_type);
+            }
+            self.global.context.function_declaration(func_decl);
+            self.function_body_statements(data);
+        }
+        self.type_arguments.clear();
+        self.instantiation_depth = 0;
+    }
+
+    /// Analyze function body statements with function parameters
+    /// in new function body state. Values, that should be allocated in
+    /// memory, are collected before function body analyzed, including
+    /// values of closures bodies.
+    fn function_body_statements(&mut self, data: &ast::FunctionStatement<'_, I, E>) {
+        self.alloca_values = AllocaValues::new(&data.body);
         // Init empty function body state
         let body_state = Rc::new(RefCell::new(BlockState::new(None)));
         self.add_state_context(body_state.clone());
+        self.function_body_state(data, &body_state);
+    }
+
+    /// Analyze function body statements in the function body state.
+    /// Function parameters initialized as values of the body state.
+    fn function_body_state(
+        &mut self,
+        data: &ast::FunctionStatement<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
         // Init function parameters - add to SemanticStackContext
-        self.init_func_params(&body_state, &data.parameters);
+        self.init_func_params(body_state, &data.parameters);
+        let return_type = self.resolve_type(&data.result_type.clone().into());
         // Flag to indicate is function return called
         let mut return_is_called = false;
+        // Flag to indicate is function return called from nested
+        // statement, and function return should be set by return label
+        let mut return_label_is_called = false;
         // Fetch function elements and gather errors
         for body in &data.body {
             if return_is_called {
@@ -378,24 +1246,9 @@ where
                 ));
             }
             match body {
-                ast::BodyStatement::LetBinding(bind) => {
-                    self.let_binding(bind, &body_state);
-                }
-                ast::BodyStatement::Binding(bind) => {
-                    self.binding(bind, &body_state);
-                }
-                ast::BodyStatement::FunctionCall(fn_call) => {
-                    self.function_call(fn_call, &body_state);
-                }
-                ast::BodyStatement::If(if_condition) => {
-                    self.if_condition(if_condition, &body_state, &None, None);
-                }
-                ast::BodyStatement::Loop(loop_statement) => {
-                    self.loop_statement(loop_statement, &body_state);
-                }
                 ast::BodyStatement::Expression(expression)
                 | ast::BodyStatement::Return(expression) => {
-                    let expr_result = self.expression(expression, &body_state);
+                    let expr_result = self.expression(expression, body_state);
                     let expr: Expression = expression.clone().into();
                     // Check is return statement previously called
                     if return_is_called {
@@ -408,8 +1261,7 @@ where
                     if let Some(res) = expr_result {
                         // Check expression type and do not exist from flow
                         self.check_type_exists(&res.expr_type, &expr, expression);
-                        let fn_ty: Type = data.result_type.clone().into();
-                        if fn_ty != res.expr_type {
+                        if return_type != res.expr_type {
                             self.add_error(error::StateErrorResult::new(
                                 error::StateErrorKind::WrongReturnType,
                                 expr.to_string(),
@@ -418,21 +1270,21 @@ where
                         }
 
                         return_is_called = true;
-                        // Check is state contain flag of manual
-                        // return from other states, for example:
-                        // if-flow, loop-flow
-                        if body_state.borrow().manual_return {
-                            // First we put expression return calculation for case when
-                            // before in the state was return statement. So construct
-                            // return expression and jump to return label, set return
-                            // label and invoke after that read `return` value from all
-                            // previous returns and invoke return instruction itself.
-                            body_state
-                                .borrow_mut()
-                                .expression_function_return_with_label(res);
-                        } else {
-                            body_state.borrow_mut().expression_function_return(res);
-                        }
+                        Self::function_return(body_state, res);
+                    }
+                }
+                _ => {
+                    let (is_return, block_value) =
+                        self.body_statement(body, body_state, &return_type);
+                    if is_return {
+                        return_is_called = true;
+                        return_label_is_called = true;
+                    }
+                    // Value of the block statement is value of the
+                    // function body
+                    if let Some(res) = block_value {
+                        return_is_called = true;
+                        Self::function_return(body_state, res);
                     }
                 }
             }
@@ -444,7 +1296,160 @@ where
                 String::new(),
                 data.location(),
             ));
+        } else if return_label_is_called {
+            body_state.borrow_mut().function_return_label(return_type);
+        }
+    }
+
+    /// Return from the function with the function body expression result.
+    fn function_return(body_state: &Rc<RefCell<BlockState<I>>>, res: ExpressionResult) {
+        // Check is state contain flag of manual
+        // return from other states, for example:
+        // if-flow, loop-flow
+        if body_state.borrow().manual_return {
+            // First we put expression return calculation for case when
+            // before in the state was return statement. So construct
+            // return expression and jump to return label, set return
+            // label and invoke after that read `return` value from all
+            // previous returns and invoke return instruction itself.
+            body_state
+                .borrow_mut()
+                .expression_function_return_with_label(res);
+        } else {
+            body_state.borrow_mut().expression_function_return(res);
+        }
+    }
+
+    /// Analyze body statement of the function body or of the nested
+    /// block statement. Expression and return statements aren't
+    /// analyzed, as function body and block handle it differently.
+    ///
+    /// ## Return
+    /// Return status of the function return from nested statement,
+    /// and value of the nested block statement
+    fn body_statement(
+        &mut self,
+        body: &ast::BodyStatement<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+        return_type: &Type,
+    ) -> (bool, Option<ExpressionResult>) {
+        match body {
+            ast::BodyStatement::LetBinding(bind) => {
+                self.let_binding(bind, body_state);
+            }
+            ast::BodyStatement::LetTupleBinding(bind) => {
+                self.let_tuple_binding(bind, body_state);
+            }
+            ast::BodyStatement::Binding(bind) => {
+                self.binding(bind, body_state);
+            }
+            ast::BodyStatement::FunctionCall(fn_call) => {
+                self.function_call(fn_call, body_state);
+            }
+            ast::BodyStatement::If(if_condition) => {
+                self.if_condition(if_condition, body_state, &None, None);
+            }
+            ast::BodyStatement::Loop(loop_statement) => {
+                self.loop_statement(loop_statement, body_state);
+            }
+            ast::BodyStatement::LabeledLoop(loop_statement) => {
+                self.labeled_loop_statement(loop_statement, body_state);
+            }
+            ast::BodyStatement::While(while_statement) => {
+                self.while_statement(while_statement, body_state);
+            }
+            ast::BodyStatement::For(for_statement) => {
+                self.for_statement(for_statement, body_state);
+            }
+            ast::BodyStatement::Match(match_statement) => {
+                return (
+                    self.match_statement(match_statement, body_state, None),
+                    None,
+                );
+            }
+            ast::BodyStatement::Block(block) => {
+                return self.block_statement(block, body_state, return_type);
+            }
+            ast::BodyStatement::Expression(_) | ast::BodyStatement::Return(_) => {}
+        }
+        (false, None)
+    }
+
+    /// Create child state of the nested block statement. Block state
+    /// is scope of the values declared in the block.
+    fn block_state(parent_state: &Rc<RefCell<BlockState<I>>>) -> Rc<RefCell<BlockState<I>>> {
+        let block_state = Rc::new(RefCell::new(BlockState::new(Some(parent_state.clone()))));
+        parent_state.borrow_mut().set_child(block_state.clone());
+        block_state
+    }
+
+    /// # Block statement
+    /// Analyze nested block statement of the function body. Block has
+    /// own state, so values declared in the block are visible only
+    /// inside the block, and can shadow values of the parent states.
+    /// Return statement of the block returns from the function through
+    /// the return label. Expression statement is the block value, and
+    /// should be the last statement of the block. As the block value
+    /// is value of the function body, it should be the function return
+    /// type.
+    ///
+    /// ## Return
+    /// Return block "return" status and the block value
+    pub fn block_statement(
+        &mut self,
+        data: &[ast::BodyStatement<'_, I, E>],
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+        return_type: &Type,
+    ) -> (bool, Option<ExpressionResult>) {
+        let block_state = Self::block_state(function_body_state);
+        let mut return_is_called = false;
+        let mut block_value = None;
+        for body in data {
+            if return_is_called || block_value.is_some() {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ForbiddenCodeAfterReturnDeprecated,
+                    format!("{body:?}"),
+                    CodeLocation::new(1, 1),
+                ));
+            }
+            match body {
+                ast::BodyStatement::Expression(expression)
+                | ast::BodyStatement::Return(expression) => {
+                    let Some(res) = self.expression(expression, &block_state) else {
+                        continue;
+                    };
+                    let expr: Expression = expression.clone().into();
+                    self.check_type_exists(&res.expr_type, &expr, expression);
+                    if return_type != &res.expr_type {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::WrongReturnType,
+                            expr.to_string(),
+                            expression.location(),
+                        ));
+                    }
+                    if matches!(body, ast::BodyStatement::Expression(_)) {
+                        block_value = Some(res);
+                    } else {
+                        // Jump to return label in codegen and set return
+                        // status to indicate function, that it's manual
+                        // return
+                        block_state.borrow_mut().jump_function_return(res);
+                        block_state.borrow_mut().set_return();
+                        return_is_called = true;
+                    }
+                }
+                _ => {
+                    let (is_return, nested_value) =
+                        self.body_statement(body, &block_state, return_type);
+                    return_is_called |= is_return;
+                    // Value of the nested block is value of the block
+                    if nested_value.is_some() {
+                        block_value = nested_value;
+                    }
+                }
+            }
         }
+        (return_is_called, block_value)
     }
 
     /// # Let-binding statement
@@ -468,7 +1473,8 @@ where
         let Some(expr_result) = self.expression(&data.value, function_state) else {
             return;
         };
-        let let_data: LetBinding = data.clone().into();
+        let mut let_data: LetBinding = data.clone().into();
+        let_data.value_type = let_data.value_type.map(|ty| self.resolve_type(&ty));
 
         if let Some(ty) = &let_data.value_type {
             if &expr_result.expr_type != ty {
@@ -481,9 +1487,184 @@ where
             }
         }
         let let_ty = expr_result.expr_type.clone();
+        let alloca = self
+            .alloca_values
+            .is_alloca(&let_data.name, let_data.mutable);
+        let value = Self::declare_value(
+            function_state,
+            let_data.name,
+            let_ty,
+            let_data.mutable,
+            alloca,
+        );
+        function_state.borrow_mut().let_binding(value, expr_result);
+    }
+
+    /// # Let tuple binding statement
+    /// Analyze let-binding with tuple pattern. Expression value should
+    /// be tuple with the same elements count as tuple pattern. Pattern
+    /// names should be unique. Each tuple element is extracted to the
+    /// register and bound to the new value, that declared same way as
+    /// for let-binding with own `inner_name`.
+    pub fn let_tuple_binding(
+        &mut self,
+        data: &ast::LetTupleBinding<'_, I, E>,
+        function_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        let Some(expr_result) = self.expression(&data.value, function_state) else {
+            return;
+        };
+        let mut let_data: LetTupleBinding = data.clone().into();
+        let_data.value_type = let_data.value_type.map(|ty| self.resolve_type(&ty));
+
+        if let Some(ty) = &let_data.value_type {
+            if &expr_result.expr_type != ty {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::WrongLetType,
+                    let_data.to_string(),
+                    data.location(),
+                ));
+                return;
+            }
+        }
+        let Some(element_types) = expr_result.expr_type.get_tuple() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotTuple,
+                expr_result.expr_type.to_string(),
+                data.value.location(),
+            ));
+            return;
+        };
+        if element_types.len() != let_data.names.len() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TuplePatternArityMismatch,
+                format!(
+                    "{let_data}: expected {}, found {}",
+                    element_types.len(),
+                    let_data.names.len()
+                ),
+                data.location(),
+            ));
+            return;
+        }
+        let mut names = HashSet::new();
+        for (name, ast_name) in let_data.names.iter().zip(&data.names) {
+            if !names.insert(name.name.clone()) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::TuplePatternDuplicateName,
+                    name.name.to_string(),
+                    ast_name.name.location(),
+                ));
+                return;
+            }
+        }
+        for ((name, element_type), index) in let_data.names.into_iter().zip(element_types).zip(0..)
+        {
+            // Tuple element is set to register
+            function_state.borrow_mut().inc_register();
+            let last_register_number = function_state.borrow().last_register_number;
+            function_state.borrow_mut().tuple_element(
+                expr_result.clone(),
+                index,
+                element_type.clone(),
+                last_register_number,
+            );
+            let alloca = self.alloca_values.is_alloca(&name.name, name.mutable);
+            let value = Self::declare_value(
+                function_state,
+                name.name,
+                element_type.clone(),
+                name.mutable,
+                alloca,
+            );
+            function_state.borrow_mut().let_binding(
+                value,
+                ExpressionResult {
+                    expr_type: element_type,
+                    expr_value: ExpressionResultValue::Register(last_register_number),
+                },
+            );
+        }
+    }
+
+    /// Get value by value name from current state and parent states.
+    /// For closure body state, value not found in the closure states
+    /// is captured from enclosing states: declared in the closure body
+    /// state and added to closure captures. Mutable values are captured
+    /// by reference, so captured values are already allocated in
+    /// memory by function body pre-scan.
+    fn get_value(
+        &self,
+        name: &ValueName,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<Value> {
+        let value = body_state.borrow().get_value_name(name);
+        if value.is_some() {
+            return value;
+        }
+        let closure_state = Self::closure_body_state(body_state);
+        let enclosing = closure_state.borrow().enclosing.clone()?;
+        let value = self.get_value(name, &enclosing)?;
+        let by_reference = value.mutable;
+        let alloca = by_reference || self.alloca_values.is_alloca(name, false);
+        let inner_value = Self::declare_value(
+            &closure_state,
+            name.clone(),
+            value.inner_type.clone(),
+            value.mutable,
+            alloca,
+        );
+        closure_state.borrow_mut().captures.push(ClosureCapture {
+            name: name.clone(),
+            value,
+            inner_value: inner_value.clone(),
+            by_reference,
+        });
+        Some(inner_value)
+    }
+
+    /// Find closure body state, that contains current state. For the
+    /// function body it's the function body state.
+    fn closure_body_state(body_state: &Rc<RefCell<BlockState<I>>>) -> Rc<RefCell<BlockState<I>>> {
+        let mut closure_state = body_state.clone();
+        loop {
+            let parent = closure_state.borrow().parent.clone();
+            match parent {
+                Some(parent) => closure_state = parent,
+                None => return closure_state,
+            }
+        }
+    }
+
+    /// Get closure environment attribute index and type of the
+    /// value, that captured by reference by closure, that contains
+    /// current state.
+    fn reference_capture(
+        value: &Value,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<(u32, Type)> {
+        let closure_state = Self::closure_body_state(body_state);
+        let closure_state = closure_state.borrow();
+        (0..)
+            .zip(&closure_state.captures)
+            .find(|(_, capture)| capture.by_reference && capture.inner_value == *value)
+            .map(|(attr_index, capture)| {
+                let value_type = capture.value.inner_type.clone();
+                (attr_index, Type::MutableReference(Box::new(value_type)))
+            })
+    }
 
+    /// Declare value in the current state. `inner_name` of the value
+    /// should be unique for current and all parent states.
+    fn declare_value(
+        function_state: &Rc<RefCell<BlockState<I>>>,
+        name: ValueName,
+        value_type: Type,
+        mutable: bool,
+        alloca: bool,
+    ) -> Value {
         // Find value in current state and parent states
-        let value = function_state.borrow().get_value_name(&let_data.name);
+        let value = function_state.borrow().get_value_name(&name);
         // Calculate `inner_name` as unique for current and all parent states
         let inner_name = value.map_or_else(
             || {
@@ -491,7 +1672,7 @@ where
                 // `inner_value` from value name
                 function_state
                     .borrow()
-                    .get_next_inner_name(&let_data.name.clone().into())
+                    .get_next_inner_name(&name.clone().into())
             },
             |val| {
                 // Increment inner value name counter for shadowed variable
@@ -502,44 +1683,41 @@ where
         // Set value parameters
         let value = Value {
             inner_name: inner_name.clone(),
-            inner_type: let_ty,
-            mutable: let_data.mutable,
-            alloca: false,
+            inner_type: value_type,
+            mutable,
+            alloca,
             malloc: false,
         };
         // Value inserted only to current state by Value name and Value data
         function_state
             .borrow_mut()
             .values
-            .insert(let_data.name, value.clone());
+            .insert(name, value.clone());
         // Set `inner_name` to current state and all parent states
         function_state
             .borrow_mut()
             .set_inner_value_name(&inner_name);
-
-        function_state.borrow_mut().let_binding(value, expr_result);
+        value
     }
 
     /// # Binding statement
     /// Analyze binding statement for mutable variables:
-    /// 1. Bind from expression. First should be analysed
-    ///    `expression` for binding value.
-    /// 2. Read value for current state.
-    /// 3. Update value to current values state map: value `name` -> `Data`
-    /// 4. Codegen with Store action
+    /// 1. Read value for current state. Value should be mutable, except
+    ///    binding through dereference of pointer or reference.
+    /// 2. Resolve binding target place. Place indexes are analysed
+    ///    before binding value.
+    /// 3. Bind from expression. Analyse `expression` for binding value.
+    /// 4. Update value to current values state map: value `name` -> `Data`
+    /// 5. Codegen with Store action
     pub fn binding(
         &mut self,
         data: &ast::Binding<'_, I, E>,
         function_state: &Rc<RefCell<BlockState<I>>>,
     ) {
-        // Call value analytics before putting let-value to state
-        let Some(expr_result) = self.expression(&data.value, function_state) else {
-            return;
-        };
         let bind_data: Binding = data.clone().into();
 
         // Find value in current state and parent states
-        let Some(value) = function_state.borrow().get_value_name(&bind_data.name) else {
+        let Some(value) = self.get_value(&bind_data.name, function_state) else {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::ValueNotFound,
                 bind_data.to_string(),
@@ -547,8 +1725,13 @@ where
             ));
             return;
         };
-        // Check is value mutable
-        if !value.mutable {
+        // Check is value mutable. For binding through dereference
+        // mutability is checked by pointer type.
+        let is_deref = data
+            .path
+            .iter()
+            .any(|access| matches!(access, ast::PlaceAccess::Deref));
+        if !value.mutable && !is_deref {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::ValueIsNotMutable,
                 bind_data.to_string(),
@@ -556,7 +1739,122 @@ where
             ));
             return;
         }
-        function_state.borrow_mut().binding(value, expr_result);
+        // Resolve binding target place
+        let Some((place_type, path)) = self.binding_place(
+            &value.inner_type,
+            &data.path,
+            &data.location(),
+            function_state,
+        ) else {
+            return;
+        };
+        let Some(expr_result) = self.expression(&data.value, function_state) else {
+            return;
+        };
+        if place_type != expr_result.expr_type {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::WrongBindingType,
+                format!("expected {place_type}, found {}", expr_result.expr_type),
+                data.location(),
+            ));
+            return;
+        }
+        if !path.is_empty() {
+            function_state.borrow_mut().store(value, path, expr_result);
+        } else if let Some((attr_index, attr_type)) =
+            Self::reference_capture(&value, function_state)
+        {
+            // Value captured by reference is stored by address from
+            // the closure environment attribute
+            function_state.borrow_mut().inc_register();
+            let address_register = function_state.borrow().last_register_number;
+            function_state
+                .borrow_mut()
+                .environment_field(attr_index, attr_type, address_register);
+            function_state
+                .borrow_mut()
+                .store_address(address_register, expr_result);
+        } else {
+            function_state.borrow_mut().binding(value, expr_result);
+        }
+    }
+
+    /// # Binding place
+    /// Resolve access path of binding target from the value type:
+    /// struct fields resolved to attribute indexes, and array
+    /// elements indexes are analyzed as expressions. Dereference is
+    /// allowed only for raw pointer or mutable reference.
+    ///
+    /// ## Return
+    /// Type of binding place and resolved access path. If access
+    /// path is invalid return `None`.
+    fn binding_place(
+        &mut self,
+        value_type: &Type,
+        path: &[ast::PlaceAccess<'_, I, E>],
+        location: &CodeLocation,
+        function_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<(Type, Vec<PlaceIndex>)> {
+        let mut place_type = value_type.clone();
+        let mut place_path = vec![];
+        for access in path {
+            match access {
+                ast::PlaceAccess::Field(name) => {
+                    let Some(ty) = place_type.get_struct() else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ValueNotStruct,
+                            place_type.to_string(),
+                            name.location(),
+                        ));
+                        return None;
+                    };
+                    let Some(attr) = ty.attributes.get(&name.clone().into()) else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ValueNotStructField,
+                            name.name(),
+                            name.location(),
+                        ));
+                        return None;
+                    };
+                    place_path.push(PlaceIndex::Field(attr.attr_index));
+                    place_type = attr.attr_type.clone();
+                }
+                ast::PlaceAccess::Index(index) => {
+                    let Type::Array(element_type, size) = place_type.unalias().clone() else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ValueNotArray,
+                            place_type.to_string(),
+                            index.location(),
+                        ));
+                        return None;
+                    };
+                    let index = self.array_index(index, size, function_state)?;
+                    place_path.push(PlaceIndex::Element(index));
+                    place_type = *element_type;
+                }
+                ast::PlaceAccess::Deref => {
+                    let Some(pointee) = place_type.get_pointee() else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::DereferenceNotPointer,
+                            place_type.to_string(),
+                            location.clone(),
+                        ));
+                        return None;
+                    };
+                    if !place_type.is_mutable_pointer() {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ReferenceNotMutable,
+                            place_type.to_string(),
+                            location.clone(),
+                        ));
+                        return None;
+                    }
+                    place_path.push(PlaceIndex::Deref);
+                    place_type = pointee;
+                }
+            }
+        }
+        Some((place_type, place_path))
     }
 
     /// # Function-call
@@ -577,6 +1875,14 @@ where
         body_state: &Rc<RefCell<BlockState<I>>>,
     ) -> Option<Type> {
         let func_call_data: FunctionCall = data.clone().into();
+        if let Some(generic) = self
+            .global
+            .generic_functions
+            .get(&func_call_data.name)
+            .cloned()
+        {
+            return self.generic_function_call(data, &generic, body_state);
+        }
         // Check is function exists in global functions stat
         let Some(func_data) = self.global.functions.get(&func_call_data.name).cloned() else {
             self.add_error(error::StateErrorResult::new(
@@ -615,67 +1921,295 @@ where
         Some(fn_type)
     }
 
-    /// # condition-expression
-    /// Analyse condition operations.
-    /// ## Return
-    /// Return result register of `condition-expression` calculation.
-    pub fn condition_expression(
+    /// # Generic function-call
+    /// Call generic function. Type arguments inferred from function
+    /// parameters expressions types, and generic function instantiated
+    /// for inferred type arguments.
+    ///
+    /// ## Errors
+    /// Return error if parameters count or types are wrong, or type
+    /// arguments can't be inferred.
+    fn generic_function_call(
         &mut self,
-        data: &ast::ExpressionLogicCondition<'_, I, E>,
-        function_body_state: &Rc<RefCell<BlockState<I>>>,
-    ) -> u64 {
-        // Analyse left expression of left condition
-        let left_expr = &data.left.left;
-        let left_res = self.expression(left_expr, function_body_state);
-
-        // Analyse right expression of left condition
-        let right_expr = &data.left.right;
-        let right_res = self.expression(right_expr, function_body_state);
-
-        // If some of the `left` or `right` expression is empty just return with error in the state
-        let (Some(left_res), Some(right_res)) = (left_res.clone(), right_res.clone()) else {
+        data: &ast::FunctionCall<'_, I, E>,
+        generic: &GenericFunction,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<Type> {
+        if generic.parameters.len() != data.parameters.len() {
+            let func_call_data: FunctionCall = data.clone().into();
             self.add_error(error::StateErrorResult::new(
-                error::StateErrorKind::ConditionIsEmpty,
-                format!("left={left_res:?}, right={right_res:?}"),
-                data.left.left.location(),
+                error::StateErrorKind::FunctionParametersCountWrong,
+                func_call_data.to_string(),
+                data.location(),
             ));
-            return function_body_state.borrow().last_register_number;
-        };
-
-        // Currently strict type comparison
+            return None;
+        }
+        let mut type_arguments = HashMap::new();
+        let mut params: Vec<ExpressionResult> = vec![];
+        for (expr, param_type) in data.parameters.iter().zip(&generic.parameters) {
+            let expr_result = self.expression(expr, body_state)?;
+            if !param_type.infer_type_arguments(&expr_result.expr_type, &mut type_arguments) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::FunctionParameterTypeWrong,
+                    expr_result.expr_type.to_string(),
+                    data.location(),
+                ));
+                return None;
+            }
+            params.push(expr_result);
+        }
+        let mut instantiation_type_arguments = vec![];
+        for type_parameter in &generic.type_parameters {
+            let Some(ty) = type_arguments.get(type_parameter) else {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::TypeParameterNotInferred,
+                    format!("{}<{type_parameter}>", generic.inner_name),
+                    data.location(),
+                ));
+                return None;
+            };
+            // Type arguments are instantiated without aliases, so
+            // aliased types have the same instantiation
+            instantiation_type_arguments.push(ty.without_aliases());
+        }
+        let func_data = self.function_instantiation(generic, instantiation_type_arguments, data)?;
+        let fn_type = func_data.inner_type.clone();
+
+        // Result of function call is stored to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .call(func_data, params, last_register_number);
+        Some(fn_type)
+    }
+
+    /// Get instantiation of generic function for type arguments.
+    /// Instantiation declared as function with mangled name
+    /// `name<type_arguments>`. New instantiation added to `Global
+    /// State`, and its body analyzed after current function body.
+    ///
+    /// ## Errors
+    /// Return error if instantiation depth is too deep, for example
+    /// for recursive instantiations with growing type arguments.
+    fn function_instantiation(
+        &mut self,
+        generic: &GenericFunction,
+        type_arguments: Vec<Type>,
+        location: &impl GetLocation,
+    ) -> Option<Function> {
+        let name: FunctionName = format!(
+            "{}<{}>",
+            generic.inner_name,
+            type_arguments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .into();
+        if let Some(func_data) = self.global.functions.get(&name) {
+            return Some(func_data.clone());
+        }
+        if self.instantiation_depth >= MAX_INSTANTIATION_DEPTH {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::GenericInstantiationTooDeep,
+                name.to_string(),
+                location.location(),
+            ));
+            return None;
+        }
+        let type_arguments_map: HashMap<TypeParameterName, Type> = generic
+            .type_parameters
+            .iter()
+            .cloned()
+            .zip(type_arguments.iter().cloned())
+            .collect();
+        // Substituted types can contain instances of generic struct types
+        let inner_type = self.resolve_type(
+            &generic
+                .inner_type
+                .substitute_type_parameters(&type_arguments_map, &HashMap::new()),
+        );
+        let parameters = generic
+            .parameters
+            .iter()
+            .map(|ty| {
+                self.resolve_type(
+                    &ty.substitute_type_parameters(&type_arguments_map, &HashMap::new()),
+                )
+            })
+            .collect();
+        let func_data = Function {
+            inner_name: name.clone(),
+            inner_type,
+            parameters,
+        };
+        self.global
+            .functions
+            .insert(name.clone(), func_data.clone());
+        self.global.instantiations.insert(
+            name.clone(),
+            FunctionInstantiation {
+                function: generic.inner_name.clone(),
+                type_arguments,
+            },
+        );
+        self.pending_instantiations
+            .push((name, self.instantiation_depth + 1, location.location()));
+        Some(func_data)
+    }
+
+    /// # Method-call
+    /// Call struct method with receiver and method parameters
+    /// arguments. Method resolved by receiver struct type methods.
+    /// Receiver passed to the call as first argument.
+    /// Codegen store always result to register even for void result.
+    ///
+    /// ## Errors
+    /// Return error if method doesn't exist for receiver type, or
+    /// method parameters are wrong.
+    pub fn method_call(
+        &mut self,
+        data: &ast::ExpressionMethodCall<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<Type> {
+        let receiver = self.expression(&data.receiver, body_state)?;
+        let method_name = data.name.to_string();
+        // Resolve method through struct type declared in global state
+        let func_data = self
+            .global
+            .types
+            .get(&receiver.expr_type.name())
+            .and_then(|ty| ty.get_method(method_name.clone()))
+            .and_then(|fn_name| self.global.functions.get(&fn_name).cloned());
+        let Some(func_data) = func_data else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::MethodNotFound,
+                format!("{}.{method_name}", receiver.expr_type),
+                data.location(),
+            ));
+            return None;
+        };
+        // Check receiver type
+        if func_data.parameters.first() != Some(&receiver.expr_type) {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::MethodReceiverWrongType,
+                receiver.expr_type.to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        if func_data.parameters.len() != data.parameters.len() + 1 {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::MethodParametersCountWrong,
+                format!("{}.{method_name}", receiver.expr_type),
+                data.location(),
+            ));
+            return None;
+        }
+        let fn_type = func_data.inner_type.clone();
+
+        // Receiver is first argument of the method
+        let mut params: Vec<ExpressionResult> = vec![receiver];
+        for (expr, param_type) in data.parameters.iter().zip(&func_data.parameters[1..]) {
+            let expr_result = self.expression(expr, body_state)?;
+            if &expr_result.expr_type != param_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::FunctionParameterTypeWrong,
+                    expr_result.expr_type.to_string(),
+                    data.location(),
+                ));
+                return None;
+            }
+            params.push(expr_result);
+        }
+
+        // Result of method call is stored to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .call(func_data, params, last_register_number);
+        Some(fn_type)
+    }
+
+    /// # Expression condition
+    /// Analyse condition between two expressions.
+    /// ## Return
+    /// Return result register of condition calculation. If analyzing
+    /// failed return `None`, and errors added to the state.
+    pub fn expression_condition(
+        &mut self,
+        data: &ast::ExpressionCondition<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<u64> {
+        // Analyse left expression of condition
+        let left_res = self.expression(&data.left, function_body_state);
+
+        // Analyse right expression of condition
+        let right_res = self.expression(&data.right, function_body_state);
+
+        // If some of the `left` or `right` expression is empty just return with error in the state
+        let (Some(left_res), Some(right_res)) = (left_res.clone(), right_res.clone()) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ConditionIsEmpty,
+                format!("left={left_res:?}, right={right_res:?}"),
+                data.left.location(),
+            ));
+            return None;
+        };
+
+        // Currently strict type comparison
         if left_res.expr_type != right_res.expr_type {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::ConditionExpressionWrongType,
                 left_res.expr_type.to_string(),
-                data.left.left.location(),
+                data.left.location(),
             ));
-            return function_body_state.borrow().last_register_number;
+            return None;
         }
-        if let Type::Primitive(_) = left_res.expr_type {
+        if let Type::Primitive(_) = left_res.expr_type.unalias() {
         } else {
             self.add_error(error::StateErrorResult::new(
                 error::StateErrorKind::ConditionExpressionNotSupported,
                 left_res.expr_type.to_string(),
-                data.left.left.location(),
+                data.left.location(),
             ));
-            return function_body_state.borrow().last_register_number;
+            return None;
         }
 
         // Increment register
         function_body_state.borrow_mut().inc_register();
 
         let register_number = function_body_state.borrow_mut().last_register_number;
-        // Codegen for left condition and set result to register
+        // Codegen for condition and set result to register
         function_body_state.borrow_mut().condition_expression(
             left_res,
             right_res,
-            data.left.condition.clone().into(),
+            data.condition.clone().into(),
             register_number,
         );
+        Some(register_number)
+    }
+
+    /// # condition-expression
+    /// Analyse condition operations.
+    /// ## Return
+    /// Return result register of `condition-expression` calculation.
+    pub fn condition_expression(
+        &mut self,
+        data: &ast::ExpressionLogicCondition<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> u64 {
+        // Analyse left condition
+        let Some(left_register_result) = self.expression_condition(&data.left, function_body_state)
+        else {
+            return function_body_state.borrow().last_register_number;
+        };
 
         // Analyze right condition
         if let Some(right) = &data.right {
-            let left_register_result = function_body_state.borrow_mut().last_register_number;
             // Analyse recursively right part of condition
             let right_register_result = self.condition_expression(&right.1, function_body_state);
 
@@ -696,6 +2230,63 @@ where
         function_body_state.borrow_mut().last_register_number
     }
 
+    /// # Short-circuit condition-expression
+    /// Analyse condition operations with short-circuit semantics.
+    /// Right part of logic condition is calculated only if left
+    /// condition doesn't determine the result:
+    /// - `And` - if left condition is "false" jump to `label_false`
+    /// - `Or` - if left condition is "true" jump to `label_true`
+    ///
+    /// Otherwise, jump to the next label, where right part of logic
+    /// condition is calculated. The last condition in the chain jumps
+    /// to `label_true` or `label_false` by its result.
+    pub fn condition_expression_short_circuit(
+        &mut self,
+        data: &ast::ExpressionLogicCondition<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+        label_true: &LabelName,
+        label_false: &LabelName,
+    ) {
+        // Analyse left condition
+        let Some(result_register) = self.expression_condition(&data.left, function_body_state)
+        else {
+            return;
+        };
+
+        if let Some((logic_condition, right)) = &data.right {
+            // Label for the right part of logic condition
+            let label_next = function_body_state
+                .borrow_mut()
+                .get_and_set_next_label(&"logic_next".to_string().into());
+            match logic_condition {
+                ast::LogicCondition::And => function_body_state.borrow_mut().if_condition_logic(
+                    label_next.clone(),
+                    label_false.clone(),
+                    result_register,
+                ),
+                ast::LogicCondition::Or => function_body_state.borrow_mut().if_condition_logic(
+                    label_true.clone(),
+                    label_next.clone(),
+                    result_register,
+                ),
+            }
+            function_body_state.borrow_mut().set_label(label_next);
+            // Analyse recursively right part of condition
+            self.condition_expression_short_circuit(
+                right,
+                function_body_state,
+                label_true,
+                label_false,
+            );
+        } else {
+            function_body_state.borrow_mut().if_condition_logic(
+                label_true.clone(),
+                label_false.clone(),
+                result_register,
+            );
+        }
+    }
+
     /// # If-condition body
     /// Analyze body for ant if condition:
     /// - if, else, if-else
@@ -722,6 +2313,9 @@ where
                 ast::IfBodyStatement::LetBinding(bind) => {
                     self.let_binding(bind, if_body_state);
                 }
+                ast::IfBodyStatement::LetTupleBinding(bind) => {
+                    self.let_tuple_binding(bind, if_body_state);
+                }
                 ast::IfBodyStatement::Binding(bind) => {
                     self.binding(bind, if_body_state);
                 }
@@ -739,6 +2333,24 @@ where
                 ast::IfBodyStatement::Loop(loop_statement) => {
                     self.loop_statement(loop_statement, if_body_state);
                 }
+                ast::IfBodyStatement::LabeledLoop(loop_statement) => {
+                    self.labeled_loop_statement(loop_statement, if_body_state);
+                }
+                ast::IfBodyStatement::While(while_statement) => {
+                    self.while_statement(while_statement, if_body_state);
+                }
+                ast::IfBodyStatement::For(for_statement) => {
+                    self.for_statement(for_statement, if_body_state);
+                }
+                ast::IfBodyStatement::Match(match_statement) => {
+                    return_is_called |=
+                        self.match_statement(match_statement, if_body_state, label_loop);
+                }
+                ast::IfBodyStatement::Block(block) => {
+                    let block_state = Self::block_state(if_body_state);
+                    return_is_called |=
+                        self.if_condition_body(block, &block_state, label_end, label_loop);
+                }
                 ast::IfBodyStatement::Return(expression) => {
                     let expr_result = self.expression(expression, if_body_state);
                     if let Some(res) = expr_result {
@@ -748,7 +2360,7 @@ where
                         if_body_state.borrow_mut().jump_function_return(res);
                         if_body_state.borrow_mut().set_return();
                         return_is_called = true;
-                    };
+                    }
                 }
             }
         }
@@ -760,6 +2372,7 @@ where
     /// - if, else, if-else
     /// ## Return
     /// Return body statement "return" status
+    #[allow(clippy::too_many_lines)]
     pub fn if_condition_loop_body(
         &mut self,
         body: &[ast::IfLoopBodyStatement<'_, I, E>],
@@ -772,32 +2385,20 @@ where
         let mut break_is_called = false;
         let mut continue_is_called = false;
         for body in body {
-            if return_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterReturnDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
-            if break_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterBreakDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
-            if continue_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterContinueDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
+            self.check_loop_body_after_jump(
+                body,
+                return_is_called,
+                break_is_called,
+                continue_is_called,
+            );
 
             match body {
                 ast::IfLoopBodyStatement::LetBinding(bind) => {
                     self.let_binding(bind, if_body_state);
                 }
+                ast::IfLoopBodyStatement::LetTupleBinding(bind) => {
+                    self.let_tuple_binding(bind, if_body_state);
+                }
                 ast::IfLoopBodyStatement::Binding(bind) => {
                     self.binding(bind, if_body_state);
                 }
@@ -815,6 +2416,32 @@ where
                 ast::IfLoopBodyStatement::Loop(loop_statement) => {
                     self.loop_statement(loop_statement, if_body_state);
                 }
+                ast::IfLoopBodyStatement::LabeledLoop(loop_statement) => {
+                    self.labeled_loop_statement(loop_statement, if_body_state);
+                }
+                ast::IfLoopBodyStatement::While(while_statement) => {
+                    self.while_statement(while_statement, if_body_state);
+                }
+                ast::IfLoopBodyStatement::For(for_statement) => {
+                    self.for_statement(for_statement, if_body_state);
+                }
+                ast::IfLoopBodyStatement::Match(match_statement) => {
+                    return_is_called |= self.match_statement(
+                        match_statement,
+                        if_body_state,
+                        Some((label_loop_start, label_loop_end)),
+                    );
+                }
+                ast::IfLoopBodyStatement::Block(block) => {
+                    let block_state = Self::block_state(if_body_state);
+                    return_is_called |= self.if_condition_loop_body(
+                        block,
+                        &block_state,
+                        label_if_end,
+                        label_loop_start,
+                        label_loop_end,
+                    );
+                }
                 ast::IfLoopBodyStatement::Return(expression) => {
                     let expr_result = self.expression(expression, if_body_state);
                     if let Some(res) = expr_result {
@@ -832,11 +2459,36 @@ where
                     // of loop
                     if_body_state.borrow_mut().jump_to(label_loop_start.clone());
                 }
-                ast::IfLoopBodyStatement::Break => {
+                ast::IfLoopBodyStatement::Break | ast::IfLoopBodyStatement::BreakAt(_) => {
                     break_is_called = true;
+                    let location = match body {
+                        ast::IfLoopBodyStatement::BreakAt(location) => Some(location.clone()),
+                        _ => None,
+                    };
+                    let loop_labels = if_body_state.borrow().get_current_loop_labels();
+                    self.check_break_without_value(loop_labels.as_ref(), location);
                     // Break loop and jump to the end of loop
                     if_body_state.borrow_mut().jump_to(label_loop_end.clone());
                 }
+                ast::IfLoopBodyStatement::ContinueTo(label) => {
+                    continue_is_called = true;
+                    if let Some(loop_labels) = self.loop_labels(label, if_body_state) {
+                        if_body_state
+                            .borrow_mut()
+                            .jump_to(loop_labels.label_continue);
+                    }
+                }
+                ast::IfLoopBodyStatement::BreakTo(label) => {
+                    break_is_called = true;
+                    if let Some(loop_labels) = self.loop_labels(label, if_body_state) {
+                        self.check_break_without_value(Some(&loop_labels), Some(label.location()));
+                        if_body_state.borrow_mut().jump_to(loop_labels.label_break);
+                    }
+                }
+                ast::IfLoopBodyStatement::BreakValue(expression) => {
+                    break_is_called = true;
+                    self.loop_break_value(expression, if_body_state);
+                }
             }
         }
         return_is_called
@@ -862,6 +2514,18 @@ where
                 let Some(expr_result) = self.expression(expr, if_body_state) else {
                     return;
                 };
+                // Check is expression result type accepted as condition
+                if !self
+                    .condition_truthiness
+                    .is_valid_for_type(&expr_result.expr_type)
+                {
+                    self.add_error(error::StateErrorResult::new(
+                        error::StateErrorKind::IfConditionWrongType,
+                        expr_result.expr_type.to_string(),
+                        expr.location(),
+                    ));
+                    return;
+                }
 
                 // State for if-condition from expression and if-body start
                 if is_else {
@@ -880,6 +2544,18 @@ where
             }
             // If condition contains logic condition expression
             ast::IfCondition::Logic(expr_logic) => {
+                if self.logic_condition_short_circuit {
+                    let label_if_false = if is_else { label_if_else } else { label_if_end };
+                    // Analyse if-condition logic with jumps to if-body
+                    // labels directly
+                    self.condition_expression_short_circuit(
+                        expr_logic,
+                        if_body_state,
+                        label_if_begin,
+                        label_if_false,
+                    );
+                    return;
+                }
                 // Analyse if-condition logic
                 let result_register = self.condition_expression(expr_logic, if_body_state);
                 // State for if-condition-logic with if-body start
@@ -952,14 +2628,11 @@ where
             .borrow_mut()
             .get_and_set_next_label(&"if_else".to_string().into());
         // Set if-end label from previous context
-        let label_if_end = label_end.clone().map_or_else(
-            || {
-                if_body_state
-                    .borrow_mut()
-                    .get_and_set_next_label(&"if_end".to_string().into())
-            },
-            |label| label,
-        );
+        let label_if_end = label_end.clone().unwrap_or_else(|| {
+            if_body_state
+                .borrow_mut()
+                .get_and_set_next_label(&"if_end".to_string().into())
+        });
         // To set if-end as single return point check is it previously set
         let is_set_label_if_end = label_end.is_some();
         let is_else = data.else_statement.is_some() || data.else_if_statement.is_some();
@@ -1073,6 +2746,60 @@ where
         data: &[ast::LoopBodyStatement<'_, I, E>],
         function_body_state: &Rc<RefCell<BlockState<I>>>,
     ) {
+        self.loop_with_condition(data, function_body_state, None, None, None);
+    }
+
+    /// # Labeled loop
+    /// Loop statement with label name. Nested loops can `break` and
+    /// `continue` the loop by the label name.
+    pub fn labeled_loop_statement(
+        &mut self,
+        data: &ast::LabeledLoopStatement<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        self.loop_with_condition(
+            &data.body,
+            function_body_state,
+            None,
+            Some(&data.label),
+            None,
+        );
+    }
+
+    /// # While
+    /// While statement is loop with condition. Condition checked at
+    /// the loop begin, and if it's false jump to the loop end.
+    /// `continue` jumps to the loop begin, so condition is checked
+    /// again.
+    pub fn while_statement(
+        &mut self,
+        data: &ast::WhileStatement<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        self.loop_with_condition(
+            &data.body,
+            function_body_state,
+            Some(&data.condition),
+            data.label.as_ref(),
+            None,
+        );
+    }
+
+    /// Loop statement analyzer. If `condition` is set, it's checked
+    /// at the loop begin with jump to loop body or loop end. If `label`
+    /// is set, loop can be referred by the label name.
+    /// `expression_location` is set for value-yielding loop expression.
+    ///
+    /// ## Return
+    /// Return loop body state
+    fn loop_with_condition(
+        &mut self,
+        data: &[ast::LoopBodyStatement<'_, I, E>],
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+        condition: Option<&ast::IfCondition<'_, I, E>>,
+        label: Option<&ast::LoopLabel<'_>>,
+        expression_location: Option<CodeLocation>,
+    ) -> Rc<RefCell<BlockState<I>>> {
         // Create state for loop-body, from parent func state because
         // loop-state can contain sub-state, that can be independent from parent
         // state
@@ -1090,6 +2817,13 @@ where
         let label_loop_end = loop_body_state
             .borrow_mut()
             .get_and_set_next_label(&"loop_end".to_string().into());
+        loop_body_state.borrow_mut().loop_labels = Some(LoopLabels {
+            name: label.map(|label| label.clone().into()),
+            label_continue: label_loop_begin.clone(),
+            label_break: label_loop_end.clone(),
+            expression_location,
+            value_type: None,
+        });
 
         loop_body_state
             .borrow_mut()
@@ -1097,54 +2831,518 @@ where
         loop_body_state
             .borrow_mut()
             .set_label(label_loop_begin.clone());
+        if let Some(condition) = condition {
+            // Check condition: jump to loop body or loop end
+            let label_loop_body = loop_body_state
+                .borrow_mut()
+                .get_and_set_next_label(&"loop_body".to_string().into());
+            self.if_condition_calculation(
+                condition,
+                &loop_body_state,
+                &label_loop_body,
+                &label_loop_end,
+                &label_loop_end,
+                false,
+            );
+            loop_body_state.borrow_mut().set_label(label_loop_body);
+        }
 
-        let mut return_is_called = false;
-        let mut break_is_called = false;
-        let mut continue_is_called = false;
-        for body in data {
-            if return_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterReturnDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
-            if break_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterBreakDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
-            if continue_is_called {
-                self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::ForbiddenCodeAfterContinueDeprecated,
-                    format!("{body:?}"),
-                    CodeLocation::new(1, 1),
-                ));
-            }
+        let return_is_called =
+            self.loop_body(data, &loop_body_state, &label_loop_begin, &label_loop_end);
 
-            match body {
-                ast::LoopBodyStatement::LetBinding(bind) => {
-                    self.let_binding(bind, &loop_body_state);
-                }
-                ast::LoopBodyStatement::Binding(bind) => {
-                    self.binding(bind, &loop_body_state);
-                }
-                ast::LoopBodyStatement::FunctionCall(fn_call) => {
-                    self.function_call(fn_call, &loop_body_state);
-                }
+        // If return is called do not set loop-specific instructions
+        if !return_is_called {
+            // Because it's loop jump to loop begin
+            loop_body_state
+                .borrow_mut()
+                .jump_to(label_loop_begin.clone());
+        }
+        // Loop ending. For loop with condition it's always reachable
+        // when condition is false. For loop expression it's reachable
+        // by `break value`.
+        let is_break_value = loop_body_state
+            .borrow()
+            .loop_labels
+            .as_ref()
+            .is_some_and(|loop_labels| loop_labels.value_type.is_some());
+        if !return_is_called || condition.is_some() || is_break_value {
+            loop_body_state.borrow_mut().set_label(label_loop_end);
+        }
+        loop_body_state
+    }
+
+    /// # For
+    /// For statement is counted loop over integer range `start..end`
+    /// with optional constant `step`, default step is 1. Positive step
+    /// counts up while counter is less than range end, negative step
+    /// counts down while counter is greater than range end. Range
+    /// bounds calculated once before the loop. Loop counter is internal
+    /// mutable value, and immutable induction value declared in the
+    /// loop state from the counter for each loop step. Loop step
+    /// contains:
+    /// - compare counter with range end
+    /// - loop body
+    /// - increment counter by step, `continue` jumps here. If counter
+    ///   increment overflows range type, loop is finished.
+    ///
+    /// If range is invalid, loop body is still analyzed.
+    pub fn for_statement(
+        &mut self,
+        data: &ast::ForStatement<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        // Create state for loop-body, induction value is visible only
+        // in the loop state
+        let loop_body_state = Rc::new(RefCell::new(BlockState::new(Some(
+            function_body_state.clone(),
+        ))));
+        function_body_state
+            .borrow_mut()
+            .set_child(loop_body_state.clone());
+        let start = self.expression(&data.start, &loop_body_state);
+        let range = start
+            .as_ref()
+            .and_then(|start| self.for_range(data, start, &loop_body_state));
+        let Some((start, (end, step, step_limit))) = start.clone().zip(range) else {
+            self.for_invalid_range_body(data, start, &loop_body_state);
+            return;
+        };
+        let range_type = start.expr_type.clone();
+        // Counter is not visible by name in the loop body
+        let counter_name = loop_body_state
+            .borrow()
+            .get_next_inner_name(&data.name.name().into());
+        loop_body_state
+            .borrow_mut()
+            .set_inner_value_name(&counter_name);
+        let counter = Value {
+            inner_name: counter_name,
+            inner_type: range_type.clone(),
+            mutable: true,
+            alloca: false,
+            malloc: false,
+        };
+        loop_body_state
+            .borrow_mut()
+            .let_binding(counter.clone(), start);
+
+        let [label_loop_begin, label_loop_body, label_loop_increment, label_loop_end] =
+            Self::for_loop_labels(data, &loop_body_state);
+        loop_body_state
+            .borrow_mut()
+            .jump_to(label_loop_begin.clone());
+        loop_body_state
+            .borrow_mut()
+            .set_label(label_loop_begin.clone());
+
+        // Compare counter with range end
+        let is_ascending = matches!(
+            &step.expr_value,
+            ExpressionResultValue::PrimitiveValue(step_value)
+                if step_value.integer_value().unwrap_or_default() > 0
+        );
+        let counter_register = Self::for_counter_condition(
+            &loop_body_state,
+            &counter,
+            end,
+            if is_ascending {
+                Condition::Less
+            } else {
+                Condition::Great
+            },
+            &label_loop_body,
+            &label_loop_end,
+        );
+        loop_body_state.borrow_mut().set_label(label_loop_body);
+        let name: ValueName = data.name.clone().into();
+        let alloca = self.alloca_values.is_alloca(&name, false);
+        let value = Self::declare_value(&loop_body_state, name, range_type, false, alloca);
+        loop_body_state
+            .borrow_mut()
+            .let_binding(value, counter_register);
+
+        let return_is_called = self.loop_body(
+            &data.body,
+            &loop_body_state,
+            &label_loop_increment,
+            &label_loop_end,
+        );
+        // If body always returns, increment is reachable only by `continue`
+        let increment_is_used =
+            loop_body_state
+                .borrow()
+                .get_context()
+                .get()
+                .contains(&SemanticStackContext::JumpTo {
+                    label: label_loop_increment.clone(),
+                });
+        if !return_is_called || increment_is_used {
+            Self::for_counter_increment(
+                &loop_body_state,
+                counter,
+                step,
+                step_limit,
+                is_ascending,
+                [
+                    label_loop_increment,
+                    label_loop_begin,
+                    label_loop_end.clone(),
+                ],
+            );
+        }
+        loop_body_state.borrow_mut().set_label(label_loop_end);
+    }
+
+    /// Get `for` statement loop labels: loop begin, body, increment
+    /// and end. And set loop labels for `break` and `continue` to the
+    /// loop state.
+    fn for_loop_labels(
+        data: &ast::ForStatement<'_, I, E>,
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> [LabelName; 4] {
+        let labels = ["loop_begin", "loop_body", "loop_increment", "loop_end"].map(|label| {
+            loop_body_state
+                .borrow_mut()
+                .get_and_set_next_label(&label.to_string().into())
+        });
+        loop_body_state.borrow_mut().loop_labels = Some(LoopLabels {
+            name: data.label.clone().map(Into::into),
+            label_continue: labels[2].clone(),
+            label_break: labels[3].clone(),
+            expression_location: None,
+            value_type: None,
+        });
+        labels
+    }
+
+    /// Analyze `for` statement loop body for invalid range, to report
+    /// loop body errors. Induction value declared with range start
+    /// type, if range start is valid.
+    fn for_invalid_range_body(
+        &mut self,
+        data: &ast::ForStatement<'_, I, E>,
+        start: Option<ExpressionResult>,
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        let [_, _, label_loop_increment, label_loop_end] =
+            Self::for_loop_labels(data, loop_body_state);
+        if let Some(start) = start {
+            let name: ValueName = data.name.clone().into();
+            let value =
+                Self::declare_value(loop_body_state, name, start.expr_type.clone(), false, false);
+            loop_body_state.borrow_mut().let_binding(value, start);
+        }
+        self.loop_body(
+            &data.body,
+            loop_body_state,
+            &label_loop_increment,
+            &label_loop_end,
+        );
+    }
+
+    /// Increment `for` statement loop counter by step, and jump to the
+    /// loop begin. If counter is beyond step limit: greater for
+    /// ascending loop, or less for descending loop, increment
+    /// overflows and loop is finished.
+    fn for_counter_increment(
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+        counter: Value,
+        step: ExpressionResult,
+        step_limit: ExpressionResult,
+        is_ascending: bool,
+        [label_loop_increment, label_loop_begin, label_loop_end]: [LabelName; 3],
+    ) {
+        let label_loop_step = loop_body_state
+            .borrow_mut()
+            .get_and_set_next_label(&"loop_step".to_string().into());
+        loop_body_state.borrow_mut().set_label(label_loop_increment);
+        let counter_register = Self::for_counter_condition(
+            loop_body_state,
+            &counter,
+            step_limit,
+            if is_ascending {
+                Condition::Great
+            } else {
+                Condition::Less
+            },
+            &label_loop_end,
+            &label_loop_step,
+        );
+        loop_body_state.borrow_mut().set_label(label_loop_step);
+        loop_body_state.borrow_mut().inc_register();
+        let increment_register = loop_body_state.borrow().last_register_number;
+        loop_body_state.borrow_mut().expression_operation(
+            ExpressionOperations::Plus,
+            counter_register,
+            step,
+            increment_register,
+        );
+        let expr_type = counter.inner_type.clone();
+        loop_body_state.borrow_mut().binding(
+            counter,
+            ExpressionResult {
+                expr_type,
+                expr_value: ExpressionResultValue::Register(increment_register),
+            },
+        );
+        loop_body_state.borrow_mut().jump_to(label_loop_begin);
+    }
+
+    /// Compare `for` statement loop counter value with `right` value
+    /// and jump to `label_true` or `label_false` by the result.
+    ///
+    /// ## Return
+    /// Counter value as register.
+    fn for_counter_condition(
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+        counter: &Value,
+        right: ExpressionResult,
+        condition: Condition,
+        label_true: &LabelName,
+        label_false: &LabelName,
+    ) -> ExpressionResult {
+        loop_body_state.borrow_mut().inc_register();
+        let counter_register = loop_body_state.borrow().last_register_number;
+        loop_body_state
+            .borrow_mut()
+            .expression_value(counter.clone(), counter_register);
+        let counter_result = ExpressionResult {
+            expr_type: counter.inner_type.clone(),
+            expr_value: ExpressionResultValue::Register(counter_register),
+        };
+        loop_body_state.borrow_mut().inc_register();
+        let condition_register = loop_body_state.borrow().last_register_number;
+        loop_body_state.borrow_mut().condition_expression(
+            counter_result.clone(),
+            right,
+            condition,
+            condition_register,
+        );
+        loop_body_state.borrow_mut().if_condition_logic(
+            label_true.clone(),
+            label_false.clone(),
+            condition_register,
+        );
+        counter_result
+    }
+
+    /// Calculate `for` statement range end and step. Range bounds
+    /// and step should be the same integer type. Step should be a
+    /// constant: literal, constant or negated constant value. Step
+    /// should be non-zero, and negative only for signed range type.
+    /// If range bounds are constants, step direction should reach range
+    /// end. Step limit is range type bound minus step: counter beyond
+    /// step limit overflows on increment.
+    ///
+    /// ## Return
+    /// Range end, step and step limit expression results.
+    fn for_range(
+        &mut self,
+        data: &ast::ForStatement<'_, I, E>,
+        start: &ExpressionResult,
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<(ExpressionResult, ExpressionResult, ExpressionResult)> {
+        let range_type = match start.expr_type.unalias() {
+            Type::Primitive(ty) if ty.is_integer() => ty.clone(),
+            ty => {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ForRangeWrongType,
+                    ty.to_string(),
+                    data.location(),
+                ));
+                return None;
+            }
+        };
+        let end = self.expression(&data.end, loop_body_state)?;
+        let step = if let Some(step) = &data.step {
+            let Some(step_value) = self.constant_integer(step, loop_body_state) else {
+                // Analyze step expression to report its errors
+                self.expression(step, loop_body_state)?;
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ForRangeStepNotConstant,
+                    String::new(),
+                    data.location(),
+                ));
+                return None;
+            };
+            step_value
+        } else {
+            (start.expr_type.clone(), 1)
+        };
+        for expr_type in [&end.expr_type, &step.0] {
+            if *expr_type != start.expr_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ForRangeWrongType,
+                    format!("expected {}, found {expr_type}", start.expr_type),
+                    data.location(),
+                ));
+                return None;
+            }
+        }
+        let step_value = step.1;
+        let range_value = |range: &ExpressionResult| match &range.expr_value {
+            ExpressionResultValue::PrimitiveValue(value) => value.integer_value(),
+            ExpressionResultValue::Register(_) => None,
+        };
+        let is_wrong_direction = range_value(start)
+            .zip(range_value(&end))
+            .is_some_and(|(start, end)| (start < end) != (step_value > 0) && start != end);
+        let step_limit = if step_value > 0 {
+            PrimitiveValue::integer_max(&range_type)
+        } else {
+            PrimitiveValue::integer_min(&range_type)
+        }
+        .and_then(|limit| limit.integer_value())
+        .and_then(|limit| PrimitiveValue::integer(&range_type, limit - step_value));
+        let (Some(step_limit), false) = (step_limit, step_value == 0 || is_wrong_direction) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ForRangeStepWrong,
+                step_value.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let step = ExpressionResult {
+            expr_type: start.expr_type.clone(),
+            expr_value: ExpressionResultValue::PrimitiveValue(
+                PrimitiveValue::integer(&range_type, step_value).unwrap_or(PrimitiveValue::None),
+            ),
+        };
+        let step_limit = ExpressionResult {
+            expr_type: start.expr_type.clone(),
+            expr_value: ExpressionResultValue::PrimitiveValue(step_limit),
+        };
+        Some((end, step, step_limit))
+    }
+
+    /// Calculate constant integer expression value: integer literal,
+    /// constant, or negated constant integer expression. Values in the
+    /// block state shadow constants.
+    ///
+    /// ## Return
+    /// Expression type and value. `None` if expression isn't constant
+    /// integer.
+    fn constant_integer(
+        &self,
+        data: &ast::Expression<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<(Type, i128)> {
+        if data.operation.is_some() {
+            return None;
+        }
+        match &data.expression_value {
+            ast::ExpressionValue::PrimitiveValue(value) => {
+                let integer_value = PrimitiveValue::from(value.clone()).integer_value()?;
+                Some((value.get_type().into(), integer_value))
+            }
+            ast::ExpressionValue::ValueName(name) => {
+                if body_state
+                    .borrow()
+                    .get_value_name(&name.name().into())
+                    .is_some()
+                {
+                    return None;
+                }
+                let mut constant = self.global.constants.get(&name.name().into())?;
+                loop {
+                    if constant.constant_value.operation.is_some() {
+                        return None;
+                    }
+                    match &constant.constant_value.value {
+                        ConstantValue::Value(value) => {
+                            return Some((constant.constant_type.clone(), value.integer_value()?));
+                        }
+                        ConstantValue::Constant(name) => {
+                            constant = self.global.constants.get(name)?;
+                        }
+                    }
+                }
+            }
+            ast::ExpressionValue::UnaryOperation(op)
+                if op.operation == ast::UnaryOperations::Minus =>
+            {
+                let (ty, value) = self.constant_integer(&op.expression, body_state)?;
+                match ty.unalias() {
+                    Type::Primitive(primitive) if primitive.is_signed_integer() => {
+                        Some((ty, -value))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// # Loop body
+    /// Analyze loop body statements with `break` and `continue`
+    /// jumps to the loop labels.
+    ///
+    /// ## Return
+    /// Return body statement "return" status
+    #[allow(clippy::too_many_lines)]
+    fn loop_body(
+        &mut self,
+        data: &[ast::LoopBodyStatement<'_, I, E>],
+        loop_body_state: &Rc<RefCell<BlockState<I>>>,
+        label_loop_begin: &LabelName,
+        label_loop_end: &LabelName,
+    ) -> bool {
+        let mut return_is_called = false;
+        let mut break_is_called = false;
+        let mut continue_is_called = false;
+        for body in data {
+            self.check_loop_body_after_jump(
+                body,
+                return_is_called,
+                break_is_called,
+                continue_is_called,
+            );
+
+            match body {
+                ast::LoopBodyStatement::LetBinding(bind) => {
+                    self.let_binding(bind, loop_body_state);
+                }
+                ast::LoopBodyStatement::LetTupleBinding(bind) => {
+                    self.let_tuple_binding(bind, loop_body_state);
+                }
+                ast::LoopBodyStatement::Binding(bind) => {
+                    self.binding(bind, loop_body_state);
+                }
+                ast::LoopBodyStatement::FunctionCall(fn_call) => {
+                    self.function_call(fn_call, loop_body_state);
+                }
                 ast::LoopBodyStatement::If(if_condition) => self.if_condition(
                     if_condition,
-                    &loop_body_state,
+                    loop_body_state,
                     &None,
-                    Some((&label_loop_begin, &label_loop_end)),
+                    Some((label_loop_begin, label_loop_end)),
                 ),
                 ast::LoopBodyStatement::Loop(loop_statement) => {
-                    self.loop_statement(loop_statement, &loop_body_state);
+                    self.loop_statement(loop_statement, loop_body_state);
+                }
+                ast::LoopBodyStatement::LabeledLoop(loop_statement) => {
+                    self.labeled_loop_statement(loop_statement, loop_body_state);
+                }
+                ast::LoopBodyStatement::While(while_statement) => {
+                    self.while_statement(while_statement, loop_body_state);
+                }
+                ast::LoopBodyStatement::For(for_statement) => {
+                    self.for_statement(for_statement, loop_body_state);
+                }
+                ast::LoopBodyStatement::Match(match_statement) => {
+                    return_is_called |= self.match_statement(
+                        match_statement,
+                        loop_body_state,
+                        Some((label_loop_begin, label_loop_end)),
+                    );
+                }
+                ast::LoopBodyStatement::Block(block) => {
+                    let block_state = Self::block_state(loop_body_state);
+                    return_is_called |=
+                        self.loop_body(block, &block_state, label_loop_begin, label_loop_end);
                 }
                 ast::LoopBodyStatement::Return(expression) => {
-                    let expr_result = self.expression(expression, &loop_body_state);
+                    let expr_result = self.expression(expression, loop_body_state);
                     if let Some(res) = expr_result {
                         // Jump to return label in codegen and set return
                         // status to indicate function, that it's manual
@@ -1154,7 +3352,13 @@ where
                         return_is_called = true;
                     }
                 }
-                ast::LoopBodyStatement::Break => {
+                ast::LoopBodyStatement::Break | ast::LoopBodyStatement::BreakAt(_) => {
+                    let location = match body {
+                        ast::LoopBodyStatement::BreakAt(location) => Some(location.clone()),
+                        _ => None,
+                    };
+                    let loop_labels = loop_body_state.borrow().get_current_loop_labels();
+                    self.check_break_without_value(loop_labels.as_ref(), location);
                     // Break loop and jump to the end of loop
                     loop_body_state.borrow_mut().jump_to(label_loop_end.clone());
                     break_is_called = true;
@@ -1167,84 +3371,461 @@ where
                         .jump_to(label_loop_begin.clone());
                     continue_is_called = true;
                 }
+                ast::LoopBodyStatement::BreakTo(label) => {
+                    // Break labeled loop and jump to the end of that loop
+                    if let Some(loop_labels) = self.loop_labels(label, loop_body_state) {
+                        self.check_break_without_value(Some(&loop_labels), Some(label.location()));
+                        loop_body_state
+                            .borrow_mut()
+                            .jump_to(loop_labels.label_break);
+                    }
+                    break_is_called = true;
+                }
+                ast::LoopBodyStatement::ContinueTo(label) => {
+                    // Skip next step of labeled loop and jump to the
+                    // start of that loop
+                    if let Some(loop_labels) = self.loop_labels(label, loop_body_state) {
+                        loop_body_state
+                            .borrow_mut()
+                            .jump_to(loop_labels.label_continue);
+                    }
+                    continue_is_called = true;
+                }
+                ast::LoopBodyStatement::BreakValue(expression) => {
+                    // Break loop expression with value and jump to the
+                    // end of loop
+                    self.loop_break_value(expression, loop_body_state);
+                    break_is_called = true;
+                }
             }
         }
+        return_is_called
+    }
 
-        // If return is called do not set loop-specific instructions
-        if !return_is_called {
-            // Because it's loop jump to loop begin
-            loop_body_state
-                .borrow_mut()
-                .jump_to(label_loop_begin.clone());
+    /// Check is loop body statement placed after `return`, `break` or
+    /// `continue` of the same body, as such code is unreachable.
+    fn check_loop_body_after_jump(
+        &mut self,
+        body: &impl std::fmt::Debug,
+        return_is_called: bool,
+        break_is_called: bool,
+        continue_is_called: bool,
+    ) {
+        for (is_called, kind) in [
+            (
+                return_is_called,
+                error::StateErrorKind::ForbiddenCodeAfterReturnDeprecated,
+            ),
+            (
+                break_is_called,
+                error::StateErrorKind::ForbiddenCodeAfterBreakDeprecated,
+            ),
+            (
+                continue_is_called,
+                error::StateErrorKind::ForbiddenCodeAfterContinueDeprecated,
+            ),
+        ] {
+            if is_called {
+                self.add_error(error::StateErrorResult::new(
+                    kind,
+                    format!("{body:?}"),
+                    CodeLocation::new(1, 1),
+                ));
+            }
+        }
+    }
 
-            // Loop ending
-            loop_body_state.borrow_mut().set_label(label_loop_end);
+    /// Get jump labels of the enclosing loop with `label` name. If loop
+    /// with the label not found, it's error.
+    fn loop_labels(
+        &mut self,
+        label: &ast::LoopLabel<'_>,
+        state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<LoopLabels> {
+        let loop_labels = state.borrow().get_loop_labels(&label.clone().into());
+        if loop_labels.is_none() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::LoopLabelNotFound,
+                label.name(),
+                label.location(),
+            ));
         }
+        loop_labels
     }
 
-    #[allow(clippy::doc_markdown)]
-    /// ## Expression
-    /// Is basic entity for state operation and state usage.
-    /// State correctness verified by expressions call.
-    /// Expressions folded by operations priority. For that
-    /// expressions tree folded each leaf of tree by priority operation
-    /// level. The most striking image is bracketing an expression with
-    /// a higher priority, and build tree based on that.
-    ///
-    /// ## Return
-    /// `PrimitiveValue` | `TmpRegister`
-    ///
-    ///  Possible algorithm conditions:
-    ///     1. PrimitiveValue -> PrimitiveValue
-    ///     2. Value -> load -> TmpRegister
-    ///     3. FuncCall -> call -> TmpRegister
-    ///     4. Operations
-    ///         4.1. PrimitiveValue
-    ///         - PrimitiveValue -> tmp = OP val1, val2 -> TmpRegister
-    ///         - Value -> tmp1 = load -> OP val1, tmp1 -> TmpRegister
-    ///         - FuncCAll -> tmp1 = call -> OP val1, tmp1 -> TmpRegister
-    ///         4.2. TmpRegister (with name tmp1)
-    ///         - PrimitiveValue -> tmp2 = OP tmp1, val1 -> TmpRegister
-    ///         - Value -> tmp2 = load -> tmp3 = OP tmp1, tmp2 -> TmpRegister
-    ///         - FuncCall -> tmp2 = call ->  tmp3 = OP tmp1, tmp2 -> TmpRegister
-    ///         4.3. Operations -> recursively invoke 4.2.
-    pub fn expression(
+    /// `break` without value is forbidden for the loop expression, as
+    /// loop expression should yield value. Error is reported at `break`
+    /// location, or at the loop expression location if `break` location
+    /// isn't set.
+    fn check_break_without_value(
         &mut self,
-        data: &ast::Expression<'_, I, E>,
-        body_state: &Rc<RefCell<BlockState<I>>>,
-    ) -> Option<ExpressionResult> {
-        // Fold expression operations priority
-        let expr = Self::expression_operations_priority(data.clone());
-        // To analyze expression first time, we set:
-        // left_value - as None
-        // operation - as None
-        // And basic expression value is `right_value`, because
-        // it can contain sub-operations (`left_value` don't contain
-        // and contain Expression result)
-        self.expression_operation(None, &expr, None, body_state)
+        loop_labels: Option<&LoopLabels>,
+        location: Option<CodeLocation>,
+    ) {
+        if let Some(expression_location) =
+            loop_labels.and_then(|loop_labels| loop_labels.expression_location.clone())
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::LoopExpressionBreakWithoutValue,
+                "break".to_string(),
+                location.unwrap_or(expression_location),
+            ));
+        }
     }
 
-    /// Expression operation semantic logic:
-    /// `OP(lhs, rhs)`
-    /// Left-value contains optional Expression result for left side
-    /// of expression.
-    #[allow(clippy::too_many_lines)]
-    pub fn expression_operation(
+    /// Analyze `break value` of the innermost loop, that should be loop
+    /// expression. All `break value` values of the loop should have the
+    /// same type. Value is incoming to the loop end merge point.
+    fn loop_break_value(
         &mut self,
-        left_value: Option<&ExpressionResult>,
-        right_expression: &ast::Expression<'_, I, E>,
-        op: Option<&ast::ExpressionOperations>,
-        body_state: &Rc<RefCell<BlockState<I>>>,
-    ) -> Option<ExpressionResult> {
-        // Get right side value from expression.
-        // If expression return error immediately return error
-        // because next analyzer should use success result.
+        expression: &ast::Expression<'_, I, E>,
+        state: &Rc<RefCell<BlockState<I>>>,
+    ) {
+        let Some(loop_labels) = state.borrow().get_current_loop_labels() else {
+            return;
+        };
+        if loop_labels.expression_location.is_none() {
+            let expr: Expression = expression.clone().into();
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::BreakValueInLoopStatement,
+                expr.to_string(),
+                expression.location(),
+            ));
+            return;
+        }
+        let Some(expr_result) = self.expression(expression, state) else {
+            return;
+        };
+        match &loop_labels.value_type {
+            Some(value_type) if value_type != &expr_result.expr_type => {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ExpressionBranchTypeMismatch,
+                    format!("expected {value_type}, found {}", expr_result.expr_type),
+                    expression.location(),
+                ));
+                return;
+            }
+            Some(_) => {}
+            None => state
+                .borrow_mut()
+                .set_loop_value_type(expr_result.expr_type.clone()),
+        }
+        state
+            .borrow_mut()
+            .merge_value(loop_labels.label_break.clone(), expr_result);
+        state.borrow_mut().jump_to(loop_labels.label_break);
+    }
+
+    /// # Match
+    /// Analyze match statement for integer, char, bool or enum value.
+    /// Match arm pattern is literal value, enum variant or wildcard.
+    /// Arms patterns should be unique and match should be exhaustive:
+    /// all values of the type covered, or wildcard arm is set. Each
+    /// arm has own state.
+    ///
+    /// Match is lowered to `switch` instruction with labels of arms.
+    /// For enum value `switch` is based on the enum discriminant.
+    /// Arm with loop body is allowed only in the loop.
+    ///
+    /// ## Return
+    /// Return "return" status: all arms return from function
+    pub fn match_statement(
+        &mut self,
+        data: &ast::MatchStatement<'_, I, E>,
+        function_body_state: &Rc<RefCell<BlockState<I>>>,
+        label_loop: Option<(&LabelName, &LabelName)>,
+    ) -> bool {
+        // Create state for match, arms states are children of it
+        let match_state = Rc::new(RefCell::new(BlockState::new(Some(
+            function_body_state.clone(),
+        ))));
+        function_body_state
+            .borrow_mut()
+            .set_child(match_state.clone());
+        let Some(expr_result) = self.expression(&data.value, &match_state) else {
+            return false;
+        };
+        let Some(cases) = self.match_cases(data, &expr_result) else {
+            return false;
+        };
+        // Enum value matched by discriminant
+        let switch_value = if let Some(enum_type) = expr_result.expr_type.get_enum() {
+            match_state.borrow_mut().inc_register();
+            let last_register_number = match_state.borrow().last_register_number;
+            match_state
+                .borrow_mut()
+                .enum_discriminant(expr_result, last_register_number);
+            ExpressionResult {
+                expr_type: Type::Primitive(enum_type.discriminant_type()),
+                expr_value: ExpressionResultValue::Register(last_register_number),
+            }
+        } else {
+            expr_result
+        };
+
+        let label_match_end = match_state
+            .borrow_mut()
+            .get_and_set_next_label(&"match_end".to_string().into());
+        let labels = data
+            .arms
+            .iter()
+            .map(|_| {
+                match_state
+                    .borrow_mut()
+                    .get_and_set_next_label(&"match_arm".to_string().into())
+            })
+            .collect::<Vec<_>>();
+        // Wildcard arm is default case. Without wildcard match is
+        // exhaustive, and default case is unreachable.
+        let mut label_default = label_match_end.clone();
+        let mut switch_cases = vec![];
+        for (case, label) in cases.into_iter().zip(&labels) {
+            match case {
+                Some(value) => switch_cases.push((value, label.clone())),
+                None => label_default = label.clone(),
+            }
+        }
+        match_state
+            .borrow_mut()
+            .switch(switch_value, switch_cases, label_default);
+
+        let mut all_arms_return = !data.arms.is_empty();
+        for (arm, label) in data.arms.iter().zip(labels) {
+            let arm_state = Rc::new(RefCell::new(BlockState::new(Some(match_state.clone()))));
+            match_state.borrow_mut().set_child(arm_state.clone());
+            arm_state.borrow_mut().set_label(label);
+            let return_is_called = match (&arm.body, label_loop) {
+                (ast::IfBodyStatements::If(body), _) => {
+                    self.if_condition_body(body, &arm_state, &label_match_end, label_loop)
+                }
+                (ast::IfBodyStatements::Loop(body), Some((label_loop_start, label_loop_end))) => {
+                    self.if_condition_loop_body(
+                        body,
+                        &arm_state,
+                        &label_match_end,
+                        label_loop_start,
+                        label_loop_end,
+                    )
+                }
+                (ast::IfBodyStatements::Loop(_), None) => {
+                    self.add_error(error::StateErrorResult::new(
+                        error::StateErrorKind::MatchLoopArmOutsideLoop,
+                        MatchPattern::from(arm.pattern.clone()).to_string(),
+                        arm.location(),
+                    ));
+                    false
+                }
+            };
+            all_arms_return &= return_is_called;
+            // If return is set do not add jump-to-end label.
+            if !return_is_called {
+                arm_state.borrow_mut().jump_to(label_match_end.clone());
+            }
+        }
+        match_state.borrow_mut().set_label(label_match_end);
+        all_arms_return
+    }
+
+    /// Calculate match arms cases values, check arms patterns and
+    /// match exhaustiveness.
+    ///
+    /// ## Return
+    /// Case value for each arm. For wildcard arm value is `None`.
+    fn match_cases(
+        &mut self,
+        data: &ast::MatchStatement<'_, I, E>,
+        value: &ExpressionResult,
+    ) -> Option<Vec<Option<PrimitiveValue>>> {
+        let value_type = &value.expr_type;
+        let enum_type = value_type.get_enum();
+        if enum_type.is_none()
+            && !matches!(
+                value_type.type_class(),
+                TypeClass::Integer | TypeClass::Char | TypeClass::Bool
+            )
+        {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::MatchValueWrongType,
+                value_type.to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        let mut cases: Vec<Option<PrimitiveValue>> = vec![];
+        for arm in &data.arms {
+            let (case, pattern) = match &arm.pattern {
+                ast::MatchPattern::Wildcard => (None, "_".to_string()),
+                ast::MatchPattern::Value(pattern_value) => {
+                    let pattern_type: Type = pattern_value.get_type().into();
+                    if &pattern_type != value_type {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::MatchPatternWrongType,
+                            format!("expected {value_type}, found {pattern_type}"),
+                            arm.location(),
+                        ));
+                        return None;
+                    }
+                    let case: PrimitiveValue = pattern_value.clone().into();
+                    let pattern = case.to_string();
+                    (Some(case), pattern)
+                }
+                ast::MatchPattern::EnumVariant(variant_name) => {
+                    let Some(ty) = &enum_type else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::MatchPatternWrongType,
+                            format!("expected {value_type}, found {}", variant_name.fragment()),
+                            arm.location(),
+                        ));
+                        return None;
+                    };
+                    let pattern = format!("{}::{}", ty.name, variant_name.fragment());
+                    let Some(variant) = ty.get_variant(variant_name.fragment()) else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::EnumVariantNotFound,
+                            pattern,
+                            arm.location(),
+                        ));
+                        return None;
+                    };
+                    let Some(discriminant) = ty.discriminant_value(variant) else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::EnumVariantsTooMany,
+                            pattern,
+                            arm.location(),
+                        ));
+                        return None;
+                    };
+                    (Some(discriminant), pattern)
+                }
+            };
+            // Arms after wildcard never matched
+            if cases.contains(&None) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::MatchArmUnreachable,
+                    pattern,
+                    arm.location(),
+                ));
+                return None;
+            }
+            if cases.contains(&case) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::MatchArmDuplicate,
+                    pattern,
+                    arm.location(),
+                ));
+                return None;
+            }
+            cases.push(case);
+        }
+
+        // Check exhaustiveness for match without wildcard arm
+        if !cases.contains(&None) {
+            let missing = Self::match_missing_cases(value_type, &cases);
+            if !missing.is_empty() {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::MatchNotExhaustive,
+                    missing.join(", "),
+                    data.location(),
+                ));
+                return None;
+            }
+        }
+        Some(cases)
+    }
+
+    /// Get patterns of the match value type, that not covered by
+    /// match `cases`. Integer type values covered only for small
+    /// integer types, and char values can't be all covered.
+    fn match_missing_cases(value_type: &Type, cases: &[Option<PrimitiveValue>]) -> Vec<String> {
+        match value_type.unalias() {
+            Type::Enum(ty) => ty
+                .variants
+                .iter()
+                .filter(|variant| {
+                    ty.discriminant_value(variant)
+                        .is_none_or(|discriminant| !cases.contains(&Some(discriminant)))
+                })
+                .map(|variant| format!("{}::{}", ty.name, variant.name))
+                .collect(),
+            Type::Primitive(PrimitiveTypes::Bool) => [true, false]
+                .into_iter()
+                .filter(|val| !cases.contains(&Some(PrimitiveValue::Bool(*val))))
+                .map(|val| val.to_string())
+                .collect(),
+            Type::Primitive(ty)
+                if ty.is_integer()
+                    && usize::try_from(1_u128 << ty.bit_width())
+                        .is_ok_and(|values_count| cases.len() >= values_count) =>
+            {
+                vec![]
+            }
+            _ => vec!["_".to_string()],
+        }
+    }
+
+    #[allow(clippy::doc_markdown)]
+    /// ## Expression
+    /// Is basic entity for state operation and state usage.
+    /// State correctness verified by expressions call.
+    /// Expressions folded by operations priority. For that
+    /// expressions tree folded each leaf of tree by priority operation
+    /// level. The most striking image is bracketing an expression with
+    /// a higher priority, and build tree based on that.
+    ///
+    /// ## Return
+    /// `PrimitiveValue` | `TmpRegister`
+    ///
+    ///  Possible algorithm conditions:
+    ///     1. PrimitiveValue -> PrimitiveValue
+    ///     2. Value -> load -> TmpRegister
+    ///     3. FuncCall -> call -> TmpRegister
+    ///     4. Operations
+    ///         4.1. PrimitiveValue
+    ///         - PrimitiveValue -> tmp = OP val1, val2 -> TmpRegister
+    ///         - Value -> tmp1 = load -> OP val1, tmp1 -> TmpRegister
+    ///         - FuncCAll -> tmp1 = call -> OP val1, tmp1 -> TmpRegister
+    ///         4.2. TmpRegister (with name tmp1)
+    ///         - PrimitiveValue -> tmp2 = OP tmp1, val1 -> TmpRegister
+    ///         - Value -> tmp2 = load -> tmp3 = OP tmp1, tmp2 -> TmpRegister
+    ///         - FuncCall -> tmp2 = call ->  tmp3 = OP tmp1, tmp2 -> TmpRegister
+    ///         4.3. Operations -> recursively invoke 4.2.
+    pub fn expression(
+        &mut self,
+        data: &ast::Expression<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        // Fold expression operations priority
+        let expr = Self::expression_operations_priority(data.clone());
+        // To analyze expression first time, we set:
+        // left_value - as None
+        // operation - as None
+        // And basic expression value is `right_value`, because
+        // it can contain sub-operations (`left_value` don't contain
+        // and contain Expression result)
+        self.expression_operation(None, &expr, None, body_state)
+    }
+
+    /// Expression operation semantic logic:
+    /// `OP(lhs, rhs)`
+    /// Left-value contains optional Expression result for left side
+    /// of expression.
+    #[allow(clippy::too_many_lines)]
+    pub fn expression_operation(
+        &mut self,
+        left_value: Option<&ExpressionResult>,
+        right_expression: &ast::Expression<'_, I, E>,
+        op: Option<&ast::ExpressionOperations>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        // Get right side value from expression.
+        // If expression return error immediately return error
+        // because next analyzer should use success result.
         let right_value = match &right_expression.expression_value {
             ast::ExpressionValue::_marker(..) => unreachable!(),
             // Check is expression Value entity
             ast::ExpressionValue::ValueName(value) => {
                 // Get value from block state
-                let value_from_state = body_state.borrow_mut().get_value_name(&value.name().into());
+                let value_from_state = self.get_value(&value.name().into(), body_state);
                 // Register contains result
                 body_state.borrow_mut().inc_register();
                 let last_register_number = body_state.borrow().last_register_number;
@@ -1303,18 +3884,15 @@ where
                 let struct_value: ExpressionStructValue = value.clone().into();
                 // Can be only Value from state, not constant
                 // Get value from block state
-                let val = body_state
-                    .borrow_mut()
-                    .get_value_name(&struct_value.name)
-                    .or_else(|| {
-                        // If value doesn't exist
-                        self.add_error(error::StateErrorResult::new(
-                            error::StateErrorKind::ValueNotFound,
-                            value.name.name(),
-                            value.name.location(),
-                        ));
-                        None
-                    })?;
+                let val = self.get_value(&struct_value.name, body_state).or_else(|| {
+                    // If value doesn't exist
+                    self.add_error(error::StateErrorResult::new(
+                        error::StateErrorKind::ValueNotFound,
+                        value.name.name(),
+                        value.name.location(),
+                    ));
+                    None
+                })?;
                 // Check is value type is struct
                 let ty = val.inner_type.get_struct().or_else(|| {
                     self.add_error(error::StateErrorResult::new(
@@ -1337,31 +3915,47 @@ where
                     return None;
                 }
 
-                let attributes = ty
-                    .attributes
-                    .get(&struct_value.attribute)
-                    .or_else(|| {
+                // Resolve attributes path step by step for nested
+                // structures attributes
+                let mut attr_type = Type::Struct(ty);
+                let mut path = vec![];
+                for attribute in std::iter::once(&value.attribute).chain(&value.path) {
+                    let Some(ty) = attr_type.get_struct() else {
+                        self.add_error(error::StateErrorResult::new(
+                            error::StateErrorKind::ValueNotStruct,
+                            attribute.name(),
+                            attribute.location(),
+                        ));
+                        return None;
+                    };
+                    let attr_name: ValueName = attribute.clone().into();
+                    let (Some(attr_index), Some(ty)) = (
+                        ty.get_attribute_index(&attr_name),
+                        ty.get_attribute_type(&attr_name),
+                    ) else {
                         self.add_error(error::StateErrorResult::new(
                             error::StateErrorKind::ValueNotStructField,
-                            value.name.name(),
-                            value.name.location(),
+                            attribute.name(),
+                            attribute.location(),
                         ));
-                        None
-                    })?
-                    .clone();
+                        return None;
+                    };
+                    path.push(attr_index);
+                    attr_type = ty;
+                }
 
                 // Register contains result
                 body_state.borrow_mut().inc_register();
                 let last_register_number = body_state.borrow().last_register_number;
                 body_state.borrow_mut().expression_struct_value(
                     val.clone(),
-                    attributes.attr_index,
+                    path,
                     last_register_number,
                 );
 
                 body_state.borrow_mut().inc_register();
                 ExpressionResult {
-                    expr_type: attributes.attr_type,
+                    expr_type: attr_type,
                     expr_value: ExpressionResultValue::Register(
                         body_state.borrow().last_register_number,
                     ),
@@ -1371,32 +3965,100 @@ where
                 // Subexpression should be analyzed independently
                 self.expression(expr, body_state)?
             }
+            ast::ExpressionValue::UnaryOperation(unary) => {
+                self.unary_operation(unary, body_state)?
+            }
+            ast::ExpressionValue::Cast(cast) => self.cast(cast, body_state)?,
+            ast::ExpressionValue::ArrayValue(values) => self.array_value(values, body_state)?,
+            ast::ExpressionValue::ArrayElement(element) => {
+                self.array_element(element, body_state)?
+            }
+            ast::ExpressionValue::StructLiteral(literal) => {
+                self.struct_literal(literal, body_state)?
+            }
+            ast::ExpressionValue::EnumVariant(variant) => self.enum_variant(variant, body_state)?,
+            ast::ExpressionValue::If(if_expression) => {
+                self.if_expression(if_expression, body_state)?
+            }
+            ast::ExpressionValue::Loop(loop_expression) => {
+                self.loop_expression(loop_expression, body_state)?
+            }
+            ast::ExpressionValue::TupleValue(values) => self.tuple_value(values, body_state)?,
+            ast::ExpressionValue::TupleElement(element) => {
+                self.tuple_element(element, body_state)?
+            }
+            ast::ExpressionValue::AddressOf(value) => self.address_of(value, body_state)?,
+            ast::ExpressionValue::Dereference(value) => self.dereference(value, body_state)?,
+            ast::ExpressionValue::FunctionValue(name) => self.function_value(name, body_state)?,
+            ast::ExpressionValue::IndirectCall(call) => self.indirect_call(call, body_state)?,
+            ast::ExpressionValue::Closure(closure) => self.closure(closure, body_state)?,
+            ast::ExpressionValue::MethodCall(method_call) => {
+                // Same as for function call, result of method call
+                // always stored in register.
+                let method_call_ty = self.method_call(method_call, body_state)?;
+                // Return result as register
+                body_state.borrow_mut().inc_register();
+                ExpressionResult {
+                    expr_type: method_call_ty,
+                    expr_value: ExpressionResultValue::Register(
+                        body_state.borrow().last_register_number,
+                    ),
+                }
+            }
             ast::ExpressionValue::ExtendedExpression(expr) => expr.expression(self, body_state),
         };
         // Check left expression side and generate expression operation code
         let expression_result = if let (Some(left_value), Some(op)) = (left_value, op) {
+            let operation: ExpressionOperations = op.clone().into();
             if left_value.expr_type != right_value.expr_type {
+                // Comparison operations have dedicated error kind, as
+                // result type is differ from operands type
+                let kind = if operation.is_comparison() {
+                    error::StateErrorKind::ComparisonOperationWrongType
+                } else {
+                    error::StateErrorKind::WrongExpressionType
+                };
                 self.add_error(error::StateErrorResult::new(
-                    error::StateErrorKind::WrongExpressionType,
+                    kind,
                     left_value.expr_type.to_string(),
                     right_expression.location(),
                 ));
                 // Do not fetch other expression flow if type is wrong
                 return None;
             }
+            // Check is operation valid for operands type class
+            if !operation.is_valid_for_type(&right_value.expr_type) {
+                let expected = operation
+                    .type_classes()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::InvalidOperationForType,
+                    format!(
+                        "{operation:?}: expected {expected}, found {}",
+                        right_value.expr_type
+                    ),
+                    right_expression.location(),
+                ));
+                return None;
+            }
+            // Get result type of operation for operands type
+            let expr_type = operation.result_type(&right_value.expr_type);
             // Expression operation is set to register
             body_state.borrow_mut().inc_register();
             let last_register_number = body_state.borrow().last_register_number;
             // Call expression operation for: OP(left_value, right_value)
             body_state.borrow_mut().expression_operation(
-                op.clone().into(),
+                operation,
                 left_value.clone(),
-                right_value.clone(),
+                right_value,
                 last_register_number,
             );
             // Expression result value  for Operations is always should be "register"
             ExpressionResult {
-                expr_type: right_value.expr_type,
+                expr_type,
                 expr_value: ExpressionResultValue::Register(
                     body_state.borrow().last_register_number,
                 ),
@@ -1415,6 +4077,906 @@ where
         }
     }
 
+    /// # Unary operation
+    /// Analyze unary operation for expression. Expression is analyzed
+    /// independently as sub-expression. Type rules for operations:
+    /// - `Minus` - only signed integers and floats
+    /// - `Not` - only `bool`
+    /// - `BitwiseNot` - only integers
+    ///
+    /// Result of unary operation is always stored to register.
+    pub fn unary_operation(
+        &mut self,
+        data: &ast::ExpressionUnaryOperation<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let value = self.expression(&data.expression, body_state)?;
+        let is_valid_type = match value.expr_type.unalias() {
+            Type::Primitive(ty) => match data.operation {
+                ast::UnaryOperations::Minus => ty.is_signed_integer() || ty.is_float(),
+                ast::UnaryOperations::Not => ty == &PrimitiveTypes::Bool,
+                ast::UnaryOperations::BitwiseNot => ty.is_integer(),
+            },
+            _ => false,
+        };
+        if !is_valid_type {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::UnaryOperationWrongType,
+                value.expr_type.to_string(),
+                data.expression.location(),
+            ));
+            return None;
+        }
+        // Unary operation result is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().unary_operation(
+            data.operation.clone().into(),
+            value.clone(),
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: value.expr_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Cast
+    /// Analyse cast expression. Cast valid only for primitive types
+    /// according to cast rules of primitive types.
+    ///
+    /// ## Return
+    /// Expression result of cast. If cast is invalid return `None`.
+    pub fn cast(
+        &mut self,
+        data: &ast::ExpressionCast<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let value = self.expression(&data.expression, body_state)?;
+        let to: PrimitiveTypes = data.cast_type.clone().into();
+        let cast = match value.expr_type.unalias() {
+            Type::Primitive(from) => from.cast_kind(&to).map(|kind| (from.clone(), kind)),
+            _ => None,
+        };
+        let Some((from, kind)) = cast else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::InvalidCast,
+                format!("{} as {to}", value.expr_type),
+                data.expression.location(),
+            ));
+            return None;
+        };
+        // Cast result is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .cast(value, from, to.clone(), kind, last_register_number);
+        Some(ExpressionResult {
+            expr_type: Type::Primitive(to),
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Array value
+    /// Analyse array literal. All array elements should have the
+    /// same type, and array can't be empty. Array length should fit
+    /// array type length.
+    ///
+    /// ## Return
+    /// Expression result of array value. If array value is invalid
+    /// return `None`.
+    pub fn array_value(
+        &mut self,
+        data: &ast::ExpressionArrayValue<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        if data.values.is_empty() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ArrayValueEmpty,
+                "[]".to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        let Ok(length) = u32::try_from(data.values.len()) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ArrayValueTooLong,
+                data.values.len().to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let mut values = vec![];
+        for expr in &data.values {
+            values.push(self.expression(expr, body_state)?);
+        }
+        let element_type = values[0].expr_type.clone();
+        for (value, expr) in values.iter().zip(&data.values) {
+            if value.expr_type != element_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::ArrayElementWrongType,
+                    format!("expected {element_type}, found {}", value.expr_type),
+                    expr.location(),
+                ));
+                return None;
+            }
+        }
+        let array_type = Type::Array(Box::new(element_type), length);
+        // Array value is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().expression_array_value(
+            values,
+            array_type.clone(),
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: array_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Array element
+    /// Analyse access to array element by index. Index should be
+    /// integer, and for constant index it's checked array bounds.
+    ///
+    /// ## Return
+    /// Expression result of loaded array element. If array element
+    /// access is invalid return `None`.
+    pub fn array_element(
+        &mut self,
+        data: &ast::ExpressionArrayElement<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let array = self.expression(&data.array, body_state)?;
+        let Type::Array(element_type, size) = array.expr_type.unalias().clone() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotArray,
+                array.expr_type.to_string(),
+                data.array.location(),
+            ));
+            return None;
+        };
+        let index = self.array_index(&data.index, size, body_state)?;
+        // Array element address is set to register
+        body_state.borrow_mut().inc_register();
+        let address_register = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .array_element_address(array, index, address_register);
+        // Array element value loaded to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().load(
+            *element_type.clone(),
+            address_register,
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: *element_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Tuple value
+    /// Analyse tuple literal. Tuple type is built from elements
+    /// expressions types.
+    ///
+    /// ## Return
+    /// Expression result of tuple value. If any element is invalid
+    /// return `None`.
+    pub fn tuple_value(
+        &mut self,
+        data: &ast::ExpressionTupleValue<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let mut values = vec![];
+        for expr in &data.values {
+            values.push(self.expression(expr, body_state)?);
+        }
+        let tuple_type = Type::Tuple(values.iter().map(|value| value.expr_type.clone()).collect());
+        // Tuple value is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().expression_tuple_value(
+            values,
+            tuple_type.clone(),
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: tuple_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Tuple element
+    /// Analyse access to tuple element by constant index. Index
+    /// should be in tuple elements bounds.
+    ///
+    /// ## Return
+    /// Expression result of tuple element. If tuple element access is
+    /// invalid return `None`.
+    pub fn tuple_element(
+        &mut self,
+        data: &ast::ExpressionTupleElement<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let tuple = self.expression(&data.tuple, body_state)?;
+        let Some(element_types) = tuple.expr_type.get_tuple() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotTuple,
+                tuple.expr_type.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let Some(element_type) = usize::try_from(data.index)
+            .ok()
+            .and_then(|index| element_types.get(index))
+            .cloned()
+        else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TupleElementIndexOutOfBounds,
+                format!("{}.{}", tuple.expr_type, data.index),
+                data.location(),
+            ));
+            return None;
+        };
+        // Tuple element is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().tuple_element(
+            tuple,
+            data.index,
+            element_type.clone(),
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: element_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Address-of
+    /// Analyse reference to the value: `&value` or `&mut value`.
+    /// Mutable reference can be taken only for mutable value. Values,
+    /// that address is taken, are allocated in memory since their
+    /// declaration by function body pre-scan.
+    ///
+    /// ## Return
+    /// Expression result of reference. If value not found or
+    /// reference is invalid return `None`.
+    pub fn address_of(
+        &mut self,
+        data: &ast::ExpressionAddressOf<'_>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let address_of: ExpressionAddressOf = data.clone().into();
+        let Some(value) = self.get_value(&address_of.value, body_state) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotFound,
+                address_of.value.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        if address_of.mutable && !value.mutable {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueIsNotMutable,
+                address_of.to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        let pointee = Box::new(value.inner_type.clone());
+        let expr_type = if address_of.mutable {
+            Type::MutableReference(pointee)
+        } else {
+            Type::Reference(pointee)
+        };
+        // Value address is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .address_of(value, last_register_number);
+        Some(ExpressionResult {
+            expr_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Dereference
+    /// Analyse load of the value by pointer or reference: `*value`.
+    ///
+    /// ## Return
+    /// Expression result of loaded value. If expression isn't pointer
+    /// or reference return `None`.
+    pub fn dereference(
+        &mut self,
+        data: &ast::ExpressionDereference<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let pointer_result = self.expression(&data.expression, body_state)?;
+        let (Some(pointee), ExpressionResultValue::Register(address_register)) = (
+            pointer_result.expr_type.get_pointee(),
+            &pointer_result.expr_value,
+        ) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::DereferenceNotPointer,
+                pointer_result.expr_type.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        // Pointee value loaded to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state
+            .borrow_mut()
+            .load(pointee.clone(), *address_register, last_register_number);
+        Some(ExpressionResult {
+            expr_type: pointee,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Function value
+    /// Analyse function as value: pointer to the function. Generic
+    /// function can't be used as value, because its type arguments
+    /// can't be inferred.
+    ///
+    /// ## Return
+    /// Expression result of function pointer type. If function not
+    /// found return `None`.
+    pub fn function_value(
+        &mut self,
+        data: &ast::FunctionName<'_>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let name: FunctionName = data.clone().into();
+        if self.global.generic_functions.contains_key(&name) {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeParameterNotInferred,
+                name.to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        let Some(func_data) = self.global.functions.get(&name).cloned() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::FunctionNotFound,
+                name.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        // Function pointer is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        let expr_type = func_data.function_type();
+        body_state
+            .borrow_mut()
+            .function_address(func_data, last_register_number);
+        Some(ExpressionResult {
+            expr_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Indirect call
+    /// Analyse call of the function by function pointer or closure.
+    /// Parameters count and types should match function pointer or
+    /// closure type.
+    ///
+    /// ## Return
+    /// Expression result of function call. If callee isn't function
+    /// pointer or closure, or parameters are wrong return `None`.
+    pub fn indirect_call(
+        &mut self,
+        data: &ast::ExpressionIndirectCall<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let callee = self.expression(&data.callee, body_state)?;
+        let closure = match callee.expr_type.unalias() {
+            Type::Closure(closure_type) => self
+                .global
+                .closures
+                .get(&closure_type.name.clone().into())
+                .cloned(),
+            _ => None,
+        };
+        let signature = closure.as_ref().map_or_else(
+            || callee.expr_type.get_function(),
+            |closure| Some((closure.parameters.clone(), closure.inner_type.clone())),
+        );
+        let Some((parameters, result_type)) = signature else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotFunction,
+                callee.expr_type.to_string(),
+                data.callee.location(),
+            ));
+            return None;
+        };
+        if parameters.len() != data.parameters.len() {
+            let indirect_call: ExpressionIndirectCall = data.clone().into();
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::FunctionParametersCountWrong,
+                indirect_call.to_string(),
+                data.callee.location(),
+            ));
+            return None;
+        }
+        let mut params: Vec<ExpressionResult> = vec![];
+        for (expr, param_type) in data.parameters.iter().zip(&parameters) {
+            let expr_result = self.expression(expr, body_state)?;
+            if &expr_result.expr_type != param_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::FunctionParameterTypeWrong,
+                    expr_result.expr_type.to_string(),
+                    expr.location(),
+                ));
+                return None;
+            }
+            params.push(expr_result);
+        }
+        // Result of function call is stored to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        if let Some(closure) = closure {
+            body_state
+                .borrow_mut()
+                .closure_call(closure, callee, params, last_register_number);
+        } else {
+            body_state
+                .borrow_mut()
+                .indirect_call(callee, params, last_register_number);
+        }
+        Some(ExpressionResult {
+            expr_type: result_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Closure
+    /// Analyse closure declaration. Closure body analyzed as function
+    /// body in own closure body state, that enclosed by current state.
+    /// Values of enclosing states, used in closure body, are captured
+    /// to closure environment struct. Immutable values are captured by
+    /// value, and mutable values by reference. Closure declared in
+    /// `Global State` with unique name `closure.N`.
+    ///
+    /// ## Return
+    /// Expression result of closure type. Closure is declared even if
+    /// closure body contains errors.
+    pub fn closure(
+        &mut self,
+        data: &ast::ExpressionClosure<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let closure_state = Rc::new(RefCell::new(BlockState::new(None)));
+        closure_state.borrow_mut().enclosing = Some(body_state.clone());
+        let closure_function = ast::FunctionStatement::new(
+            ast::FunctionName::new(ast::Ident::new("closure")),
+            data.parameters.clone(),
+            data.result_type.clone(),
+            data.body.clone(),
+        );
+        // Closure is declared even with body errors, so only body
+        // errors are reported, without errors of the closure usage
+        self.function_body_state(&closure_function, &closure_state);
+
+        let inner_name: FunctionName = format!("closure.{}", self.global.closures.len()).into();
+        let captures = closure_state.borrow().captures.clone();
+        let mut attributes = HashMap::new();
+        for (attr_index, capture) in (0..).zip(&captures) {
+            let value_type = capture.value.inner_type.clone();
+            let attr_type = if capture.by_reference {
+                Type::MutableReference(Box::new(value_type))
+            } else {
+                value_type
+            };
+            attributes.insert(
+                capture.name.clone(),
+                StructAttributeType {
+                    attr_name: capture.name.clone(),
+                    attr_index,
+                    attr_type,
+                },
+            );
+        }
+        let environment = StructTypes {
+            name: format!("{inner_name}.environment"),
+            attributes,
+            methods: HashMap::new(),
+            instance: None,
+        };
+        self.global.types.insert(
+            environment.name.clone().into(),
+            Type::Struct(environment.clone()),
+        );
+        self.global.context.types(environment.clone());
+
+        let parameters = data
+            .parameters
+            .iter()
+            .map(|param| self.resolve_type(&param.parameter_type.clone().into()))
+            .collect();
+        let closure = Closure {
+            inner_name: inner_name.clone(),
+            inner_type: self.resolve_type(&data.result_type.clone().into()),
+            parameters,
+            environment,
+            captures,
+        };
+        self.global.closures.insert(inner_name, closure.clone());
+        self.global.context.closure_declaration(closure.clone());
+        self.closure_context.push(closure_state);
+
+        // Closure value is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        let expr_type = closure.closure_type();
+        body_state
+            .borrow_mut()
+            .create_closure(closure, last_register_number);
+        Some(ExpressionResult {
+            expr_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Struct literal
+    /// Analyse struct value construction. Struct type should exist,
+    /// all struct attributes should be initialized only once, with
+    /// values of struct attributes types.
+    ///
+    /// ## Return
+    /// Expression result of struct value. If struct literal is
+    /// invalid return `None`.
+    pub fn struct_literal(
+        &mut self,
+        data: &ast::ExpressionStructLiteral<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let type_name: TypeName = (*data.name.fragment()).to_string().into();
+        let Some(struct_type) = self.global.types.get(&type_name).cloned() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeNotFound,
+                type_name.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let Some(ty) = struct_type.get_struct() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotStruct,
+                type_name.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        // Fields values by struct attribute index
+        let mut fields: HashMap<u32, ExpressionResult> = HashMap::new();
+        for field in &data.fields {
+            let field_name: ValueName = field.name.clone().into();
+            let Some(attr) = ty.attributes.get(&field_name) else {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::StructLiteralFieldUnknown,
+                    format!("{type_name}.{field_name}"),
+                    field.name.location(),
+                ));
+                return None;
+            };
+            if fields.contains_key(&attr.attr_index) {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::StructLiteralFieldDuplicate,
+                    format!("{type_name}.{field_name}"),
+                    field.name.location(),
+                ));
+                return None;
+            }
+            let expr_result = self.expression(&field.value, body_state)?;
+            if expr_result.expr_type != attr.attr_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::StructLiteralFieldWrongType,
+                    format!(
+                        "{type_name}.{field_name}: expected {}, found {}",
+                        attr.attr_type, expr_result.expr_type
+                    ),
+                    field.name.location(),
+                ));
+                return None;
+            }
+            fields.insert(attr.attr_index, expr_result);
+        }
+        // Check is all struct attributes initialized
+        let mut missing_fields = ty
+            .attributes
+            .iter()
+            .filter(|(_, attr)| !fields.contains_key(&attr.attr_index))
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        if !missing_fields.is_empty() {
+            missing_fields.sort();
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::StructLiteralFieldMissing,
+                format!("{type_name}: {}", missing_fields.join(", ")),
+                data.location(),
+            ));
+            return None;
+        }
+        // Values should be ordered by struct attribute index
+        let mut fields = fields.into_iter().collect::<Vec<_>>();
+        fields.sort_by_key(|(index, _)| *index);
+        let values = fields.into_iter().map(|(_, value)| value).collect();
+
+        // Struct value is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().expression_struct_literal(
+            struct_type.clone(),
+            values,
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: struct_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # If expression
+    /// Analyze value-yielding if expression. Each branch has own state,
+    /// and yields value with the same type. Branches values are merged
+    /// at the if-end label to the register. Branch with `return` doesn't
+    /// yield value, but at least one branch should yield it.
+    ///
+    /// ## Return
+    /// Return merged value as register. If branches are invalid, or
+    /// all branches return from function, return `None`.
+    pub fn if_expression(
+        &mut self,
+        data: &ast::ExpressionIf<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let if_state = Rc::new(RefCell::new(BlockState::new(Some(body_state.clone()))));
+        body_state.borrow_mut().set_child(if_state.clone());
+        let [label_if_begin, label_if_else, label_if_end] =
+            ["if_begin", "if_else", "if_end"].map(|label| {
+                if_state
+                    .borrow_mut()
+                    .get_and_set_next_label(&label.to_string().into())
+            });
+        self.if_condition_calculation(
+            &data.condition,
+            &if_state,
+            &label_if_begin,
+            &label_if_else,
+            &label_if_end,
+            true,
+        );
+
+        let mut value_type: Option<Type> = None;
+        for (label, branch) in [
+            (label_if_begin, &data.body),
+            (label_if_else, &data.else_body),
+        ] {
+            if_state.borrow_mut().set_label(label);
+            let branch_state = Rc::new(RefCell::new(BlockState::new(Some(if_state.clone()))));
+            if_state.borrow_mut().set_child(branch_state.clone());
+            // Branch body has own end label, as nested if-statements
+            // jump to it, and the branch value calculated after it
+            let label_branch_end = branch_state
+                .borrow_mut()
+                .get_and_set_next_label(&"if_branch_end".to_string().into());
+            if self.if_condition_body(&branch.body, &branch_state, &label_branch_end, None) {
+                continue;
+            }
+            branch_state.borrow_mut().jump_to(label_branch_end.clone());
+            branch_state.borrow_mut().set_label(label_branch_end);
+            let expr_result = self.expression(&branch.value, &branch_state)?;
+            match &value_type {
+                Some(ty) if ty != &expr_result.expr_type => {
+                    self.add_error(error::StateErrorResult::new(
+                        error::StateErrorKind::ExpressionBranchTypeMismatch,
+                        format!("expected {ty}, found {}", expr_result.expr_type),
+                        branch.value.location(),
+                    ));
+                    return None;
+                }
+                Some(_) => {}
+                None => value_type = Some(expr_result.expr_type.clone()),
+            }
+            branch_state
+                .borrow_mut()
+                .merge_value(label_if_end.clone(), expr_result);
+            branch_state.borrow_mut().jump_to(label_if_end.clone());
+        }
+        let Some(value_type) = value_type else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::IfExpressionWithoutValue,
+                "all branches return from function".to_string(),
+                data.else_body.value.location(),
+            ));
+            return None;
+        };
+
+        if_state.borrow_mut().set_label(label_if_end.clone());
+        if_state.borrow_mut().inc_register();
+        let register_number = if_state.borrow().last_register_number;
+        if_state
+            .borrow_mut()
+            .merge_result(label_if_end, value_type.clone(), register_number);
+        Some(ExpressionResult {
+            expr_type: value_type,
+            expr_value: ExpressionResultValue::Register(register_number),
+        })
+    }
+
+    /// # Loop expression
+    /// Analyze value-yielding loop expression. Loop yields value by
+    /// `break value`, all values should have the same type. Values are
+    /// merged at the loop-end label to the register. `break` without
+    /// value is forbidden.
+    ///
+    /// ## Return
+    /// Return merged value as register. If loop never yields value,
+    /// add error to `Error State` and return `None`.
+    pub fn loop_expression(
+        &mut self,
+        data: &ast::ExpressionLoop<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let errors_len = self.errors.len();
+        let loop_body_state =
+            self.loop_with_condition(&data.body, body_state, None, None, Some(data.location()));
+        let loop_labels = loop_body_state.borrow().loop_labels.clone()?;
+        let Some(value_type) = loop_labels.value_type else {
+            // Loop body errors already reported
+            if self.errors.len() == errors_len {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::LoopExpressionWithoutValue,
+                    "loop never breaks with value".to_string(),
+                    data.location(),
+                ));
+            }
+            return None;
+        };
+
+        loop_body_state.borrow_mut().inc_register();
+        let register_number = loop_body_state.borrow().last_register_number;
+        loop_body_state.borrow_mut().merge_result(
+            loop_labels.label_break,
+            value_type.clone(),
+            register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: value_type,
+            expr_value: ExpressionResultValue::Register(register_number),
+        })
+    }
+
+    /// # Enum variant
+    /// Analyze enum variant construction. Enum type and variant
+    /// should exist, and payload values should be the same count and
+    /// types as variant payload declaration.
+    ///
+    /// ## Return
+    /// Return enum value as register. If enum variant is invalid
+    /// return `None`.
+    pub fn enum_variant(
+        &mut self,
+        data: &ast::ExpressionEnumVariant<'_, I, E>,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let type_name: TypeName = (*data.name.fragment()).to_string().into();
+        let Some(enum_type) = self.global.types.get(&type_name).cloned() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::TypeNotFound,
+                type_name.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let Some(ty) = enum_type.get_enum() else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ValueNotEnum,
+                type_name.to_string(),
+                data.location(),
+            ));
+            return None;
+        };
+        let variant_name = format!("{type_name}::{}", data.variant.fragment());
+        let Some(variant) = ty.get_variant(data.variant.fragment()) else {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::EnumVariantNotFound,
+                variant_name,
+                data.location(),
+            ));
+            return None;
+        };
+        if variant.payload.len() != data.payload.len() {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::EnumVariantPayloadCountWrong,
+                format!(
+                    "{variant_name}: expected {}, found {}",
+                    variant.payload.len(),
+                    data.payload.len()
+                ),
+                data.location(),
+            ));
+            return None;
+        }
+        let mut payload = vec![];
+        for (payload_type, value) in variant.payload.iter().zip(&data.payload) {
+            let expr_result = self.expression(value, body_state)?;
+            if &expr_result.expr_type != payload_type {
+                self.add_error(error::StateErrorResult::new(
+                    error::StateErrorKind::EnumVariantPayloadWrongType,
+                    format!(
+                        "{variant_name}: expected {payload_type}, found {}",
+                        expr_result.expr_type
+                    ),
+                    value.location(),
+                ));
+                return None;
+            }
+            payload.push(expr_result);
+        }
+
+        // Enum value is set to register
+        body_state.borrow_mut().inc_register();
+        let last_register_number = body_state.borrow().last_register_number;
+        body_state.borrow_mut().expression_enum_variant(
+            enum_type.clone(),
+            variant.discriminant,
+            payload,
+            last_register_number,
+        );
+        Some(ExpressionResult {
+            expr_type: enum_type,
+            expr_value: ExpressionResultValue::Register(last_register_number),
+        })
+    }
+
+    /// # Array index
+    /// Analyse array element index expression. Index should be
+    /// integer, and for constant index it's checked array bounds.
+    ///
+    /// ## Return
+    /// Expression result of index. If index is invalid return `None`.
+    fn array_index(
+        &mut self,
+        data: &ast::Expression<'_, I, E>,
+        size: u32,
+        body_state: &Rc<RefCell<BlockState<I>>>,
+    ) -> Option<ExpressionResult> {
+        let index = self.expression(data, body_state)?;
+        if index.expr_type.type_class() != TypeClass::Integer {
+            self.add_error(error::StateErrorResult::new(
+                error::StateErrorKind::ArrayIndexWrongType,
+                index.expr_type.to_string(),
+                data.location(),
+            ));
+            return None;
+        }
+        // Constant index can be checked for array bounds
+        if let ExpressionResultValue::PrimitiveValue(value) = &index.expr_value {
+            if let Some(idx) = value.integer_value() {
+                if idx < 0 || idx >= i128::from(size) {
+                    self.add_error(error::StateErrorResult::new(
+                        error::StateErrorKind::ArrayIndexOutOfBounds,
+                        format!("index {idx} for array length {size}"),
+                        data.location(),
+                    ));
+                    return None;
+                }
+            }
+        }
+        Some(index)
+    }
+
     /// # Expression operation priority
     /// Fold expression priority.
     /// Pass expressions tree from max priority level to minimum
@@ -0,0 +1,416 @@
+//! # Alloca values
+//! Pre-scan of the function body for values, that should be
+//! allocated in memory.
+
+use super::ValueName;
+use crate::ast;
+use crate::types::semantic::{ExtendedExpression, SemanticContextInstruction};
+use std::collections::HashSet;
+
+/// # Alloca values
+/// Names of the values, that should be allocated in memory. Values
+/// are collected by function body pre-scan before function body
+/// analyzed, so value declarations are emitted with final allocation
+/// status:
+/// - `address_taken` - values, that address is taken
+/// - `captured` - values used in closures bodies. Mutable values are
+///   captured by closure by reference, so they should be allocated in
+///   memory.
+///
+/// Values are collected by name, so all values with the same name
+/// (shadowed values, closures own values) are allocated in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllocaValues {
+    /// Values, that address is taken
+    pub address_taken: HashSet<ValueName>,
+    /// Values used in closures bodies
+    pub captured: HashSet<ValueName>,
+}
+
+impl AllocaValues {
+    /// Collect alloca values of the function body
+    #[must_use]
+    pub fn new<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        body: &[ast::BodyStatement<'_, I, E>],
+    ) -> Self {
+        let mut alloca_values = Self::default();
+        for statement in body {
+            alloca_values.body_statement(statement, false);
+        }
+        alloca_values
+    }
+
+    /// Is value with the name and mutability should be allocated in
+    /// memory.
+    #[must_use]
+    pub fn is_alloca(&self, name: &ValueName, mutable: bool) -> bool {
+        self.address_taken.contains(name) || (mutable && self.captured.contains(name))
+    }
+
+    /// Add value name, that used in the closure body
+    fn value_name(&mut self, name: &ast::ValueName<'_>, in_closure: bool) {
+        if in_closure {
+            self.captured.insert(name.clone().into());
+        }
+    }
+
+    fn body_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::BodyStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match data {
+            ast::BodyStatement::LetBinding(bind) => self.expression(&bind.value, in_closure),
+            ast::BodyStatement::LetTupleBinding(bind) => self.expression(&bind.value, in_closure),
+            ast::BodyStatement::Binding(bind) => self.binding(bind, in_closure),
+            ast::BodyStatement::FunctionCall(fn_call) => {
+                self.expressions(&fn_call.parameters, in_closure);
+            }
+            ast::BodyStatement::If(if_statement) => self.if_statement(if_statement, in_closure),
+            ast::BodyStatement::Loop(body) => self.loop_body(body, in_closure),
+            ast::BodyStatement::LabeledLoop(loop_statement) => {
+                self.loop_body(&loop_statement.body, in_closure);
+            }
+            ast::BodyStatement::While(while_statement) => {
+                self.while_statement(while_statement, in_closure);
+            }
+            ast::BodyStatement::For(for_statement) => {
+                self.for_statement(for_statement, in_closure);
+            }
+            ast::BodyStatement::Match(match_statement) => {
+                self.match_statement(match_statement, in_closure);
+            }
+            ast::BodyStatement::Block(body) => {
+                for statement in body {
+                    self.body_statement(statement, in_closure);
+                }
+            }
+            ast::BodyStatement::Expression(expression) | ast::BodyStatement::Return(expression) => {
+                self.expression(expression, in_closure);
+            }
+        }
+    }
+
+    fn if_body_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::IfBodyStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match data {
+            ast::IfBodyStatement::LetBinding(bind) => self.expression(&bind.value, in_closure),
+            ast::IfBodyStatement::LetTupleBinding(bind) => {
+                self.expression(&bind.value, in_closure);
+            }
+            ast::IfBodyStatement::Binding(bind) => self.binding(bind, in_closure),
+            ast::IfBodyStatement::FunctionCall(fn_call) => {
+                self.expressions(&fn_call.parameters, in_closure);
+            }
+            ast::IfBodyStatement::If(if_statement) => self.if_statement(if_statement, in_closure),
+            ast::IfBodyStatement::Loop(body) => self.loop_body(body, in_closure),
+            ast::IfBodyStatement::LabeledLoop(loop_statement) => {
+                self.loop_body(&loop_statement.body, in_closure);
+            }
+            ast::IfBodyStatement::While(while_statement) => {
+                self.while_statement(while_statement, in_closure);
+            }
+            ast::IfBodyStatement::For(for_statement) => {
+                self.for_statement(for_statement, in_closure);
+            }
+            ast::IfBodyStatement::Match(match_statement) => {
+                self.match_statement(match_statement, in_closure);
+            }
+            ast::IfBodyStatement::Block(body) => {
+                for statement in body {
+                    self.if_body_statement(statement, in_closure);
+                }
+            }
+            ast::IfBodyStatement::Return(expression) => self.expression(expression, in_closure),
+        }
+    }
+
+    fn if_loop_body_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::IfLoopBodyStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match data {
+            ast::IfLoopBodyStatement::LetBinding(bind) => self.expression(&bind.value, in_closure),
+            ast::IfLoopBodyStatement::LetTupleBinding(bind) => {
+                self.expression(&bind.value, in_closure);
+            }
+            ast::IfLoopBodyStatement::Binding(bind) => self.binding(bind, in_closure),
+            ast::IfLoopBodyStatement::FunctionCall(fn_call) => {
+                self.expressions(&fn_call.parameters, in_closure);
+            }
+            ast::IfLoopBodyStatement::If(if_statement) => {
+                self.if_statement(if_statement, in_closure);
+            }
+            ast::IfLoopBodyStatement::Loop(body) => self.loop_body(body, in_closure),
+            ast::IfLoopBodyStatement::LabeledLoop(loop_statement) => {
+                self.loop_body(&loop_statement.body, in_closure);
+            }
+            ast::IfLoopBodyStatement::While(while_statement) => {
+                self.while_statement(while_statement, in_closure);
+            }
+            ast::IfLoopBodyStatement::For(for_statement) => {
+                self.for_statement(for_statement, in_closure);
+            }
+            ast::IfLoopBodyStatement::Match(match_statement) => {
+                self.match_statement(match_statement, in_closure);
+            }
+            ast::IfLoopBodyStatement::Block(body) => {
+                for statement in body {
+                    self.if_loop_body_statement(statement, in_closure);
+                }
+            }
+            ast::IfLoopBodyStatement::Return(expression)
+            | ast::IfLoopBodyStatement::BreakValue(expression) => {
+                self.expression(expression, in_closure);
+            }
+            ast::IfLoopBodyStatement::Break
+            | ast::IfLoopBodyStatement::BreakAt(_)
+            | ast::IfLoopBodyStatement::Continue
+            | ast::IfLoopBodyStatement::BreakTo(_)
+            | ast::IfLoopBodyStatement::ContinueTo(_) => {}
+        }
+    }
+
+    fn loop_body<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &[ast::LoopBodyStatement<'_, I, E>],
+        in_closure: bool,
+    ) {
+        for statement in data {
+            match statement {
+                ast::LoopBodyStatement::LetBinding(bind) => {
+                    self.expression(&bind.value, in_closure);
+                }
+                ast::LoopBodyStatement::LetTupleBinding(bind) => {
+                    self.expression(&bind.value, in_closure);
+                }
+                ast::LoopBodyStatement::Binding(bind) => self.binding(bind, in_closure),
+                ast::LoopBodyStatement::FunctionCall(fn_call) => {
+                    self.expressions(&fn_call.parameters, in_closure);
+                }
+                ast::LoopBodyStatement::If(if_statement) => {
+                    self.if_statement(if_statement, in_closure);
+                }
+                ast::LoopBodyStatement::Loop(body) | ast::LoopBodyStatement::Block(body) => {
+                    self.loop_body(body, in_closure);
+                }
+                ast::LoopBodyStatement::LabeledLoop(loop_statement) => {
+                    self.loop_body(&loop_statement.body, in_closure);
+                }
+                ast::LoopBodyStatement::While(while_statement) => {
+                    self.while_statement(while_statement, in_closure);
+                }
+                ast::LoopBodyStatement::For(for_statement) => {
+                    self.for_statement(for_statement, in_closure);
+                }
+                ast::LoopBodyStatement::Match(match_statement) => {
+                    self.match_statement(match_statement, in_closure);
+                }
+                ast::LoopBodyStatement::Return(expression)
+                | ast::LoopBodyStatement::BreakValue(expression) => {
+                    self.expression(expression, in_closure);
+                }
+                ast::LoopBodyStatement::Break
+                | ast::LoopBodyStatement::BreakAt(_)
+                | ast::LoopBodyStatement::Continue
+                | ast::LoopBodyStatement::BreakTo(_)
+                | ast::LoopBodyStatement::ContinueTo(_) => {}
+            }
+        }
+    }
+
+    fn if_body_statements<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::IfBodyStatements<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match data {
+            ast::IfBodyStatements::If(body) => {
+                for statement in body {
+                    self.if_body_statement(statement, in_closure);
+                }
+            }
+            ast::IfBodyStatements::Loop(body) => {
+                for statement in body {
+                    self.if_loop_body_statement(statement, in_closure);
+                }
+            }
+        }
+    }
+
+    fn if_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::IfStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        self.if_condition(&data.condition, in_closure);
+        self.if_body_statements(&data.body, in_closure);
+        if let Some(else_statement) = &data.else_statement {
+            self.if_body_statements(else_statement, in_closure);
+        }
+        if let Some(else_if_statement) = &data.else_if_statement {
+            self.if_statement(else_if_statement, in_closure);
+        }
+    }
+
+    fn if_condition<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::IfCondition<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match data {
+            ast::IfCondition::Single(expression) => self.expression(expression, in_closure),
+            ast::IfCondition::Logic(condition) => {
+                let mut condition = Some(condition);
+                while let Some(logic_condition) = condition {
+                    self.expression(&logic_condition.left.left, in_closure);
+                    self.expression(&logic_condition.left.right, in_closure);
+                    condition = logic_condition
+                        .right
+                        .as_ref()
+                        .map(|(_, right)| right.as_ref());
+                }
+            }
+        }
+    }
+
+    fn while_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::WhileStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        self.if_condition(&data.condition, in_closure);
+        self.loop_body(&data.body, in_closure);
+    }
+
+    fn for_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::ForStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        self.expression(&data.start, in_closure);
+        self.expression(&data.end, in_closure);
+        if let Some(step) = &data.step {
+            self.expression(step, in_closure);
+        }
+        self.loop_body(&data.body, in_closure);
+    }
+
+    fn match_statement<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::MatchStatement<'_, I, E>,
+        in_closure: bool,
+    ) {
+        self.expression(&data.value, in_closure);
+        for arm in &data.arms {
+            self.if_body_statements(&arm.body, in_closure);
+        }
+    }
+
+    fn binding<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::Binding<'_, I, E>,
+        in_closure: bool,
+    ) {
+        self.value_name(&data.name, in_closure);
+        for access in &data.path {
+            if let ast::PlaceAccess::Index(index) = access {
+                self.expression(index, in_closure);
+            }
+        }
+        self.expression(&data.value, in_closure);
+    }
+
+    fn expressions<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &[ast::Expression<'_, I, E>],
+        in_closure: bool,
+    ) {
+        for expression in data {
+            self.expression(expression, in_closure);
+        }
+    }
+
+    fn expression<I: SemanticContextInstruction, E: ExtendedExpression<I>>(
+        &mut self,
+        data: &ast::Expression<'_, I, E>,
+        in_closure: bool,
+    ) {
+        match &data.expression_value {
+            ast::ExpressionValue::ValueName(name) => self.value_name(name, in_closure),
+            ast::ExpressionValue::StructValue(value) => self.value_name(&value.name, in_closure),
+            ast::ExpressionValue::FunctionCall(fn_call) => {
+                self.expressions(&fn_call.parameters, in_closure);
+            }
+            ast::ExpressionValue::Expression(expression) => {
+                self.expression(expression, in_closure);
+            }
+            ast::ExpressionValue::UnaryOperation(operation) => {
+                self.expression(&operation.expression, in_closure);
+            }
+            ast::ExpressionValue::Cast(cast) => self.expression(&cast.expression, in_closure),
+            ast::ExpressionValue::ArrayValue(array) => self.expressions(&array.values, in_closure),
+            ast::ExpressionValue::ArrayElement(element) => {
+                self.expression(&element.array, in_closure);
+                self.expression(&element.index, in_closure);
+            }
+            ast::ExpressionValue::StructLiteral(literal) => {
+                for field in &literal.fields {
+                    self.expression(&field.value, in_closure);
+                }
+            }
+            ast::ExpressionValue::MethodCall(method_call) => {
+                self.expression(&method_call.receiver, in_closure);
+                self.expressions(&method_call.parameters, in_closure);
+            }
+            ast::ExpressionValue::EnumVariant(variant) => {
+                self.expressions(&variant.payload, in_closure);
+            }
+            ast::ExpressionValue::If(if_expression) => {
+                self.if_condition(&if_expression.condition, in_closure);
+                for branch in [&if_expression.body, &if_expression.else_body] {
+                    for statement in &branch.body {
+                        self.if_body_statement(statement, in_closure);
+                    }
+                    self.expression(&branch.value, in_closure);
+                }
+            }
+            ast::ExpressionValue::Loop(loop_expression) => {
+                self.loop_body(&loop_expression.body, in_closure);
+            }
+            ast::ExpressionValue::TupleValue(tuple) => self.expressions(&tuple.values, in_closure),
+            ast::ExpressionValue::TupleElement(element) => {
+                self.expression(&element.tuple, in_closure);
+            }
+            ast::ExpressionValue::AddressOf(address_of) => {
+                self.address_taken.insert(address_of.value.clone().into());
+                self.value_name(&address_of.value, in_closure);
+            }
+            ast::ExpressionValue::Dereference(dereference) => {
+                self.expression(&dereference.expression, in_closure);
+            }
+            ast::ExpressionValue::IndirectCall(indirect_call) => {
+                self.expression(&indirect_call.callee, in_closure);
+                self.expressions(&indirect_call.parameters, in_closure);
+            }
+            // Values used in closure body are captured from enclosing
+            // states
+            ast::ExpressionValue::Closure(closure) => {
+                for statement in &closure.body {
+                    self.body_statement(statement, true);
+                }
+            }
+            ast::ExpressionValue::PrimitiveValue(_)
+            | ast::ExpressionValue::FunctionValue(_)
+            | ast::ExpressionValue::ExtendedExpression(_)
+            | ast::ExpressionValue::_marker(..) => {}
+        }
+        if let Some((_, expression)) = &data.operation {
+            self.expression(expression, in_closure);
+        }
+    }
+}
@@ -2,16 +2,40 @@
 //! Block state Semantic types.
 
 use super::semantic::{ExtendedSemanticContext, SemanticContextInstruction, SemanticStack};
-use super::{Constant, Function, FunctionParameter, InnerValueName, LabelName, Value, ValueName};
+use super::{
+    Closure, ClosureCapture, Constant, Function, FunctionParameter, InnerValueName, LabelName,
+    LoopLabel, PlaceIndex, PrimitiveValue, Value, ValueName,
+};
+use crate::ast::CodeLocation;
 use crate::types::condition::{Condition, LogicCondition};
-use crate::types::expression::{ExpressionOperations, ExpressionResult};
+use crate::types::expression::{ExpressionOperations, ExpressionResult, UnaryOperations};
 use crate::types::semantic::SemanticContext;
+use crate::types::types::{CastKind, PrimitiveTypes, Type};
 #[cfg(feature = "codec")]
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
+/// # Loop labels
+/// Jump labels of the loop body state. For loop expression contains
+/// type of the `break value` values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LoopLabels {
+    /// Loop label name, set only for labeled loop
+    pub name: Option<LoopLabel>,
+    /// `continue` jump label
+    pub label_continue: LabelName,
+    /// `break` jump label
+    pub label_break: LabelName,
+    /// Loop expression location, set only for loop expression, that
+    /// yields value by `break value`
+    pub expression_location: Option<CodeLocation>,
+    /// Type of the loop expression `break value` values
+    pub value_type: Option<Type>,
+}
+
 /// # Block state
 /// - `values` - contains unique values map for current state but not unique
 ///   for parent states. The map contains key-value: `value_name` (unique
@@ -28,6 +52,13 @@ use std::rc::Rc;
 ///   should be linearly incremented.
 /// - `manual_return` - flag indicated, that return was invoked from
 /// other state, for example: if-flow, loop-flow
+/// - `loop_labels` - loop name with `continue` and `break` jump labels,
+///   set only for loop body state
+/// - `enclosing` - enclosing state of the closure body state, set only
+///   for closure body state. Enclosing states values are captured by
+///   closure, and closure instructions aren't propagated to enclosing
+///   states
+/// - `captures` - values captured by closure body state
 /// - `parent` - represent parent states.  
 #[derive(Debug)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
@@ -42,6 +73,19 @@ pub struct BlockState<I: SemanticContextInstruction> {
     pub last_register_number: u64,
     /// Manual return from other states
     pub manual_return: bool,
+    /// Loop name with `continue` and `break` jump labels
+    pub loop_labels: Option<LoopLabels>,
+    /// Enclosing state of the closure body state
+    #[cfg_attr(
+        feature = "codec",
+        serde(
+            serialize_with = "rc_serializer::serialize_option",
+            deserialize_with = "rc_serializer::deserialize_option"
+        )
+    )]
+    pub enclosing: Option<Rc<RefCell<Self>>>,
+    /// Values captured by closure body state
+    pub captures: Vec<ClosureCapture>,
     /// Parent state
     #[cfg_attr(
         feature = "codec",
@@ -50,7 +94,7 @@ pub struct BlockState<I: SemanticContextInstruction> {
             deserialize_with = "rc_serializer::deserialize_option"
         )
     )]
-    pub parent: Option<Rc<RefCell<BlockState<I>>>>,
+    pub parent: Option<Rc<RefCell<Self>>>,
     /// children states
     #[cfg_attr(
         feature = "codec",
@@ -59,7 +103,7 @@ pub struct BlockState<I: SemanticContextInstruction> {
             deserialize_with = "rc_serializer::deserialize_vec"
         )
     )]
-    pub children: Vec<Rc<RefCell<BlockState<I>>>>,
+    pub children: Vec<Rc<RefCell<Self>>>,
     /// Semantic stack context for Block state
     context: SemanticStack<I>,
 }
@@ -89,6 +133,9 @@ impl<I: SemanticContextInstruction> BlockState<I> {
             labels,
             last_register_number,
             manual_return,
+            loop_labels: None,
+            enclosing: None,
+            captures: vec![],
             parent,
             context: SemanticStack::new(),
         }
@@ -149,6 +196,43 @@ impl<I: SemanticContextInstruction> BlockState<I> {
         None
     }
 
+    /// Get jump labels of the labeled loop by loop label name from
+    /// current state. If not found on current state - recursively find
+    /// in parent states.
+    #[must_use]
+    pub fn get_loop_labels(&self, label: &LoopLabel) -> Option<LoopLabels> {
+        match &self.loop_labels {
+            Some(loop_labels) if loop_labels.name.as_ref() == Some(label) => {
+                Some(loop_labels.clone())
+            }
+            _ => self
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.borrow().get_loop_labels(label)),
+        }
+    }
+
+    /// Get jump labels of the innermost loop from current and parent
+    /// states.
+    #[must_use]
+    pub fn get_current_loop_labels(&self) -> Option<LoopLabels> {
+        self.loop_labels.clone().or_else(|| {
+            self.parent
+                .as_ref()
+                .and_then(|parent| parent.borrow().get_current_loop_labels())
+        })
+    }
+
+    /// Set type of the `break value` values for the innermost loop
+    /// from current and parent states.
+    pub fn set_loop_value_type(&mut self, value_type: Type) {
+        if let Some(loop_labels) = &mut self.loop_labels {
+            loop_labels.value_type = Some(value_type);
+        } else if let Some(parent) = &self.parent {
+            parent.borrow_mut().set_loop_value_type(value_type);
+        }
+    }
+
     /// Check is label name exist in current and parent states
     #[must_use]
     pub fn is_label_name_exist(&self, name: &LabelName) -> bool {
@@ -243,13 +327,13 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
-    fn expression_struct_value(&mut self, expression: Value, index: u32, register_number: u64) {
+    fn expression_struct_value(&mut self, expression: Value, path: Vec<u32>, register_number: u64) {
         self.context
-            .expression_struct_value(expression.clone(), index, register_number);
+            .expression_struct_value(expression.clone(), path.clone(), register_number);
         if let Some(parent) = &self.parent {
             parent
                 .borrow_mut()
-                .expression_struct_value(expression, index, register_number);
+                .expression_struct_value(expression, path, register_number);
         }
     }
 
@@ -276,6 +360,182 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
+    fn unary_operation(
+        &mut self,
+        operation: UnaryOperations,
+        value: ExpressionResult,
+        register_number: u64,
+    ) {
+        self.context
+            .unary_operation(operation.clone(), value.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .unary_operation(operation, value, register_number);
+        }
+    }
+
+    fn cast(
+        &mut self,
+        value: ExpressionResult,
+        from: PrimitiveTypes,
+        to: PrimitiveTypes,
+        kind: CastKind,
+        register_number: u64,
+    ) {
+        self.context.cast(
+            value.clone(),
+            from.clone(),
+            to.clone(),
+            kind,
+            register_number,
+        );
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .cast(value, from, to, kind, register_number);
+        }
+    }
+
+    fn expression_array_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        array_type: Type,
+        register_number: u64,
+    ) {
+        self.context
+            .expression_array_value(values.clone(), array_type.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .expression_array_value(values, array_type, register_number);
+        }
+    }
+
+    fn expression_struct_literal(
+        &mut self,
+        struct_type: Type,
+        values: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.context.expression_struct_literal(
+            struct_type.clone(),
+            values.clone(),
+            register_number,
+        );
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .expression_struct_literal(struct_type, values, register_number);
+        }
+    }
+
+    fn expression_enum_variant(
+        &mut self,
+        enum_type: Type,
+        discriminant: u32,
+        payload: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.context.expression_enum_variant(
+            enum_type.clone(),
+            discriminant,
+            payload.clone(),
+            register_number,
+        );
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().expression_enum_variant(
+                enum_type,
+                discriminant,
+                payload,
+                register_number,
+            );
+        }
+    }
+
+    fn enum_discriminant(&mut self, value: ExpressionResult, register_number: u64) {
+        self.context
+            .enum_discriminant(value.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .enum_discriminant(value, register_number);
+        }
+    }
+
+    fn expression_tuple_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        tuple_type: Type,
+        register_number: u64,
+    ) {
+        self.context
+            .expression_tuple_value(values.clone(), tuple_type.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .expression_tuple_value(values, tuple_type, register_number);
+        }
+    }
+
+    fn tuple_element(
+        &mut self,
+        tuple: ExpressionResult,
+        index: u32,
+        element_type: Type,
+        register_number: u64,
+    ) {
+        self.context
+            .tuple_element(tuple.clone(), index, element_type.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .tuple_element(tuple, index, element_type, register_number);
+        }
+    }
+
+    fn array_element_address(
+        &mut self,
+        array: ExpressionResult,
+        index: ExpressionResult,
+        register_number: u64,
+    ) {
+        self.context
+            .array_element_address(array.clone(), index.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .array_element_address(array, index, register_number);
+        }
+    }
+
+    fn load(&mut self, value_type: Type, address_register: u64, register_number: u64) {
+        self.context
+            .load(value_type.clone(), address_register, register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .load(value_type, address_register, register_number);
+        }
+    }
+
+    fn environment_field(&mut self, attr_index: u32, attr_type: Type, register_number: u64) {
+        self.context
+            .environment_field(attr_index, attr_type.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .environment_field(attr_index, attr_type, register_number);
+        }
+    }
+
+    fn address_of(&mut self, value: Value, register_number: u64) {
+        self.context.address_of(value.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().address_of(value, register_number);
+        }
+    }
+
     fn call(&mut self, call: Function, params: Vec<ExpressionResult>, register_number: u64) {
         self.context
             .call(call.clone(), params.clone(), register_number);
@@ -284,6 +544,59 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
+    fn function_address(&mut self, function: Function, register_number: u64) {
+        self.context
+            .function_address(function.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .function_address(function, register_number);
+        }
+    }
+
+    fn create_closure(&mut self, closure: Closure, register_number: u64) {
+        self.context
+            .create_closure(closure.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().create_closure(closure, register_number);
+        }
+    }
+
+    fn closure_call(
+        &mut self,
+        closure: Closure,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.context.closure_call(
+            closure.clone(),
+            callee.clone(),
+            params.clone(),
+            register_number,
+        );
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .closure_call(closure, callee, params, register_number);
+        }
+    }
+
+    fn indirect_call(
+        &mut self,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.context
+            .indirect_call(callee.clone(), params.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .indirect_call(callee, params, register_number);
+        }
+    }
+
     fn let_binding(&mut self, let_decl: Value, expr_result: ExpressionResult) {
         self.context
             .let_binding(let_decl.clone(), expr_result.clone());
@@ -299,6 +612,24 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
+    fn store(&mut self, val: Value, path: Vec<PlaceIndex>, expr_result: ExpressionResult) {
+        self.context
+            .store(val.clone(), path.clone(), expr_result.clone());
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().store(val, path, expr_result);
+        }
+    }
+
+    fn store_address(&mut self, address_register: u64, expr_result: ExpressionResult) {
+        self.context
+            .store_address(address_register, expr_result.clone());
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .store_address(address_register, expr_result);
+        }
+    }
+
     fn expression_function_return(&mut self, expr_result: ExpressionResult) {
         self.context.expression_function_return(expr_result.clone());
         if let Some(parent) = &self.parent {
@@ -378,6 +709,13 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
+    fn function_return_label(&mut self, return_type: Type) {
+        self.context.function_return_label(return_type.clone());
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().function_return_label(return_type);
+        }
+    }
+
     fn logic_condition(
         &mut self,
         logic_condition: LogicCondition,
@@ -419,6 +757,36 @@ impl<I: SemanticContextInstruction> SemanticContext for BlockState<I> {
         }
     }
 
+    fn switch(
+        &mut self,
+        value: ExpressionResult,
+        cases: Vec<(PrimitiveValue, LabelName)>,
+        label_default: LabelName,
+    ) {
+        self.context
+            .switch(value.clone(), cases.clone(), label_default.clone());
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().switch(value, cases, label_default);
+        }
+    }
+
+    fn merge_value(&mut self, label: LabelName, expr_result: ExpressionResult) {
+        self.context.merge_value(label.clone(), expr_result.clone());
+        if let Some(parent) = &self.parent {
+            parent.borrow_mut().merge_value(label, expr_result);
+        }
+    }
+
+    fn merge_result(&mut self, label: LabelName, expr_type: Type, register_number: u64) {
+        self.context
+            .merge_result(label.clone(), expr_type.clone(), register_number);
+        if let Some(parent) = &self.parent {
+            parent
+                .borrow_mut()
+                .merge_result(label, expr_type, register_number);
+        }
+    }
+
     fn function_arg(&mut self, value: Value, func_arg: FunctionParameter) {
         self.context.function_arg(value.clone(), func_arg.clone());
         if let Some(parent) = &self.parent {
@@ -2,11 +2,15 @@
 //! Condition types for Semantic analyzer result state.
 
 use super::expression::Expression;
-use super::{Binding, FunctionCall, LetBinding};
+use super::types::{Type, TypeClass};
+use super::{
+    Binding, FunctionCall, LetBinding, LetTupleBinding, LoopLabel, PrimitiveValue, ValueName,
+};
 use crate::ast;
 use crate::types::semantic::{ExtendedExpression, SemanticContextInstruction};
 #[cfg(feature = "codec")]
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 /// Basic logical conditions mostly for compare expressions
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -92,7 +96,7 @@ pub struct ExpressionLogicCondition {
     /// Left expression condition
     pub left: ExpressionCondition,
     /// Optional right expression condition with logic condition
-    pub right: Option<(LogicCondition, Box<ExpressionLogicCondition>)>,
+    pub right: Option<(LogicCondition, Box<Self>)>,
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
@@ -108,6 +112,42 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
     }
 }
 
+/// Truthiness policy for the `IfCondition::Single` expression. It
+/// defines which types of expression result accepted as condition:
+/// - `Bool` - only `bool`
+/// - `Integer` - `bool` and integers
+/// - `IntegerAndPointer` - `bool`, integers and pointers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum ConditionTruthiness {
+    #[default]
+    Bool,
+    Integer,
+    IntegerAndPointer,
+}
+
+impl ConditionTruthiness {
+    /// Get type classes accepted as if-condition expression result
+    #[must_use]
+    pub const fn type_classes(&self) -> &'static [TypeClass] {
+        match self {
+            Self::Bool => &[TypeClass::Bool],
+            Self::Integer => &[TypeClass::Bool, TypeClass::Integer],
+            Self::IntegerAndPointer => &[TypeClass::Bool, TypeClass::Integer, TypeClass::Pointer],
+        }
+    }
+
+    /// Check is type accepted as if-condition expression result
+    #[must_use]
+    pub fn is_valid_for_type(&self, ty: &Type) -> bool {
+        self.type_classes().contains(&ty.type_class())
+    }
+}
+
 /// If-condition representation. It can be:
 /// - simple - just expression
 /// - logic - represented through `ExpressionLogicCondition`
@@ -145,7 +185,7 @@ pub struct IfStatement {
     /// Basic else-body, if if-condition is false
     pub else_statement: Option<IfBodyStatements>,
     /// Basic else-if-body
-    pub else_if_statement: Option<Box<IfStatement>>,
+    pub else_if_statement: Option<Box<Self>>,
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::IfStatement<'_, I, E>>
@@ -163,6 +203,168 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::IfStatem
     }
 }
 
+/// # While statement
+/// Loop with condition, that checked before each loop step.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct WhileStatement {
+    /// Loop label
+    pub label: Option<LoopLabel>,
+    /// Loop condition
+    pub condition: IfCondition,
+    /// Loop body
+    pub body: Vec<LoopBodyStatement>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::WhileStatement<'_, I, E>>
+    for WhileStatement
+{
+    fn from(value: ast::WhileStatement<'_, I, E>) -> Self {
+        Self {
+            label: value.label.map(Into::into),
+            condition: value.condition.into(),
+            body: value.body.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// # Labeled loop statement
+/// Loop with label name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LabeledLoopStatement {
+    /// Loop label
+    pub label: LoopLabel,
+    /// Loop body
+    pub body: Vec<LoopBodyStatement>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::LabeledLoopStatement<'_, I, E>> for LabeledLoopStatement
+{
+    fn from(value: ast::LabeledLoopStatement<'_, I, E>) -> Self {
+        Self {
+            label: value.label.into(),
+            body: value.body.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// # For statement
+/// Counted loop over integer range with induction value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ForStatement {
+    /// Loop label
+    pub label: Option<LoopLabel>,
+    /// Induction value name
+    pub name: ValueName,
+    /// Range start
+    pub start: Expression,
+    /// Range end
+    pub end: Expression,
+    /// Range step
+    pub step: Option<Expression>,
+    /// Loop body
+    pub body: Vec<LoopBodyStatement>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::ForStatement<'_, I, E>>
+    for ForStatement
+{
+    fn from(value: ast::ForStatement<'_, I, E>) -> Self {
+        Self {
+            label: value.label.map(Into::into),
+            name: value.name.into(),
+            start: value.start.into(),
+            end: value.end.into(),
+            step: value.step.map(Into::into),
+            body: value.body.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// # Match pattern
+/// Pattern of the match arm:
+/// - primitive literal value
+/// - enum variant name
+/// - wildcard
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum MatchPattern {
+    Value(PrimitiveValue),
+    EnumVariant(String),
+    Wildcard,
+}
+
+impl Display for MatchPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(val) => write!(f, "{val}"),
+            Self::EnumVariant(name) => write!(f, "{name}"),
+            Self::Wildcard => write!(f, "_"),
+        }
+    }
+}
+
+impl From<ast::MatchPattern<'_>> for MatchPattern {
+    fn from(value: ast::MatchPattern<'_>) -> Self {
+        match value {
+            ast::MatchPattern::Value(v) => Self::Value(v.into()),
+            ast::MatchPattern::EnumVariant(v) => Self::EnumVariant((*v.fragment()).to_string()),
+            ast::MatchPattern::Wildcard => Self::Wildcard,
+        }
+    }
+}
+
+/// # Match arm
+/// Arm of the match statement with pattern and body.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct MatchArm {
+    /// Arm pattern
+    pub pattern: MatchPattern,
+    /// Arm body
+    pub body: IfBodyStatements,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::MatchArm<'_, I, E>>
+    for MatchArm
+{
+    fn from(value: ast::MatchArm<'_, I, E>) -> Self {
+        Self {
+            pattern: value.pattern.into(),
+            body: value.body.into(),
+        }
+    }
+}
+
+/// # Match statement
+/// Basic entity that represent match-statement.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct MatchStatement {
+    /// Match value
+    pub value: Expression,
+    /// Match arms
+    pub arms: Vec<MatchArm>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::MatchStatement<'_, I, E>>
+    for MatchStatement
+{
+    fn from(value: ast::MatchStatement<'_, I, E>) -> Self {
+        Self {
+            value: value.value.into(),
+            arms: value.arms.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 /// If-body statement can be:
 /// - if-body-statement related only
 /// - loop-body-statement related - special case for the loops
@@ -199,13 +401,22 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::IfBodySt
 )]
 pub enum LoopBodyStatement {
     LetBinding(LetBinding),
+    LetTupleBinding(LetTupleBinding),
     Binding(Binding),
     FunctionCall(FunctionCall),
     If(IfStatement),
-    Loop(Vec<LoopBodyStatement>),
+    Loop(Vec<Self>),
+    LabeledLoop(LabeledLoopStatement),
+    While(WhileStatement),
+    For(ForStatement),
+    Match(MatchStatement),
+    Block(Vec<Self>),
     Return(Expression),
     Break,
     Continue,
+    BreakTo(LoopLabel),
+    ContinueTo(LoopLabel),
+    BreakValue(Expression),
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::LoopBodyStatement<'_, I, E>>
@@ -214,15 +425,26 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::LoopBody
     fn from(value: ast::LoopBodyStatement<'_, I, E>) -> Self {
         match value {
             ast::LoopBodyStatement::LetBinding(v) => Self::LetBinding(v.into()),
+            ast::LoopBodyStatement::LetTupleBinding(v) => Self::LetTupleBinding(v.into()),
             ast::LoopBodyStatement::Binding(v) => Self::Binding(v.into()),
             ast::LoopBodyStatement::FunctionCall(v) => Self::FunctionCall(v.into()),
-            ast::LoopBodyStatement::If(v) => Self::If(v.into()),
+            ast::LoopBodyStatement::If(v) => Self::If((*v).into()),
             ast::LoopBodyStatement::Loop(v) => {
                 Self::Loop(v.iter().map(|v| v.clone().into()).collect())
             }
+            ast::LoopBodyStatement::LabeledLoop(v) => Self::LabeledLoop(v.into()),
+            ast::LoopBodyStatement::While(v) => Self::While((*v).into()),
+            ast::LoopBodyStatement::For(v) => Self::For((*v).into()),
+            ast::LoopBodyStatement::Match(v) => Self::Match(v.into()),
+            ast::LoopBodyStatement::Block(v) => {
+                Self::Block(v.into_iter().map(Into::into).collect())
+            }
             ast::LoopBodyStatement::Return(v) => Self::Return(v.into()),
-            ast::LoopBodyStatement::Break => Self::Break,
+            ast::LoopBodyStatement::Break | ast::LoopBodyStatement::BreakAt(_) => Self::Break,
             ast::LoopBodyStatement::Continue => Self::Continue,
+            ast::LoopBodyStatement::BreakTo(v) => Self::BreakTo(v.into()),
+            ast::LoopBodyStatement::ContinueTo(v) => Self::ContinueTo(v.into()),
+            ast::LoopBodyStatement::BreakValue(v) => Self::BreakValue(v.into()),
         }
     }
 }
@@ -236,10 +458,16 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::LoopBody
 )]
 pub enum IfBodyStatement {
     LetBinding(LetBinding),
+    LetTupleBinding(LetTupleBinding),
     Binding(Binding),
     FunctionCall(FunctionCall),
     If(IfStatement),
     Loop(Vec<LoopBodyStatement>),
+    LabeledLoop(LabeledLoopStatement),
+    While(WhileStatement),
+    For(ForStatement),
+    Match(MatchStatement),
+    Block(Vec<Self>),
     Return(Expression),
 }
 
@@ -249,12 +477,18 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::IfBodySt
     fn from(value: ast::IfBodyStatement<'_, I, E>) -> Self {
         match value {
             ast::IfBodyStatement::LetBinding(v) => Self::LetBinding(v.into()),
+            ast::IfBodyStatement::LetTupleBinding(v) => Self::LetTupleBinding(v.into()),
             ast::IfBodyStatement::Binding(v) => Self::Binding(v.into()),
             ast::IfBodyStatement::FunctionCall(v) => Self::FunctionCall(v.into()),
-            ast::IfBodyStatement::If(v) => Self::If(v.into()),
+            ast::IfBodyStatement::If(v) => Self::If((*v).into()),
             ast::IfBodyStatement::Loop(v) => {
                 Self::Loop(v.iter().map(|v| v.clone().into()).collect())
             }
+            ast::IfBodyStatement::LabeledLoop(v) => Self::LabeledLoop(v.into()),
+            ast::IfBodyStatement::While(v) => Self::While((*v).into()),
+            ast::IfBodyStatement::For(v) => Self::For((*v).into()),
+            ast::IfBodyStatement::Match(v) => Self::Match(v.into()),
+            ast::IfBodyStatement::Block(v) => Self::Block(v.into_iter().map(Into::into).collect()),
             ast::IfBodyStatement::Return(v) => Self::Return(v.into()),
         }
     }
@@ -270,13 +504,22 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::IfBodySt
 )]
 pub enum IfLoopBodyStatement {
     LetBinding(LetBinding),
+    LetTupleBinding(LetTupleBinding),
     Binding(Binding),
     FunctionCall(FunctionCall),
     If(IfStatement),
     Loop(Vec<LoopBodyStatement>),
+    LabeledLoop(LabeledLoopStatement),
+    While(WhileStatement),
+    For(ForStatement),
+    Match(MatchStatement),
+    Block(Vec<Self>),
     Return(Expression),
     Break,
     Continue,
+    BreakTo(LoopLabel),
+    ContinueTo(LoopLabel),
+    BreakValue(Expression),
 }
 
 impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
@@ -285,15 +528,26 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
     fn from(value: ast::IfLoopBodyStatement<'_, I, E>) -> Self {
         match value {
             ast::IfLoopBodyStatement::LetBinding(v) => Self::LetBinding(v.into()),
+            ast::IfLoopBodyStatement::LetTupleBinding(v) => Self::LetTupleBinding(v.into()),
             ast::IfLoopBodyStatement::Binding(v) => Self::Binding(v.into()),
             ast::IfLoopBodyStatement::FunctionCall(v) => Self::FunctionCall(v.into()),
-            ast::IfLoopBodyStatement::If(v) => Self::If(v.into()),
+            ast::IfLoopBodyStatement::If(v) => Self::If((*v).into()),
             ast::IfLoopBodyStatement::Loop(v) => {
                 Self::Loop(v.iter().map(|v| v.clone().into()).collect())
             }
+            ast::IfLoopBodyStatement::LabeledLoop(v) => Self::LabeledLoop(v.into()),
+            ast::IfLoopBodyStatement::While(v) => Self::While((*v).into()),
+            ast::IfLoopBodyStatement::For(v) => Self::For((*v).into()),
+            ast::IfLoopBodyStatement::Match(v) => Self::Match(v.into()),
+            ast::IfLoopBodyStatement::Block(v) => {
+                Self::Block(v.into_iter().map(Into::into).collect())
+            }
             ast::IfLoopBodyStatement::Return(v) => Self::Return(v.into()),
-            ast::IfLoopBodyStatement::Break => Self::Break,
+            ast::IfLoopBodyStatement::Break | ast::IfLoopBodyStatement::BreakAt(_) => Self::Break,
             ast::IfLoopBodyStatement::Continue => Self::Continue,
+            ast::IfLoopBodyStatement::BreakTo(v) => Self::BreakTo(v.into()),
+            ast::IfLoopBodyStatement::ContinueTo(v) => Self::ContinueTo(v.into()),
+            ast::IfLoopBodyStatement::BreakValue(v) => Self::BreakValue(v.into()),
         }
     }
 }
@@ -39,6 +39,61 @@ pub enum StateErrorKind {
     ForbiddenCodeAfterContinueDeprecated,
     ForbiddenCodeAfterBreakDeprecated,
     FunctionArgumentNameDuplicated,
+    UnaryOperationWrongType,
+    ComparisonOperationWrongType,
+    InvalidOperationForType,
+    IfConditionWrongType,
+    InvalidCast,
+    ArrayValueEmpty,
+    ArrayElementWrongType,
+    ValueNotArray,
+    ArrayIndexWrongType,
+    ArrayIndexOutOfBounds,
+    ArrayValueTooLong,
+    WrongBindingType,
+    StructLiteralFieldMissing,
+    StructLiteralFieldDuplicate,
+    StructLiteralFieldUnknown,
+    StructLiteralFieldWrongType,
+    MethodAlreadyExist,
+    MethodNotFound,
+    MethodReceiverWrongType,
+    MethodParametersCountWrong,
+    EnumVariantAlreadyExist,
+    ValueNotEnum,
+    EnumVariantNotFound,
+    EnumVariantPayloadCountWrong,
+    EnumVariantPayloadWrongType,
+    StructAttributesTooMany,
+    EnumVariantsTooMany,
+    MatchValueWrongType,
+    MatchPatternWrongType,
+    MatchArmDuplicate,
+    MatchArmUnreachable,
+    MatchNotExhaustive,
+    MatchLoopArmOutsideLoop,
+    LoopLabelNotFound,
+    ForRangeWrongType,
+    ForRangeStepWrong,
+    ForRangeStepNotConstant,
+    ExpressionBranchTypeMismatch,
+    BreakValueInLoopStatement,
+    LoopExpressionBreakWithoutValue,
+    IfExpressionWithoutValue,
+    LoopExpressionWithoutValue,
+    ValueNotTuple,
+    TupleElementIndexOutOfBounds,
+    TuplePatternArityMismatch,
+    TuplePatternDuplicateName,
+    DereferenceNotPointer,
+    ReferenceNotMutable,
+    TypeParameterAlreadyExist,
+    TypeParameterNotInferred,
+    FunctionParametersCountWrong,
+    GenericInstantiationTooDeep,
+    ValueNotFunction,
+    TypeAliasCycle,
+    ConstArgumentNotConstant,
 }
 
 /// State error location. Useful to determine location of error
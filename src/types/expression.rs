@@ -1,8 +1,11 @@
 //! # Expression types
 //! Expression types for Semantic analyzer result state.
 
-use super::types::Type;
-use super::{FunctionCall, PrimitiveValue, ValueName};
+use super::condition::{IfBodyStatement, IfCondition, LoopBodyStatement};
+use super::types::{PrimitiveTypes, Type, TypeClass};
+use super::{
+    BodyStatement, FunctionCall, FunctionName, FunctionParameter, PrimitiveValue, ValueName,
+};
 use crate::ast;
 use crate::types::semantic::{ExtendedExpression, SemanticContextInstruction};
 #[cfg(feature = "codec")]
@@ -51,6 +54,7 @@ pub struct ExtendedExpressionValue(String);
 /// - struct value - value of struct type
 /// - function call - call of function with params
 /// - expression - contains other expression
+/// - unary operation - unary operation for other expression
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "codec",
@@ -63,6 +67,22 @@ pub enum ExpressionValue {
     StructValue(ExpressionStructValue),
     FunctionCall(FunctionCall),
     Expression(Box<Expression>),
+    UnaryOperation(ExpressionUnaryOperation),
+    Cast(ExpressionCast),
+    ArrayValue(Vec<Expression>),
+    ArrayElement(ExpressionArrayElement),
+    StructLiteral(ExpressionStructLiteral),
+    MethodCall(ExpressionMethodCall),
+    EnumVariant(ExpressionEnumVariant),
+    If(Box<ExpressionIf>),
+    Loop(Vec<LoopBodyStatement>),
+    TupleValue(Vec<Expression>),
+    TupleElement(ExpressionTupleElement),
+    AddressOf(ExpressionAddressOf),
+    Dereference(Box<Expression>),
+    FunctionValue(FunctionName),
+    IndirectCall(ExpressionIndirectCall),
+    Closure(Box<ExpressionClosure>),
     ExtendedExpression(ExtendedExpressionValue),
 }
 
@@ -74,6 +94,36 @@ impl Display for ExpressionValue {
             Self::StructValue(st_val) => st_val.clone().to_string(),
             Self::FunctionCall(fn_call) => fn_call.clone().to_string(),
             Self::Expression(val) => val.to_string(),
+            Self::UnaryOperation(val) => val.to_string(),
+            Self::Cast(val) => val.to_string(),
+            Self::ArrayValue(values) => format!(
+                "[{}]",
+                values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::ArrayElement(val) => val.to_string(),
+            Self::StructLiteral(val) => val.to_string(),
+            Self::MethodCall(val) => val.to_string(),
+            Self::EnumVariant(val) => val.to_string(),
+            Self::If(val) => val.to_string(),
+            Self::Loop(_) => "loop { .. }".to_string(),
+            Self::TupleValue(values) => format!(
+                "({})",
+                values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::TupleElement(val) => val.to_string(),
+            Self::AddressOf(val) => val.to_string(),
+            Self::Dereference(val) => format!("*{val}"),
+            Self::FunctionValue(val) => val.to_string(),
+            Self::IndirectCall(val) => val.to_string(),
+            Self::Closure(val) => val.to_string(),
             Self::ExtendedExpression(val) => val.clone().0,
         };
         write!(f, "{str}")
@@ -94,6 +144,30 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::Expressi
             ast::ExpressionValue::Expression(v) => {
                 Self::Expression(Box::new(v.as_ref().clone().into()))
             }
+            ast::ExpressionValue::UnaryOperation(v) => Self::UnaryOperation(v.into()),
+            ast::ExpressionValue::Cast(v) => Self::Cast(v.into()),
+            ast::ExpressionValue::ArrayValue(v) => {
+                Self::ArrayValue(v.values.into_iter().map(Into::into).collect())
+            }
+            ast::ExpressionValue::ArrayElement(v) => Self::ArrayElement(v.into()),
+            ast::ExpressionValue::StructLiteral(v) => Self::StructLiteral(v.into()),
+            ast::ExpressionValue::MethodCall(v) => Self::MethodCall(v.into()),
+            ast::ExpressionValue::EnumVariant(v) => Self::EnumVariant(v.into()),
+            ast::ExpressionValue::If(v) => Self::If(Box::new(v.as_ref().clone().into())),
+            ast::ExpressionValue::Loop(v) => {
+                Self::Loop(v.body.into_iter().map(Into::into).collect())
+            }
+            ast::ExpressionValue::TupleValue(v) => {
+                Self::TupleValue(v.values.into_iter().map(Into::into).collect())
+            }
+            ast::ExpressionValue::TupleElement(v) => Self::TupleElement(v.into()),
+            ast::ExpressionValue::AddressOf(v) => Self::AddressOf(v.into()),
+            ast::ExpressionValue::Dereference(v) => {
+                Self::Dereference(Box::new(v.expression.as_ref().clone().into()))
+            }
+            ast::ExpressionValue::FunctionValue(v) => Self::FunctionValue(v.into()),
+            ast::ExpressionValue::IndirectCall(v) => Self::IndirectCall(v.into()),
+            ast::ExpressionValue::Closure(v) => Self::Closure(Box::new(v.as_ref().clone().into())),
             ast::ExpressionValue::ExtendedExpression(expr) => {
                 Self::ExtendedExpression(ExtendedExpressionValue(format!("{expr:?}")))
             }
@@ -110,6 +184,8 @@ pub struct ExpressionStructValue {
     pub name: ValueName,
     /// Value attribute for structure value
     pub attribute: ValueName,
+    /// Nested attributes path for structure value attribute
+    pub path: Vec<ValueName>,
 }
 
 impl Display for ExpressionStructValue {
@@ -123,6 +199,7 @@ impl From<ast::ExpressionStructValue<'_>> for ExpressionStructValue {
         Self {
             name: value.name.into(),
             attribute: value.attribute.into(),
+            path: value.path.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -153,6 +230,71 @@ pub enum ExpressionOperations {
     LessEq,
 }
 
+impl ExpressionOperations {
+    /// Check is operation comparison: `==, !=, >, <, >=, <=`
+    #[must_use]
+    pub const fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Eq | Self::NotEq | Self::Great | Self::Less | Self::GreatEq | Self::LessEq
+        )
+    }
+
+    /// Get type classes, that valid as operands for expression operation:
+    /// - arithmetic: integer, float
+    /// - shifts: integer
+    /// - bitwise and logic: integer, bool
+    /// - equality: integer, float, bool, char, pointer
+    /// - ordering: integer, float, char
+    #[must_use]
+    pub const fn type_classes(&self) -> &'static [TypeClass] {
+        match self {
+            Self::Plus | Self::Minus | Self::Multiply | Self::Divide => {
+                &[TypeClass::Integer, TypeClass::Float]
+            }
+            Self::ShiftLeft | Self::ShiftRight => &[TypeClass::Integer],
+            Self::And | Self::Or | Self::Xor => &[TypeClass::Integer, TypeClass::Bool],
+            Self::Eq | Self::NotEq => &[
+                TypeClass::Integer,
+                TypeClass::Float,
+                TypeClass::Bool,
+                TypeClass::Char,
+                TypeClass::Pointer,
+            ],
+            Self::Great | Self::Less | Self::GreatEq | Self::LessEq => {
+                &[TypeClass::Integer, TypeClass::Float, TypeClass::Char]
+            }
+        }
+    }
+
+    /// Check is expression operation valid for operands type
+    #[must_use]
+    pub fn is_valid_for_type(&self, operand_type: &Type) -> bool {
+        self.type_classes().contains(&operand_type.type_class())
+    }
+
+    /// Get result type of expression operation for operands type.
+    /// Comparison operations always produce `bool`, other operations
+    /// keep operands type.
+    #[must_use]
+    pub fn result_type(&self, operand_type: &Type) -> Type {
+        match self {
+            Self::Plus
+            | Self::Minus
+            | Self::Multiply
+            | Self::Divide
+            | Self::ShiftLeft
+            | Self::ShiftRight
+            | Self::And
+            | Self::Or
+            | Self::Xor => operand_type.clone(),
+            Self::Eq | Self::NotEq | Self::Great | Self::Less | Self::GreatEq | Self::LessEq => {
+                Type::Primitive(PrimitiveTypes::Bool)
+            }
+        }
+    }
+}
+
 impl From<ast::ExpressionOperations> for ExpressionOperations {
     fn from(value: ast::ExpressionOperations) -> Self {
         match value {
@@ -175,6 +317,449 @@ impl From<ast::ExpressionOperations> for ExpressionOperations {
     }
 }
 
+/// Unary operations - operations over single expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum UnaryOperations {
+    Minus,
+    Not,
+    BitwiseNot,
+}
+
+impl Display for UnaryOperations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Minus => "-",
+            Self::Not => "!",
+            Self::BitwiseNot => "~",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<ast::UnaryOperations> for UnaryOperations {
+    fn from(value: ast::UnaryOperations) -> Self {
+        match value {
+            ast::UnaryOperations::Minus => Self::Minus,
+            ast::UnaryOperations::Not => Self::Not,
+            ast::UnaryOperations::BitwiseNot => Self::BitwiseNot,
+        }
+    }
+}
+
+/// Expression value of unary operation. It's represent unary
+/// operation applied to the expression
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionUnaryOperation {
+    /// Unary operation
+    pub operation: UnaryOperations,
+    /// Expression for unary operation
+    pub expression: Box<Expression>,
+}
+
+impl Display for ExpressionUnaryOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.operation, self.expression)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionUnaryOperation<'_, I, E>> for ExpressionUnaryOperation
+{
+    fn from(value: ast::ExpressionUnaryOperation<'_, I, E>) -> Self {
+        Self {
+            operation: value.operation.into(),
+            expression: Box::new(value.expression.as_ref().clone().into()),
+        }
+    }
+}
+
+/// Expression value of cast. It's represent conversion of the
+/// expression to primitive type
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionCast {
+    /// Expression for cast
+    pub expression: Box<Expression>,
+    /// Type to cast expression to
+    pub cast_type: PrimitiveTypes,
+}
+
+impl Display for ExpressionCast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} as {}", self.expression, self.cast_type)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::ExpressionCast<'_, I, E>>
+    for ExpressionCast
+{
+    fn from(value: ast::ExpressionCast<'_, I, E>) -> Self {
+        Self {
+            expression: Box::new(value.expression.as_ref().clone().into()),
+            cast_type: value.cast_type.into(),
+        }
+    }
+}
+
+/// Expression value of array element. It's represent access to
+/// array element by index
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionArrayElement {
+    /// Array expression
+    pub array: Box<Expression>,
+    /// Array element index expression
+    pub index: Box<Expression>,
+}
+
+impl Display for ExpressionArrayElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.array, self.index)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionArrayElement<'_, I, E>> for ExpressionArrayElement
+{
+    fn from(value: ast::ExpressionArrayElement<'_, I, E>) -> Self {
+        Self {
+            array: Box::new(value.array.as_ref().clone().into()),
+            index: Box::new(value.index.as_ref().clone().into()),
+        }
+    }
+}
+
+/// Expression value of indirect call. It's represent call of
+/// function by function pointer
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionIndirectCall {
+    /// Function pointer expression
+    pub callee: Box<Expression>,
+    /// Function parameters expressions
+    pub parameters: Vec<Expression>,
+}
+
+impl Display for ExpressionIndirectCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({})",
+            self.callee,
+            self.parameters
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionIndirectCall<'_, I, E>> for ExpressionIndirectCall
+{
+    fn from(value: ast::ExpressionIndirectCall<'_, I, E>) -> Self {
+        Self {
+            callee: Box::new(value.callee.as_ref().clone().into()),
+            parameters: value.parameters.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Expression value of closure. It's represent closure declaration,
+/// that captures values of enclosing states
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionClosure {
+    /// Closure parameters
+    pub parameters: Vec<FunctionParameter>,
+    /// Closure result type
+    pub result_type: Type,
+    /// Closure body statements
+    pub body: Vec<BodyStatement>,
+}
+
+impl Display for ExpressionClosure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "|{}| -> {}",
+            self.parameters
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.result_type
+        )
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::ExpressionClosure<'_, I, E>>
+    for ExpressionClosure
+{
+    fn from(value: ast::ExpressionClosure<'_, I, E>) -> Self {
+        Self {
+            parameters: value.parameters.into_iter().map(Into::into).collect(),
+            result_type: value.result_type.into(),
+            body: value.body.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Expression value of tuple element. It's represent access to
+/// tuple element by constant index
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionTupleElement {
+    /// Tuple expression
+    pub tuple: Box<Expression>,
+    /// Tuple element index
+    pub index: u32,
+}
+
+impl Display for ExpressionTupleElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.tuple, self.index)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionTupleElement<'_, I, E>> for ExpressionTupleElement
+{
+    fn from(value: ast::ExpressionTupleElement<'_, I, E>) -> Self {
+        Self {
+            tuple: Box::new(value.tuple.as_ref().clone().into()),
+            index: value.index,
+        }
+    }
+}
+
+/// Expression value of address-of. It's represent reference to
+/// the value
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionAddressOf {
+    /// Referenced value name
+    pub value: ValueName,
+    /// Mutable reference flag
+    pub mutable: bool,
+}
+
+impl Display for ExpressionAddressOf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.mutable {
+            write!(f, "&mut {}", self.value)
+        } else {
+            write!(f, "&{}", self.value)
+        }
+    }
+}
+
+impl From<ast::ExpressionAddressOf<'_>> for ExpressionAddressOf {
+    fn from(value: ast::ExpressionAddressOf<'_>) -> Self {
+        Self {
+            value: value.value.into(),
+            mutable: value.mutable,
+        }
+    }
+}
+
+/// Struct literal field. It's represent named struct attribute
+/// initialization
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct StructLiteralField {
+    /// Struct attribute name
+    pub name: ValueName,
+    /// Struct attribute value expression
+    pub value: Expression,
+}
+
+impl Display for StructLiteralField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.value)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::StructLiteralField<'_, I, E>> for StructLiteralField
+{
+    fn from(value: ast::StructLiteralField<'_, I, E>) -> Self {
+        Self {
+            name: value.name.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+/// Expression value of struct literal. It's represent construction
+/// of struct type value with named fields
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionStructLiteral {
+    /// Struct type name
+    pub name: String,
+    /// Struct fields initialization
+    pub fields: Vec<StructLiteralField>,
+}
+
+impl Display for ExpressionStructLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{} {{ {fields} }}", self.name)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionStructLiteral<'_, I, E>> for ExpressionStructLiteral
+{
+    fn from(value: ast::ExpressionStructLiteral<'_, I, E>) -> Self {
+        Self {
+            name: (*value.name.fragment()).to_string(),
+            fields: value.fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Expression value of enum variant. It's represent construction
+/// of enum type value for the variant with payload
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionEnumVariant {
+    /// Enum type name
+    pub name: String,
+    /// Enum variant name
+    pub variant: String,
+    /// Variant payload values
+    pub payload: Vec<Expression>,
+}
+
+impl Display for ExpressionEnumVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.payload.is_empty() {
+            return write!(f, "{}::{}", self.name, self.variant);
+        }
+        let payload = self
+            .payload
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{}::{}({payload})", self.name, self.variant)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionEnumVariant<'_, I, E>> for ExpressionEnumVariant
+{
+    fn from(value: ast::ExpressionEnumVariant<'_, I, E>) -> Self {
+        Self {
+            name: (*value.name.fragment()).to_string(),
+            variant: (*value.variant.fragment()).to_string(),
+            payload: value.payload.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Value-yielding branch: body statements followed by the branch
+/// result value
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionBranch {
+    /// Branch body statements
+    pub body: Vec<IfBodyStatement>,
+    /// Branch result value
+    pub value: Box<Expression>,
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::ExpressionBranch<'_, I, E>>
+    for ExpressionBranch
+{
+    fn from(value: ast::ExpressionBranch<'_, I, E>) -> Self {
+        Self {
+            body: value.body.into_iter().map(Into::into).collect(),
+            value: Box::new(value.value.as_ref().clone().into()),
+        }
+    }
+}
+
+/// Expression value of if expression. It's represent value-yielding
+/// if-else branches
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionIf {
+    /// If condition
+    pub condition: IfCondition,
+    /// If branch
+    pub body: ExpressionBranch,
+    /// Else branch
+    pub else_body: ExpressionBranch,
+}
+
+impl Display for ExpressionIf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "if {{ {} }} else {{ {} }}",
+            self.body.value, self.else_body.value
+        )
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::ExpressionIf<'_, I, E>>
+    for ExpressionIf
+{
+    fn from(value: ast::ExpressionIf<'_, I, E>) -> Self {
+        Self {
+            condition: value.condition.into(),
+            body: value.body.into(),
+            else_body: value.else_body.into(),
+        }
+    }
+}
+
+/// Expression value of method call. It's represent call of the
+/// struct method with receiver value
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ExpressionMethodCall {
+    /// Receiver expression
+    pub receiver: Box<Expression>,
+    /// Method name
+    pub name: FunctionName,
+    /// Method parameters
+    pub parameters: Vec<Expression>,
+}
+
+impl Display for ExpressionMethodCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.receiver, self.name)
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>>
+    From<ast::ExpressionMethodCall<'_, I, E>> for ExpressionMethodCall
+{
+    fn from(value: ast::ExpressionMethodCall<'_, I, E>) -> Self {
+        Self {
+            receiver: Box::new(value.receiver.as_ref().clone().into()),
+            name: value.name.into(),
+            parameters: value.parameters.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 /// # Expression
 /// Basic expression entity representation. It contains
 /// `ExpressionValue` and optional operations with other
@@ -185,7 +770,7 @@ pub struct Expression {
     /// Expression value
     pub expression_value: ExpressionValue,
     /// Optional expression operation under other `Expression`
-    pub operation: Option<(ExpressionOperations, Box<Expression>)>,
+    pub operation: Option<(ExpressionOperations, Box<Self>)>,
 }
 
 impl Display for Expression {
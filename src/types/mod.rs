@@ -8,6 +8,8 @@
 
 #![allow(clippy::module_inception)]
 
+/// Alloca values types
+pub mod alloca;
 /// Block state types
 pub mod block_state;
 /// Condition types
@@ -21,9 +23,12 @@ pub mod semantic;
 /// Types for type system
 pub mod types;
 
-use self::condition::{IfStatement, LoopBodyStatement};
-use self::expression::{Expression, ExpressionOperations};
-use self::types::Type;
+use self::condition::{
+    ForStatement, IfStatement, LabeledLoopStatement, LoopBodyStatement, MatchStatement,
+    WhileStatement,
+};
+use self::expression::{Expression, ExpressionOperations, ExpressionResult};
+use self::types::{ClosureType, PrimitiveTypes, StructTypes, Type, TypeParameterName};
 use crate::ast;
 use crate::ast::GetName;
 use crate::types::semantic::{ExtendedExpression, SemanticContextInstruction};
@@ -106,6 +111,29 @@ impl Display for LabelName {
     }
 }
 
+/// Loop label name type
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LoopLabel(String);
+
+impl From<ast::LoopLabel<'_>> for LoopLabel {
+    fn from(value: ast::LoopLabel<'_>) -> Self {
+        Self(value.name())
+    }
+}
+
+impl From<String> for LoopLabel {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for LoopLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.clone())
+    }
+}
+
 /// Function name type
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
@@ -181,7 +209,7 @@ pub struct ConstantExpression {
     /// Constant value for expression operation
     pub value: ConstantValue,
     /// Optional expression operation and next constant expression entry point
-    pub operation: Option<(ExpressionOperations, Box<ConstantExpression>)>,
+    pub operation: Option<(ExpressionOperations, Box<Self>)>,
 }
 
 impl From<ast::ConstantExpression<'_>> for ConstantExpression {
@@ -257,6 +285,92 @@ pub struct Function {
     pub parameters: Vec<Type>,
 }
 
+impl Function {
+    /// Get function pointer type of the function
+    #[must_use]
+    pub fn function_type(&self) -> Type {
+        Type::Function(self.parameters.clone(), Box::new(self.inner_type.clone()))
+    }
+}
+
+/// # Generic function
+/// Generic function declaration with type parameters. Parameters and
+/// result types can contain type parameters. Generic function is
+/// analyzed only as concrete instantiation for specific type arguments.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct GenericFunction {
+    /// Generic function name
+    pub inner_name: FunctionName,
+    /// Type parameters
+    pub type_parameters: Vec<TypeParameterName>,
+    /// Inner (return) type
+    pub inner_type: Type,
+    /// Function parameters types
+    pub parameters: Vec<Type>,
+}
+
+/// # Function instantiation
+/// Concrete instantiation of generic function for type arguments.
+/// Instantiation is declared as function with mangled name:
+/// `name<type_arguments>`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct FunctionInstantiation {
+    /// Generic function name
+    pub function: FunctionName,
+    /// Type arguments for generic function type parameters
+    pub type_arguments: Vec<Type>,
+}
+
+/// # Closure capture
+/// Value of the enclosing state, that captured by closure. Immutable
+/// values captured by value. Mutable values captured by reference,
+/// so changes are visible for the enclosing state.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ClosureCapture {
+    /// Captured value name
+    pub name: ValueName,
+    /// Captured value of the enclosing state
+    pub value: Value,
+    /// Value of the closure body, bound to closure environment attribute
+    pub inner_value: Value,
+    /// Is value captured by reference
+    pub by_reference: bool,
+}
+
+/// # Closure
+/// Closure declaration. Closure body analyzed as function body with
+/// additional environment struct parameter, that contains captured
+/// values in captures order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct Closure {
+    /// Closure name
+    pub inner_name: FunctionName,
+    /// Inner (return) type
+    pub inner_type: Type,
+    /// Closure parameters types
+    pub parameters: Vec<Type>,
+    /// Environment struct type
+    pub environment: StructTypes,
+    /// Captured values
+    pub captures: Vec<ClosureCapture>,
+}
+
+impl Closure {
+    /// Get closure type of the closure
+    #[must_use]
+    pub fn closure_type(&self) -> Type {
+        Type::Closure(ClosureType {
+            name: self.inner_name.to_string(),
+            parameters: self.parameters.clone(),
+            result_type: Box::new(self.inner_type.clone()),
+        })
+    }
+}
+
 /// Parameter name type for Functions parameter
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
@@ -305,6 +419,8 @@ impl Display for FunctionParameter {
 pub struct FunctionStatement {
     /// Function name
     pub name: FunctionName,
+    /// Type parameters of generic function
+    pub type_parameters: Vec<TypeParameterName>,
     /// Function parameters
     pub parameters: Vec<FunctionParameter>,
     /// Function result type
@@ -319,6 +435,7 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::Function
     fn from(value: ast::FunctionStatement<'_, I, E>) -> Self {
         Self {
             name: value.name.into(),
+            type_parameters: value.type_parameters.into_iter().map(Into::into).collect(),
             parameters: value.parameters.iter().map(|v| v.clone().into()).collect(),
             result_type: value.result_type.into(),
             body: value.body.iter().map(|v| v.clone().into()).collect(),
@@ -337,10 +454,16 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::Function
 )]
 pub enum BodyStatement {
     LetBinding(LetBinding),
+    LetTupleBinding(LetTupleBinding),
     Binding(Binding),
     FunctionCall(FunctionCall),
     If(IfStatement),
     Loop(Vec<LoopBodyStatement>),
+    LabeledLoop(LabeledLoopStatement),
+    While(WhileStatement),
+    For(ForStatement),
+    Match(MatchStatement),
+    Block(Vec<Self>),
     Expression(Expression),
     Return(Expression),
 }
@@ -351,10 +474,16 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::BodyStat
     fn from(value: ast::BodyStatement<'_, I, E>) -> Self {
         match value {
             ast::BodyStatement::LetBinding(v) => Self::LetBinding(v.into()),
+            ast::BodyStatement::LetTupleBinding(v) => Self::LetTupleBinding(v.into()),
             ast::BodyStatement::Binding(v) => Self::Binding(v.into()),
             ast::BodyStatement::FunctionCall(v) => Self::FunctionCall(v.into()),
-            ast::BodyStatement::If(v) => Self::If(v.into()),
+            ast::BodyStatement::If(v) => Self::If((*v).into()),
             ast::BodyStatement::Loop(v) => Self::Loop(v.iter().map(|v| v.clone().into()).collect()),
+            ast::BodyStatement::LabeledLoop(v) => Self::LabeledLoop(v.into()),
+            ast::BodyStatement::While(v) => Self::While((*v).into()),
+            ast::BodyStatement::For(v) => Self::For((*v).into()),
+            ast::BodyStatement::Match(v) => Self::Match(v.into()),
+            ast::BodyStatement::Block(v) => Self::Block(v.into_iter().map(Into::into).collect()),
             ast::BodyStatement::Expression(v) => Self::Expression(v.into()),
             ast::BodyStatement::Return(v) => Self::Return(v.into()),
         }
@@ -395,6 +524,72 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::LetBindi
     }
 }
 
+/// # Let tuple name
+/// Value name of the let binding tuple pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LetTupleName {
+    /// Value name
+    pub name: ValueName,
+    /// Value mutability flag
+    pub mutable: bool,
+}
+
+impl Display for LetTupleName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.mutable {
+            write!(f, "mut {}", self.name)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+impl From<ast::LetTupleName<'_>> for LetTupleName {
+    fn from(value: ast::LetTupleName<'_>) -> Self {
+        Self {
+            name: value.name.into(),
+            mutable: value.mutable,
+        }
+    }
+}
+
+/// # Let tuple binding
+/// Values initialization through tuple value destructuring.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct LetTupleBinding {
+    /// Tuple pattern values names
+    pub names: Vec<LetTupleName>,
+    /// Tuple value type
+    pub value_type: Option<Type>,
+    /// Tuple value bind expression
+    pub value: Box<Expression>,
+}
+
+impl Display for LetTupleBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = self
+            .names
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "({})", names.join(", "))
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::LetTupleBinding<'_, I, E>>
+    for LetTupleBinding
+{
+    fn from(value: ast::LetTupleBinding<'_, I, E>) -> Self {
+        Self {
+            names: value.names.into_iter().map(Into::into).collect(),
+            value_type: value.value_type.map(Into::into),
+            value: Box::new(value.value.as_ref().clone().into()),
+        }
+    }
+}
+
 /// Primitive value is most primitive and basic values entity.
 /// It's basic elements for all other values elements.
 #[derive(Debug, Clone, PartialEq)]
@@ -441,6 +636,70 @@ impl From<ast::PrimitiveValue> for PrimitiveValue {
     }
 }
 
+impl PrimitiveValue {
+    /// Get integer value of the primitive value. Useful for
+    /// constant indexes calculation.
+    ///
+    /// ## Return
+    /// `None` if value is not integer.
+    #[must_use]
+    pub fn integer_value(&self) -> Option<i128> {
+        match self {
+            Self::U8(val) => Some(i128::from(*val)),
+            Self::U16(val) => Some(i128::from(*val)),
+            Self::U32(val) => Some(i128::from(*val)),
+            Self::U64(val) => Some(i128::from(*val)),
+            Self::I8(val) => Some(i128::from(*val)),
+            Self::I16(val) => Some(i128::from(*val)),
+            Self::I32(val) => Some(i128::from(*val)),
+            Self::I64(val) => Some(i128::from(*val)),
+            _ => None,
+        }
+    }
+
+    /// Get integer primitive value of the `value_type` type.
+    ///
+    /// ## Return
+    /// `None` if type is not integer or value is out of type range.
+    #[must_use]
+    pub fn integer(value_type: &PrimitiveTypes, value: i128) -> Option<Self> {
+        match value_type {
+            PrimitiveTypes::U8 => u8::try_from(value).ok().map(Self::U8),
+            PrimitiveTypes::U16 => u16::try_from(value).ok().map(Self::U16),
+            PrimitiveTypes::U32 => u32::try_from(value).ok().map(Self::U32),
+            PrimitiveTypes::U64 => u64::try_from(value).ok().map(Self::U64),
+            PrimitiveTypes::I8 => i8::try_from(value).ok().map(Self::I8),
+            PrimitiveTypes::I16 => i16::try_from(value).ok().map(Self::I16),
+            PrimitiveTypes::I32 => i32::try_from(value).ok().map(Self::I32),
+            PrimitiveTypes::I64 => i64::try_from(value).ok().map(Self::I64),
+            _ => None,
+        }
+    }
+
+    /// Get maximum integer primitive value of the `value_type` type.
+    ///
+    /// ## Return
+    /// `None` if type is not integer.
+    #[must_use]
+    pub fn integer_max(value_type: &PrimitiveTypes) -> Option<Self> {
+        let value_bits = value_type.bit_width() - u8::from(value_type.is_signed_integer());
+        Self::integer(value_type, (1 << value_bits) - 1)
+    }
+
+    /// Get minimum integer primitive value of the `value_type` type.
+    ///
+    /// ## Return
+    /// `None` if type is not integer.
+    #[must_use]
+    pub fn integer_min(value_type: &PrimitiveTypes) -> Option<Self> {
+        if value_type.is_signed_integer() {
+            Self::integer(value_type, -(1 << (value_type.bit_width() - 1)))
+        } else {
+            Self::integer(value_type, 0)
+        }
+    }
+}
+
 impl Display for PrimitiveValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -491,19 +750,77 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::Function
     }
 }
 
+/// `PlaceAccess` represents access path element for binding target
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum PlaceAccess {
+    Field(ValueName),
+    Index(Box<Expression>),
+    Deref,
+}
+
+impl Display for PlaceAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::Deref => write!(f, ".*"),
+        }
+    }
+}
+
+impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::PlaceAccess<'_, I, E>>
+    for PlaceAccess
+{
+    fn from(value: ast::PlaceAccess<'_, I, E>) -> Self {
+        match value {
+            ast::PlaceAccess::Field(v) => Self::Field(v.into()),
+            ast::PlaceAccess::Index(v) => Self::Index(Box::new(v.as_ref().clone().into())),
+            ast::PlaceAccess::Deref => Self::Deref,
+        }
+    }
+}
+
+/// `PlaceIndex` represents resolved access path element for the
+/// binding target:
+/// - `Field` - struct attribute index
+/// - `Element` - array element index expression result
+/// - `Deref` - pointer or reference target
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum PlaceIndex {
+    Field(u32),
+    Element(ExpressionResult),
+    Deref,
+}
+
 /// `Binding` represents mutable binding for previously bind values
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 pub struct Binding {
     /// Binding value name
     pub name: ValueName,
+    /// Access path to binding target
+    pub path: Vec<PlaceAccess>,
     /// Value expression representation
     pub value: Box<Expression>,
 }
 
 impl Display for Binding {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", self.name)?;
+        for access in &self.path {
+            write!(f, "{access}")?;
+        }
+        Ok(())
     }
 }
 
@@ -513,6 +830,7 @@ impl<I: SemanticContextInstruction, E: ExtendedExpression<I>> From<ast::Binding<
     fn from(value: ast::Binding<'_, I, E>) -> Self {
         Self {
             name: value.name.into(),
+            path: value.path.into_iter().map(Into::into).collect(),
             value: Box::new(value.value.as_ref().clone().into()),
         }
     }
@@ -3,9 +3,12 @@
 //! It contains `SemanticStack` as Semantic results Context data.
 
 use super::condition::{Condition, LogicCondition};
-use super::expression::{ExpressionOperations, ExpressionResult};
-use super::types::StructTypes;
-use super::{Constant, Function, FunctionParameter, FunctionStatement, LabelName, Value};
+use super::expression::{ExpressionOperations, ExpressionResult, UnaryOperations};
+use super::types::{CastKind, EnumTypes, PrimitiveTypes, StructTypes, Type};
+use super::{
+    Closure, Constant, Function, FunctionParameter, FunctionStatement, LabelName, PlaceIndex,
+    PrimitiveValue, Value,
+};
 use crate::semantic::State;
 use crate::types::block_state::BlockState;
 #[cfg(feature = "codec")]
@@ -19,10 +22,13 @@ use std::rc::Rc;
 /// - functions
 /// - types
 /// - constants
+/// - closures
 pub trait GlobalSemanticContext {
     fn function_declaration(&mut self, fn_decl: FunctionStatement);
     fn constant(&mut self, const_decl: Constant);
     fn types(&mut self, type_decl: StructTypes);
+    fn enum_types(&mut self, type_decl: EnumTypes);
+    fn closure_declaration(&mut self, closure: Closure);
 }
 
 /// Semantic Context trait contain instructions set functions
@@ -30,7 +36,7 @@ pub trait GlobalSemanticContext {
 pub trait SemanticContext {
     fn expression_value(&mut self, expression: Value, register_number: u64);
     fn expression_const(&mut self, expression: Constant, register_number: u64);
-    fn expression_struct_value(&mut self, expression: Value, index: u32, register_number: u64);
+    fn expression_struct_value(&mut self, expression: Value, path: Vec<u32>, register_number: u64);
     fn expression_operation(
         &mut self,
         operation: ExpressionOperations,
@@ -38,9 +44,82 @@ pub trait SemanticContext {
         right_value: ExpressionResult,
         register_number: u64,
     );
+    fn unary_operation(
+        &mut self,
+        operation: UnaryOperations,
+        value: ExpressionResult,
+        register_number: u64,
+    );
+    fn cast(
+        &mut self,
+        value: ExpressionResult,
+        from: PrimitiveTypes,
+        to: PrimitiveTypes,
+        kind: CastKind,
+        register_number: u64,
+    );
+    fn expression_array_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        array_type: Type,
+        register_number: u64,
+    );
+    fn expression_struct_literal(
+        &mut self,
+        struct_type: Type,
+        values: Vec<ExpressionResult>,
+        register_number: u64,
+    );
+    fn expression_enum_variant(
+        &mut self,
+        enum_type: Type,
+        discriminant: u32,
+        payload: Vec<ExpressionResult>,
+        register_number: u64,
+    );
+    fn enum_discriminant(&mut self, value: ExpressionResult, register_number: u64);
+    fn expression_tuple_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        tuple_type: Type,
+        register_number: u64,
+    );
+    fn tuple_element(
+        &mut self,
+        tuple: ExpressionResult,
+        index: u32,
+        element_type: Type,
+        register_number: u64,
+    );
+    fn array_element_address(
+        &mut self,
+        array: ExpressionResult,
+        index: ExpressionResult,
+        register_number: u64,
+    );
+    fn load(&mut self, value_type: Type, address_register: u64, register_number: u64);
+    fn environment_field(&mut self, attr_index: u32, attr_type: Type, register_number: u64);
+    fn address_of(&mut self, value: Value, register_number: u64);
     fn call(&mut self, call: Function, params: Vec<ExpressionResult>, register_number: u64);
+    fn function_address(&mut self, function: Function, register_number: u64);
+    fn create_closure(&mut self, closure: Closure, register_number: u64);
+    fn closure_call(
+        &mut self,
+        closure: Closure,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    );
+    fn indirect_call(
+        &mut self,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    );
     fn let_binding(&mut self, let_decl: Value, expr_result: ExpressionResult);
     fn binding(&mut self, val: Value, expr_result: ExpressionResult);
+    fn store(&mut self, val: Value, path: Vec<PlaceIndex>, expr_result: ExpressionResult);
+    fn store_address(&mut self, address_register: u64, expr_result: ExpressionResult);
     fn expression_function_return(&mut self, expr_result: ExpressionResult);
     fn expression_function_return_with_label(&mut self, expr_result: ExpressionResult);
     fn set_label(&mut self, label: LabelName);
@@ -59,6 +138,7 @@ pub trait SemanticContext {
         register_number: u64,
     );
     fn jump_function_return(&mut self, expr_result: ExpressionResult);
+    fn function_return_label(&mut self, return_type: Type);
     fn logic_condition(
         &mut self,
         logic_condition: LogicCondition,
@@ -72,6 +152,14 @@ pub trait SemanticContext {
         label_if_end: LabelName,
         result_register: u64,
     );
+    fn switch(
+        &mut self,
+        value: ExpressionResult,
+        cases: Vec<(PrimitiveValue, LabelName)>,
+        label_default: LabelName,
+    );
+    fn merge_value(&mut self, label: LabelName, expr_result: ExpressionResult);
+    fn merge_result(&mut self, label: LabelName, expr_type: Type, register_number: u64);
     fn function_arg(&mut self, value: Value, func_arg: FunctionParameter);
 }
 
@@ -154,6 +242,28 @@ impl<I: SemanticContextInstruction> GlobalSemanticContext for SemanticStack<I> {
     fn types(&mut self, type_decl: StructTypes) {
         self.push(SemanticStackContext::Types { type_decl });
     }
+
+    /// Push Context to the stack as enum types data.
+    /// Enum types declaration instruction. Declaration contains
+    /// variants discriminants and payload types.
+    ///
+    /// ## Parameters
+    /// - `type_decl` - enum type declaration parameters
+    fn enum_types(&mut self, type_decl: EnumTypes) {
+        self.push(SemanticStackContext::EnumTypes { type_decl });
+    }
+
+    /// Push Context to the stack as closure declaration data.
+    /// Closure declaration instruction. Declaration contains
+    /// environment struct type and captured values.
+    ///
+    /// ## Parameters
+    /// - `closure` - closure declaration parameters
+    fn closure_declaration(&mut self, closure: Closure) {
+        self.push(SemanticStackContext::ClosureDeclaration {
+            closure: Box::new(closure),
+        });
+    }
 }
 
 impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
@@ -185,12 +295,13 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
     ///
     /// ## Parameters
     /// - `expression` - contains expression value for specific `Structure` attribute
-    /// - `index` - represent attribute index in the `Structure` type
+    /// - `path` - represent attributes indexes path in the `Structure` type,
+    ///   for nested structures attributes it contains index for each level
     /// - `register_number` - register to store result data
-    fn expression_struct_value(&mut self, expression: Value, index: u32, register_number: u64) {
+    fn expression_struct_value(&mut self, expression: Value, path: Vec<u32>, register_number: u64) {
         self.push(SemanticStackContext::ExpressionStructValue {
             expression,
-            index,
+            path,
             register_number,
         });
     }
@@ -219,6 +330,248 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
         });
     }
 
+    /// Push Context to the stack as unary operation data.
+    /// `unary_operation` imply operation over `value` and
+    /// store result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `operation` - specific unary operation
+    /// - `value` - expression result for unary operation
+    /// - `register_number` - register to store result of unary operation
+    fn unary_operation(
+        &mut self,
+        operation: UnaryOperations,
+        value: ExpressionResult,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::UnaryOperation {
+            operation,
+            value,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as cast data.
+    /// `cast` imply conversion of `value` from one primitive type
+    /// to another and store result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `value` - expression result for cast
+    /// - `from` - source type of the value
+    /// - `to` - target type of the value
+    /// - `kind` - conversion kind
+    /// - `register_number` - register to store result of cast
+    fn cast(
+        &mut self,
+        value: ExpressionResult,
+        from: PrimitiveTypes,
+        to: PrimitiveTypes,
+        kind: CastKind,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::Cast {
+            value,
+            from,
+            to,
+            kind,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as array value data.
+    /// `expression_array_value` imply building array of `array_type`
+    /// from `values` and store result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `values` - expression results of array elements
+    /// - `array_type` - type of the array
+    /// - `register_number` - register to store array value
+    fn expression_array_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        array_type: Type,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ExpressionArrayValue {
+            values,
+            array_type,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as struct literal data.
+    /// `expression_struct_literal` imply building value of
+    /// `struct_type` from `values` and store result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `struct_type` - struct type of the value
+    /// - `values` - expression results of struct attributes in
+    ///   attributes index order
+    /// - `register_number` - register to store struct value
+    fn expression_struct_literal(
+        &mut self,
+        struct_type: Type,
+        values: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ExpressionStructLiteral {
+            struct_type,
+            values,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as enum variant data.
+    /// `expression_enum_variant` imply building value of `enum_type`
+    /// with `discriminant` and variant `payload` values, and store
+    /// result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `enum_type` - enum type of the value
+    /// - `discriminant` - discriminant of the enum variant
+    /// - `payload` - expression results of variant payload
+    /// - `register_number` - register to store enum value
+    fn expression_enum_variant(
+        &mut self,
+        enum_type: Type,
+        discriminant: u32,
+        payload: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ExpressionEnumVariant {
+            enum_type,
+            discriminant,
+            payload,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as enum discriminant data.
+    /// `enum_discriminant` imply reading discriminant of the enum
+    /// `value` and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `value` - expression result of enum value
+    /// - `register_number` - register to store discriminant
+    fn enum_discriminant(&mut self, value: ExpressionResult, register_number: u64) {
+        self.push(SemanticStackContext::EnumDiscriminant {
+            value,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as tuple value data.
+    /// `expression_tuple_value` imply building tuple of `tuple_type`
+    /// from `values` and store result to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `values` - expression results of tuple elements
+    /// - `tuple_type` - type of the tuple
+    /// - `register_number` - register to store tuple value
+    fn expression_tuple_value(
+        &mut self,
+        values: Vec<ExpressionResult>,
+        tuple_type: Type,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ExpressionTupleValue {
+            values,
+            tuple_type,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as tuple element data.
+    /// `tuple_element` imply extracting element of the `tuple` value
+    /// by constant `index` and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `tuple` - expression result of tuple
+    /// - `index` - tuple element index
+    /// - `element_type` - type of the tuple element
+    /// - `register_number` - register to store element value
+    fn tuple_element(
+        &mut self,
+        tuple: ExpressionResult,
+        index: u32,
+        element_type: Type,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::TupleElement {
+            tuple,
+            index,
+            element_type,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as array element address data.
+    /// `array_element_address` imply calculation of address of the
+    /// `array` element by `index` and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `array` - expression result of array
+    /// - `index` - expression result of element index
+    /// - `register_number` - register to store element address
+    fn array_element_address(
+        &mut self,
+        array: ExpressionResult,
+        index: ExpressionResult,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ArrayElementAddress {
+            array,
+            index,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as load data.
+    /// `load` imply loading value of `value_type` from address
+    /// stored in `address_register` to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `value_type` - type of the loaded value
+    /// - `address_register` - register that contains address
+    /// - `register_number` - register to store loaded value
+    fn load(&mut self, value_type: Type, address_register: u64, register_number: u64) {
+        self.push(SemanticStackContext::Load {
+            value_type,
+            address_register,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as environment field data.
+    /// `environment_field` imply loading attribute of the closure
+    /// environment, that passed to the closure body, to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `attr_index` - environment struct attribute index
+    /// - `attr_type` - environment struct attribute type
+    /// - `register_number` - register to store loaded attribute
+    fn environment_field(&mut self, attr_index: u32, attr_type: Type, register_number: u64) {
+        self.push(SemanticStackContext::EnvironmentField {
+            attr_index,
+            attr_type,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as address-of data.
+    /// `address_of` imply getting address of the `value`, that
+    /// allocated in memory, and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `value` - referenced value
+    /// - `register_number` - register to store value address
+    fn address_of(&mut self, value: Value, register_number: u64) {
+        self.push(SemanticStackContext::AddressOf {
+            value,
+            register_number,
+        });
+    }
+
     /// Push Context to the stack as function call data.
     /// Function call instruction with parameters and result data.
     ///
@@ -234,6 +587,80 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
         });
     }
 
+    /// Push Context to the stack as function address data.
+    /// `function_address` imply getting pointer to the `function`
+    /// and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `function` - function declaration data
+    /// - `register_number` - register to store function pointer
+    fn function_address(&mut self, function: Function, register_number: u64) {
+        self.push(SemanticStackContext::FunctionAddress {
+            function,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as closure creation data.
+    /// `create_closure` imply creating closure value with environment,
+    /// that initialized by captured values: by value or by reference,
+    /// and store it to `register_number`.
+    ///
+    /// ## Parameters
+    /// - `closure` - closure declaration data
+    /// - `register_number` - register to store closure value
+    fn create_closure(&mut self, closure: Closure, register_number: u64) {
+        self.push(SemanticStackContext::CreateClosure {
+            closure: Box::new(closure),
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as closure call data.
+    /// Call of the closure with its environment, parameters and
+    /// result data.
+    ///
+    /// ## Parameters
+    /// - `closure` - closure declaration data
+    /// - `callee` - expression result of closure value
+    /// - `params` - closure parameters
+    /// - `register_number` - register to store result of closure call
+    fn closure_call(
+        &mut self,
+        closure: Closure,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::ClosureCall {
+            closure: Box::new(closure),
+            callee,
+            params,
+            register_number,
+        });
+    }
+
+    /// Push Context to the stack as indirect call data.
+    /// Call of the function by function pointer with parameters
+    /// and result data.
+    ///
+    /// ## Parameters
+    /// - `callee` - expression result of function pointer type
+    /// - `params` - function parameters
+    /// - `register_number` - register to store result of function call
+    fn indirect_call(
+        &mut self,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    ) {
+        self.push(SemanticStackContext::IndirectCall {
+            callee,
+            params,
+            register_number,
+        });
+    }
+
     /// Push Context to the stack as let-binding data.
     /// Let binding instruction that "bind" expression result to
     /// the new value.
@@ -259,6 +686,36 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
         self.push(SemanticStackContext::Binding { val, expr_result });
     }
 
+    /// Push Context to the stack as store data.
+    /// Store instruction that "bind" expression result to the
+    /// value place: field or element of previously init value.
+    ///
+    /// ## Parameters
+    /// - `val` - value declaration
+    /// - `path` - resolved access path to the value place
+    /// - `expr_result` - expression result that will be stored to the place
+    fn store(&mut self, val: Value, path: Vec<PlaceIndex>, expr_result: ExpressionResult) {
+        self.push(SemanticStackContext::Store {
+            val,
+            path,
+            expr_result,
+        });
+    }
+
+    /// Push Context to the stack as store by address data.
+    /// Store instruction that "bind" expression result to the place,
+    /// that address stored in `address_register`.
+    ///
+    /// ## Parameters
+    /// - `address_register` - register that contains address
+    /// - `expr_result` - expression result that will be stored to the place
+    fn store_address(&mut self, address_register: u64, expr_result: ExpressionResult) {
+        self.push(SemanticStackContext::StoreAddress {
+            address_register,
+            expr_result,
+        });
+    }
+
     /// Push Context to the stack as expression function return data.
     /// Return instruction, should be used in the end of functions.
     /// Alwats should be only once.
@@ -357,6 +814,18 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
         self.push(SemanticStackContext::JumpFunctionReturn { expr_result });
     }
 
+    /// Push Context to the stack as `function return label` data.
+    /// Set `return` label and return result, that was set before by
+    /// `jump_function_return`. It's used in the end of function,
+    /// when all function body flow is jumped to `return` label, and
+    /// function body doesn't contain `return` in the end.
+    ///
+    /// ## Parameters
+    /// - `return_type` - function return type
+    fn function_return_label(&mut self, return_type: Type) {
+        self.push(SemanticStackContext::FunctionReturnLabel { return_type });
+    }
+
     /// Push Context to the stack as `logic condition` data.
     /// Operate with registers: left and right for specific logic condition.
     /// Result of calculation stored to `register_number`.
@@ -407,6 +876,55 @@ impl<I: SemanticContextInstruction> SemanticContext for SemanticStack<I> {
         });
     }
 
+    /// Push Context to the stack as `switch` data.
+    /// Multi-way jump by the `value`: jump to the case label if value
+    /// is equal to case value, otherwise jump to `label_default`.
+    ///
+    /// ## Parameters
+    /// - `value` - expression result of switch value
+    /// - `cases` - cases values and labels
+    /// - `label_default` - label for values without case
+    fn switch(
+        &mut self,
+        value: ExpressionResult,
+        cases: Vec<(PrimitiveValue, LabelName)>,
+        label_default: LabelName,
+    ) {
+        self.push(SemanticStackContext::Switch {
+            value,
+            cases,
+            label_default,
+        });
+    }
+
+    /// Push Context to the stack as `merge value` data.
+    /// Value of the value-yielding branch, that incoming to the merge
+    /// point `label`. It's followed by jump to the merge point.
+    ///
+    /// ## Parameters
+    /// - `label` - label of the merge point
+    /// - `expr_result` - branch result value
+    fn merge_value(&mut self, label: LabelName, expr_result: ExpressionResult) {
+        self.push(SemanticStackContext::MergeValue { label, expr_result });
+    }
+
+    /// Push Context to the stack as `merge result` data.
+    /// Phi-like instruction: merge all incoming branches values of the
+    /// merge point `label` to the register. It's set right after the
+    /// merge point label.
+    ///
+    /// ## Parameters
+    /// - `label` - label of the merge point
+    /// - `expr_type` - type of the merged values
+    /// - `register_number` - register to store result
+    fn merge_result(&mut self, label: LabelName, expr_type: Type, register_number: u64) {
+        self.push(SemanticStackContext::MergeResult {
+            label,
+            expr_type,
+            register_number,
+        });
+    }
+
     /// Push Context to the stack as `function argument` data.
     /// This instruction should allocate pointer (if argument type is
     /// not Ptr) and store argument value to the pointer.
@@ -449,7 +967,7 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
     },
     ExpressionStructValue {
         expression: Value,
-        index: u32,
+        path: Vec<u32>,
         register_number: u64,
     },
     ExpressionOperation {
@@ -458,11 +976,92 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
         right_value: ExpressionResult,
         register_number: u64,
     },
+    UnaryOperation {
+        operation: UnaryOperations,
+        value: ExpressionResult,
+        register_number: u64,
+    },
+    Cast {
+        value: ExpressionResult,
+        from: PrimitiveTypes,
+        to: PrimitiveTypes,
+        kind: CastKind,
+        register_number: u64,
+    },
+    ExpressionArrayValue {
+        values: Vec<ExpressionResult>,
+        array_type: Type,
+        register_number: u64,
+    },
+    ExpressionStructLiteral {
+        struct_type: Type,
+        values: Vec<ExpressionResult>,
+        register_number: u64,
+    },
+    ExpressionEnumVariant {
+        enum_type: Type,
+        discriminant: u32,
+        payload: Vec<ExpressionResult>,
+        register_number: u64,
+    },
+    EnumDiscriminant {
+        value: ExpressionResult,
+        register_number: u64,
+    },
+    ExpressionTupleValue {
+        values: Vec<ExpressionResult>,
+        tuple_type: Type,
+        register_number: u64,
+    },
+    TupleElement {
+        tuple: ExpressionResult,
+        index: u32,
+        element_type: Type,
+        register_number: u64,
+    },
+    ArrayElementAddress {
+        array: ExpressionResult,
+        index: ExpressionResult,
+        register_number: u64,
+    },
+    Load {
+        value_type: Type,
+        address_register: u64,
+        register_number: u64,
+    },
+    EnvironmentField {
+        attr_index: u32,
+        attr_type: Type,
+        register_number: u64,
+    },
+    AddressOf {
+        value: Value,
+        register_number: u64,
+    },
     Call {
         call: Function,
         params: Vec<ExpressionResult>,
         register_number: u64,
     },
+    FunctionAddress {
+        function: Function,
+        register_number: u64,
+    },
+    IndirectCall {
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    },
+    CreateClosure {
+        closure: Box<Closure>,
+        register_number: u64,
+    },
+    ClosureCall {
+        closure: Box<Closure>,
+        callee: ExpressionResult,
+        params: Vec<ExpressionResult>,
+        register_number: u64,
+    },
     LetBinding {
         let_decl: Value,
         expr_result: ExpressionResult,
@@ -471,6 +1070,15 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
         val: Value,
         expr_result: ExpressionResult,
     },
+    Store {
+        val: Value,
+        path: Vec<PlaceIndex>,
+        expr_result: ExpressionResult,
+    },
+    StoreAddress {
+        address_register: u64,
+        expr_result: ExpressionResult,
+    },
     FunctionDeclaration {
         fn_decl: FunctionStatement,
     },
@@ -480,6 +1088,12 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
     Types {
         type_decl: StructTypes,
     },
+    EnumTypes {
+        type_decl: EnumTypes,
+    },
+    ClosureDeclaration {
+        closure: Box<Closure>,
+    },
     ExpressionFunctionReturn {
         expr_result: ExpressionResult,
     },
@@ -506,6 +1120,9 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
     JumpFunctionReturn {
         expr_result: ExpressionResult,
     },
+    FunctionReturnLabel {
+        return_type: Type,
+    },
     LogicCondition {
         logic_condition: LogicCondition,
         left_register_result: u64,
@@ -517,6 +1134,20 @@ pub enum SemanticStackContext<I: SemanticContextInstruction> {
         label_if_end: LabelName,
         result_register: u64,
     },
+    Switch {
+        value: ExpressionResult,
+        cases: Vec<(PrimitiveValue, LabelName)>,
+        label_default: LabelName,
+    },
+    MergeValue {
+        label: LabelName,
+        expr_result: ExpressionResult,
+    },
+    MergeResult {
+        label: LabelName,
+        expr_type: Type,
+        register_number: u64,
+    },
     FunctionArg {
         value: Value,
         func_arg: FunctionParameter,
@@ -1,7 +1,7 @@
 //! # Semantic types
 //! Type-system types for Semantic analyzer State results.
 
-use super::{FunctionName, ValueName};
+use super::{ConstantValue, FunctionName, PrimitiveValue, ValueName};
 use crate::ast::{self, GetName};
 #[cfg(feature = "codec")]
 use serde::{Deserialize, Serialize};
@@ -46,12 +46,47 @@ impl Display for TypeName {
     }
 }
 
+/// Type parameter name of generic function
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct TypeParameterName(String);
+
+impl From<ast::TypeParameterName<'_>> for TypeParameterName {
+    fn from(value: ast::TypeParameterName<'_>) -> Self {
+        Self(value.name())
+    }
+}
+
+impl From<String> for TypeParameterName {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for TypeParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.clone())
+    }
+}
+
 /// # Type
 /// Basic representation of Type. Basic entities:
 /// - primitive type
 /// - struct type
+/// - enum type
 /// - array type
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// - tuple type
+/// - pointer and reference types
+/// - type parameter of generic function
+/// - instance of generic struct type
+/// - type alias
+/// - function pointer type
+/// - closure type
+/// - array type with const parameter length
+///
+/// Type alias is transparent for types comparison: it's equal to the
+/// aliased type, but displayed with alias name.
+#[derive(Debug, Clone, Eq)]
 #[cfg_attr(
     feature = "codec",
     derive(Serialize, Deserialize),
@@ -60,7 +95,47 @@ impl Display for TypeName {
 pub enum Type {
     Primitive(PrimitiveTypes),
     Struct(StructTypes),
+    Enum(EnumTypes),
     Array(Box<Self>, u32),
+    Tuple(Vec<Self>),
+    Pointer(Box<Self>),
+    Reference(Box<Self>),
+    MutableReference(Box<Self>),
+    Parameter(TypeParameterName),
+    StructInstance(StructInstanceType),
+    Alias(String, Box<Self>),
+    Function(Vec<Self>, Box<Self>),
+    Closure(ClosureType),
+    ParameterArray(Box<Self>, TypeParameterName),
+}
+
+impl PartialEq for Type {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Alias(_, ty), _) => ty.as_ref() == other,
+            (_, Self::Alias(_, ty)) => self == ty.as_ref(),
+            (Self::Primitive(left), Self::Primitive(right)) => left == right,
+            (Self::Struct(left), Self::Struct(right)) => left == right,
+            (Self::Enum(left), Self::Enum(right)) => left == right,
+            (Self::Array(left, left_size), Self::Array(right, right_size)) => {
+                left_size == right_size && left == right
+            }
+            (Self::Tuple(left), Self::Tuple(right)) => left == right,
+            (Self::Pointer(left), Self::Pointer(right))
+            | (Self::Reference(left), Self::Reference(right))
+            | (Self::MutableReference(left), Self::MutableReference(right)) => left == right,
+            (Self::Parameter(left), Self::Parameter(right)) => left == right,
+            (Self::StructInstance(left), Self::StructInstance(right)) => left == right,
+            (Self::Function(left, left_result), Self::Function(right, right_result)) => {
+                left == right && left_result == right_result
+            }
+            (Self::Closure(left), Self::Closure(right)) => left == right,
+            (Self::ParameterArray(left, left_size), Self::ParameterArray(right, right_size)) => {
+                left_size == right_size && left == right
+            }
+            _ => false,
+        }
+    }
 }
 
 impl Type {
@@ -70,14 +145,282 @@ impl Type {
         self.to_string().into()
     }
 
+    /// Get aliased type. For aliases of aliases return the most inner
+    /// aliased type. Other types returned as is.
+    #[must_use]
+    pub fn unalias(&self) -> &Self {
+        match self {
+            Self::Alias(_, ty) => ty.unalias(),
+            ty => ty,
+        }
+    }
+
+    /// Get type with all aliases replaced by aliased types, including
+    /// aliases of nested types.
+    #[must_use]
+    pub fn without_aliases(&self) -> Self {
+        match self {
+            Self::Alias(_, ty) => ty.without_aliases(),
+            Self::Array(ty, size) => Self::Array(Box::new(ty.without_aliases()), *size),
+            Self::Tuple(types) => Self::Tuple(types.iter().map(Self::without_aliases).collect()),
+            Self::Pointer(ty) => Self::Pointer(Box::new(ty.without_aliases())),
+            Self::Reference(ty) => Self::Reference(Box::new(ty.without_aliases())),
+            Self::MutableReference(ty) => Self::MutableReference(Box::new(ty.without_aliases())),
+            Self::Function(parameters, result_type) => Self::Function(
+                parameters.iter().map(Self::without_aliases).collect(),
+                Box::new(result_type.without_aliases()),
+            ),
+            ty => ty.clone(),
+        }
+    }
+
     /// Get structure type if it is
     #[must_use]
     pub fn get_struct(&self) -> Option<StructTypes> {
-        match self {
+        match self.unalias() {
             Self::Struct(ty) => Some(ty.clone()),
             _ => None,
         }
     }
+
+    /// Get enum type if it is
+    #[must_use]
+    pub fn get_enum(&self) -> Option<EnumTypes> {
+        match self.unalias() {
+            Self::Enum(ty) => Some(ty.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get tuple elements types if it is
+    #[must_use]
+    pub fn get_tuple(&self) -> Option<Vec<Self>> {
+        match self.unalias() {
+            Self::Tuple(types) => Some(types.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get pointee type if it's pointer or reference
+    #[must_use]
+    pub fn get_pointee(&self) -> Option<Self> {
+        match self.unalias() {
+            Self::Pointer(ty) | Self::Reference(ty) | Self::MutableReference(ty) => {
+                Some(*ty.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Get parameters types and result type if it's function pointer
+    #[must_use]
+    pub fn get_function(&self) -> Option<(Vec<Self>, Self)> {
+        match self.unalias() {
+            Self::Function(parameters, result_type) => {
+                Some((parameters.clone(), *result_type.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check is pointee can be changed through the pointer: raw
+    /// pointer or mutable reference
+    #[must_use]
+    pub const fn is_mutable_pointer(&self) -> bool {
+        match self {
+            Self::Alias(_, ty) => ty.is_mutable_pointer(),
+            ty => matches!(ty, Self::Pointer(_) | Self::MutableReference(_)),
+        }
+    }
+
+    /// Check is type contains type parameters
+    #[must_use]
+    pub fn has_type_parameters(&self) -> bool {
+        match self {
+            Self::Parameter(_) | Self::ParameterArray(..) => true,
+            Self::Array(ty, _)
+            | Self::Pointer(ty)
+            | Self::Reference(ty)
+            | Self::MutableReference(ty) => ty.has_type_parameters(),
+            Self::Tuple(types) => types.iter().any(Self::has_type_parameters),
+            Self::Function(parameters, result_type) => {
+                parameters.iter().any(Self::has_type_parameters)
+                    || result_type.has_type_parameters()
+            }
+            Self::StructInstance(instance) => instance
+                .type_arguments
+                .iter()
+                .any(GenericArgument::has_type_parameters),
+            _ => false,
+        }
+    }
+
+    /// Substitute type parameters with type arguments, and const
+    /// parameters with const arguments values. Parameters without
+    /// arguments stay unchanged.
+    #[must_use]
+    pub fn substitute_type_parameters(
+        &self,
+        type_arguments: &HashMap<TypeParameterName, Self>,
+        const_arguments: &HashMap<TypeParameterName, u32>,
+    ) -> Self {
+        let substitute = |ty: &Self| ty.substitute_type_parameters(type_arguments, const_arguments);
+        match self {
+            Self::Parameter(name) => type_arguments
+                .get(name)
+                .map_or_else(|| self.clone(), Clone::clone),
+            Self::Array(ty, size) => Self::Array(Box::new(substitute(ty)), *size),
+            Self::ParameterArray(ty, size) => const_arguments.get(size).map_or_else(
+                || Self::ParameterArray(Box::new(substitute(ty)), size.clone()),
+                |size| Self::Array(Box::new(substitute(ty)), *size),
+            ),
+            Self::Tuple(types) => Self::Tuple(types.iter().map(substitute).collect()),
+            Self::Pointer(ty) => Self::Pointer(Box::new(substitute(ty))),
+            Self::Reference(ty) => Self::Reference(Box::new(substitute(ty))),
+            Self::MutableReference(ty) => Self::MutableReference(Box::new(substitute(ty))),
+            Self::StructInstance(instance) => Self::StructInstance(StructInstanceType {
+                name: instance.name.clone(),
+                type_arguments: instance
+                    .type_arguments
+                    .iter()
+                    .map(|argument| match argument {
+                        GenericArgument::Type(ty) => GenericArgument::Type(substitute(ty)),
+                        GenericArgument::Const(ConstantValue::Constant(name)) => {
+                            const_arguments.get(&name.to_string().into()).map_or_else(
+                                || argument.clone(),
+                                |value| GenericArgument::value(*value),
+                            )
+                        }
+                        GenericArgument::Const(_) => argument.clone(),
+                    })
+                    .collect(),
+            }),
+            Self::Function(parameters, result_type) => Self::Function(
+                parameters.iter().map(substitute).collect(),
+                Box::new(substitute(result_type)),
+            ),
+            ty => ty.clone(),
+        }
+    }
+
+    /// Infer type arguments of generic type parameters by matching
+    /// current type, that can contain type parameters, with concrete
+    /// type. Inferred type arguments are added to `type_arguments`.
+    ///
+    /// ## Return
+    /// Is types matched with already inferred type arguments
+    pub fn infer_type_arguments(
+        &self,
+        ty: &Self,
+        type_arguments: &mut HashMap<TypeParameterName, Self>,
+    ) -> bool {
+        match (self, ty) {
+            (Self::Parameter(name), _) => {
+                if let Some(type_argument) = type_arguments.get(name) {
+                    return type_argument == ty;
+                }
+                type_arguments.insert(name.clone(), ty.clone());
+                true
+            }
+            (Self::Array(param_ty, param_size), Self::Array(arg_ty, arg_size)) => {
+                param_size == arg_size && param_ty.infer_type_arguments(arg_ty, type_arguments)
+            }
+            (Self::Tuple(param_types), Self::Tuple(arg_types)) => {
+                param_types.len() == arg_types.len()
+                    && param_types.iter().zip(arg_types).all(|(param_ty, arg_ty)| {
+                        param_ty.infer_type_arguments(arg_ty, type_arguments)
+                    })
+            }
+            (Self::Pointer(param_ty), Self::Pointer(arg_ty))
+            | (Self::Reference(param_ty), Self::Reference(arg_ty))
+            | (Self::MutableReference(param_ty), Self::MutableReference(arg_ty)) => {
+                param_ty.infer_type_arguments(arg_ty, type_arguments)
+            }
+            (Self::Function(param_types, param_result), Self::Function(arg_types, arg_result)) => {
+                param_types.len() == arg_types.len()
+                    && param_types.iter().zip(arg_types).all(|(param_ty, arg_ty)| {
+                        param_ty.infer_type_arguments(arg_ty, type_arguments)
+                    })
+                    && param_result.infer_type_arguments(arg_result, type_arguments)
+            }
+            (Self::Alias(_, param_ty), _) => param_ty.infer_type_arguments(ty, type_arguments),
+            (_, Self::Alias(_, arg_ty)) => self.infer_type_arguments(arg_ty, type_arguments),
+            // Concrete struct type is instance of generic struct type
+            (Self::StructInstance(param_instance), Self::Struct(arg_struct)) => {
+                arg_struct.instance.as_ref().is_some_and(|arg_instance| {
+                    param_instance.name == arg_instance.name
+                        && param_instance.type_arguments.len() == arg_instance.type_arguments.len()
+                        && param_instance
+                            .type_arguments
+                            .iter()
+                            .zip(&arg_instance.type_arguments)
+                            .all(|arguments| match arguments {
+                                (
+                                    GenericArgument::Type(param_ty),
+                                    GenericArgument::Type(arg_ty),
+                                ) => param_ty.infer_type_arguments(arg_ty, type_arguments),
+                                (param_argument, arg_argument) => param_argument == arg_argument,
+                            })
+                })
+            }
+            _ => self == ty,
+        }
+    }
+
+    /// Get type class of the type
+    #[must_use]
+    pub const fn type_class(&self) -> TypeClass {
+        match self {
+            Self::Primitive(ty) => ty.type_class(),
+            Self::Struct(_)
+            | Self::Enum(_)
+            | Self::Array(..)
+            | Self::Tuple(_)
+            | Self::StructInstance(_)
+            | Self::Closure(_)
+            | Self::ParameterArray(..) => TypeClass::Aggregate,
+            Self::Pointer(_)
+            | Self::Reference(_)
+            | Self::MutableReference(_)
+            | Self::Function(..) => TypeClass::Pointer,
+            Self::Parameter(_) => TypeClass::None,
+            Self::Alias(_, ty) => ty.type_class(),
+        }
+    }
+}
+
+/// # Type class
+/// Generalized kind of types. Used to check is operation valid
+/// for the type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum TypeClass {
+    Integer,
+    Float,
+    Bool,
+    Char,
+    Pointer,
+    Aggregate,
+    None,
+}
+
+impl Display for TypeClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Char => "char",
+            Self::Pointer => "pointer",
+            Self::Aggregate => "aggregate",
+            Self::None => "()",
+        };
+        write!(f, "{s}")
+    }
 }
 
 impl Display for Type {
@@ -85,9 +428,36 @@ impl Display for Type {
         let str = match self {
             Self::Primitive(primitive) => primitive.to_string(),
             Self::Struct(struct_type) => struct_type.name.clone(),
+            Self::Enum(enum_type) => enum_type.name.clone(),
             Self::Array(array_type, size) => {
                 format!("[{:?};{:?}]", array_type.to_string(), size)
             }
+            Self::Tuple(types) => format!(
+                "({})",
+                types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Pointer(ty) => format!("*{ty}"),
+            Self::Reference(ty) => format!("&{ty}"),
+            Self::MutableReference(ty) => format!("&mut {ty}"),
+            Self::Parameter(name) => name.to_string(),
+            Self::StructInstance(instance) => instance.to_string(),
+            Self::Alias(name, _) => name.clone(),
+            Self::Function(parameters, result_type) => format!(
+                "fn({}) -> {result_type}",
+                parameters
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Closure(closure) => closure.name.clone(),
+            Self::ParameterArray(array_type, size) => {
+                format!("[{:?};{size}]", array_type.to_string())
+            }
         };
         write!(f, "{str}")
     }
@@ -95,31 +465,31 @@ impl Display for Type {
 
 impl TypeAttributes for Type {
     fn get_attribute_index(&self, attr_name: &ValueName) -> Option<u32> {
-        match self {
+        match self.unalias() {
             Self::Struct(st) => st.get_attribute_index(attr_name),
             _ => None,
         }
     }
     fn get_attribute_type(&self, attr_name: &ValueName) -> Option<Type> {
-        match self {
+        match self.unalias() {
             Self::Struct(st) => st.get_attribute_type(attr_name),
             _ => None,
         }
     }
     fn get_method(&self, method_name: String) -> Option<FunctionName> {
-        match self {
+        match self.unalias() {
             Self::Struct(st) => st.get_method(method_name),
             _ => None,
         }
     }
     fn is_attribute(&self, attr_name: &ValueName) -> bool {
-        match self {
+        match self.unalias() {
             Self::Struct(st) => st.is_attribute(attr_name),
             _ => false,
         }
     }
     fn is_method(&self, method_name: String) -> bool {
-        match self {
+        match self.unalias() {
             Self::Struct(st) => st.is_method(method_name),
             _ => false,
         }
@@ -131,7 +501,21 @@ impl From<ast::Type<'_>> for Type {
         match value {
             ast::Type::Primitive(v) => Self::Primitive(v.into()),
             ast::Type::Struct(v) => Self::Struct(v.into()),
+            ast::Type::Enum(v) => Self::Enum(v.into()),
             ast::Type::Array(v, s) => Self::Array(Box::new(v.as_ref().clone().into()), s),
+            ast::Type::Tuple(v) => Self::Tuple(v.into_iter().map(Into::into).collect()),
+            ast::Type::Pointer(v) => Self::Pointer(Box::new((*v).into())),
+            ast::Type::Reference(v) => Self::Reference(Box::new((*v).into())),
+            ast::Type::MutableReference(v) => Self::MutableReference(Box::new((*v).into())),
+            ast::Type::Parameter(v) => Self::Parameter(v.into()),
+            ast::Type::StructInstance(v) => Self::StructInstance(v.into()),
+            ast::Type::Function(parameters, result_type) => Self::Function(
+                parameters.into_iter().map(Into::into).collect(),
+                Box::new((*result_type).into()),
+            ),
+            ast::Type::ParameterArray(v, size) => {
+                Self::ParameterArray(Box::new((*v).into()), size.into())
+            }
         }
     }
 }
@@ -161,6 +545,130 @@ pub enum PrimitiveTypes {
     None,
 }
 
+impl PrimitiveTypes {
+    /// Check is type signed integer
+    #[must_use]
+    pub const fn is_signed_integer(&self) -> bool {
+        matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64)
+    }
+
+    /// Check is type unsigned integer
+    #[must_use]
+    pub const fn is_unsigned_integer(&self) -> bool {
+        matches!(self, Self::U8 | Self::U16 | Self::U32 | Self::U64)
+    }
+
+    /// Check is type integer: signed or unsigned
+    #[must_use]
+    pub const fn is_integer(&self) -> bool {
+        self.is_signed_integer() || self.is_unsigned_integer()
+    }
+
+    /// Check is type float
+    #[must_use]
+    pub const fn is_float(&self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+
+    /// Get type class of the primitive type
+    #[must_use]
+    pub const fn type_class(&self) -> TypeClass {
+        match self {
+            Self::U8
+            | Self::U16
+            | Self::U32
+            | Self::U64
+            | Self::I8
+            | Self::I16
+            | Self::I32
+            | Self::I64 => TypeClass::Integer,
+            Self::F32 | Self::F64 => TypeClass::Float,
+            Self::Bool => TypeClass::Bool,
+            Self::Char => TypeClass::Char,
+            Self::Ptr => TypeClass::Pointer,
+            Self::None => TypeClass::None,
+        }
+    }
+
+    /// Get size of the primitive type in bits
+    #[must_use]
+    pub const fn bit_width(&self) -> u8 {
+        match self {
+            Self::Bool => 1,
+            Self::U8 | Self::I8 => 8,
+            Self::U16 | Self::I16 => 16,
+            Self::U32 | Self::I32 | Self::F32 | Self::Char => 32,
+            Self::U64 | Self::I64 | Self::F64 | Self::Ptr => 64,
+            Self::None => 0,
+        }
+    }
+
+    /// Get cast kind for conversion of the primitive type to the
+    /// `to` type. Cast rules:
+    /// - integer to integer: extend, truncate or bit-preserving sign change
+    /// - float to float: extend or truncate
+    /// - integer to float and float to integer
+    /// - bool to integer
+    /// - char to integer, and only `u8` to char
+    /// - same types cast is no-op
+    ///
+    /// ## Return
+    /// `None` if cast is invalid.
+    #[must_use]
+    pub const fn cast_kind(&self, to: &Self) -> Option<CastKind> {
+        let (from_width, to_width) = (self.bit_width(), to.bit_width());
+        let kind = match (self.type_class(), to.type_class()) {
+            (TypeClass::Integer, TypeClass::Integer) | (TypeClass::Float, TypeClass::Float)
+                if from_width == to_width =>
+            {
+                CastKind::NoOp
+            }
+            (TypeClass::Integer, TypeClass::Integer) if from_width < to_width => {
+                CastKind::IntegerExtend
+            }
+            (TypeClass::Integer, TypeClass::Integer) => CastKind::IntegerTruncate,
+            (TypeClass::Float, TypeClass::Float) if from_width < to_width => CastKind::FloatExtend,
+            (TypeClass::Float, TypeClass::Float) => CastKind::FloatTruncate,
+            (TypeClass::Integer, TypeClass::Float) => CastKind::IntegerToFloat,
+            (TypeClass::Float, TypeClass::Integer) => CastKind::FloatToInteger,
+            (TypeClass::Bool, TypeClass::Integer) => CastKind::BoolToInteger,
+            (TypeClass::Char, TypeClass::Integer) => CastKind::CharToInteger,
+            (TypeClass::Integer, TypeClass::Char) if matches!(self, Self::U8) => {
+                CastKind::IntegerToChar
+            }
+            (TypeClass::Bool, TypeClass::Bool)
+            | (TypeClass::Char, TypeClass::Char)
+            | (TypeClass::Pointer, TypeClass::Pointer) => CastKind::NoOp,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// # Cast kind
+/// Conversion kind for the cast of primitive types.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum CastKind {
+    /// Value representation not changed
+    NoOp,
+    /// Integer widening. Sign or zero extension depends on
+    /// signedness of source type
+    IntegerExtend,
+    IntegerTruncate,
+    FloatExtend,
+    FloatTruncate,
+    IntegerToFloat,
+    FloatToInteger,
+    BoolToInteger,
+    CharToInteger,
+    IntegerToChar,
+}
+
 impl Display for PrimitiveTypes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -206,7 +714,7 @@ impl From<ast::PrimitiveTypes> for PrimitiveTypes {
 
 /// # Struct types
 /// Basic entity for struct type itself.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
 pub struct StructTypes {
     /// Type name
@@ -215,6 +723,9 @@ pub struct StructTypes {
     pub attributes: HashMap<ValueName, StructAttributeType>,
     /// Struct methods
     pub methods: HashMap<String, FunctionName>,
+    /// Generic struct type and type arguments, if struct type is
+    /// instance of generic struct type
+    pub instance: Option<StructInstanceType>,
 }
 
 impl TypeAttributes for StructTypes {
@@ -237,21 +748,280 @@ impl TypeAttributes for StructTypes {
     }
 }
 
+/// Attributes count is checked by semantic analyzer, attributes
+/// out of `u32` index range are skipped.
 impl From<ast::StructTypes<'_>> for StructTypes {
     fn from(value: ast::StructTypes<'_>) -> Self {
         Self {
             name: value.name(),
             attributes: {
                 let mut res = HashMap::new();
-                for (index, val) in value.attributes.iter().enumerate() {
+                for (val, index) in value.attributes.iter().zip(0..=u32::MAX) {
                     let name = (*val.attr_name.fragment()).to_string();
                     let mut v: StructAttributeType = val.clone().into();
-                    v.attr_index = u32::try_from(index).unwrap_or_default();
+                    v.attr_index = index;
                     res.insert(name.into(), v);
                 }
                 res
             },
             methods: HashMap::new(),
+            instance: None,
+        }
+    }
+}
+
+/// # Generic struct types
+/// Generic struct type declaration with type parameters. Attributes
+/// types can contain type parameters. Concrete struct types are
+/// instantiated for type arguments with mangled name
+/// `Name<type_arguments>`, where const arguments are shown by value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct GenericStructTypes {
+    /// Type name
+    pub name: String,
+    /// Type and const parameters
+    pub type_parameters: Vec<GenericParameter>,
+    /// Struct attributes
+    pub attributes: HashMap<ValueName, StructAttributeType>,
+}
+
+impl From<ast::GenericStructTypes<'_>> for GenericStructTypes {
+    fn from(value: ast::GenericStructTypes<'_>) -> Self {
+        let struct_type: StructTypes = ast::StructTypes {
+            name: value.name,
+            attributes: value.attributes,
+        }
+        .into();
+        Self {
+            name: struct_type.name,
+            type_parameters: value.type_parameters.into_iter().map(Into::into).collect(),
+            attributes: struct_type.attributes,
+        }
+    }
+}
+
+/// # Closure type
+/// Type of the closure value. Each closure has own type, that refers
+/// to the closure declaration by closure name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct ClosureType {
+    /// Closure name
+    pub name: String,
+    /// Closure parameters types
+    pub parameters: Vec<Type>,
+    /// Closure result type
+    pub result_type: Box<Type>,
+}
+
+/// # Generic parameter
+/// Type parameter or const parameter of generic struct type. Const
+/// parameter is array length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum GenericParameter {
+    Type(TypeParameterName),
+    Const(TypeParameterName),
+}
+
+impl GenericParameter {
+    /// Get parameter name
+    #[must_use]
+    pub const fn name(&self) -> &TypeParameterName {
+        match self {
+            Self::Type(name) | Self::Const(name) => name,
+        }
+    }
+}
+
+impl From<ast::GenericParameter<'_>> for GenericParameter {
+    fn from(value: ast::GenericParameter<'_>) -> Self {
+        match value {
+            ast::GenericParameter::Type(name) => Self::Type(name.into()),
+            ast::GenericParameter::Const(name) => Self::Const(name.into()),
+        }
+    }
+}
+
+/// # Generic argument
+/// Type argument or const argument of generic struct type instance.
+/// Const argument is primitive value or constant. In instantiated
+/// struct type const arguments are `u32` values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "codec",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "content")
+)]
+pub enum GenericArgument {
+    Type(Type),
+    Const(ConstantValue),
+}
+
+/// Float values aren't valid const arguments, so const arguments
+/// comparison is equivalence.
+impl Eq for GenericArgument {}
+
+impl GenericArgument {
+    /// Const argument of the value
+    #[must_use]
+    pub const fn value(value: u32) -> Self {
+        Self::Const(ConstantValue::Value(PrimitiveValue::U32(value)))
+    }
+
+    /// Check is argument contains type parameters
+    #[must_use]
+    pub fn has_type_parameters(&self) -> bool {
+        match self {
+            Self::Type(ty) => ty.has_type_parameters(),
+            Self::Const(_) => false,
+        }
+    }
+}
+
+impl From<ast::GenericArgument<'_>> for GenericArgument {
+    fn from(value: ast::GenericArgument<'_>) -> Self {
+        match value {
+            ast::GenericArgument::Type(ty) => Self::Type(ty.into()),
+            ast::GenericArgument::Const(value) => Self::Const(value.into()),
+        }
+    }
+}
+
+impl Display for GenericArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type(ty) => write!(f, "{ty}"),
+            Self::Const(ConstantValue::Constant(name)) => write!(f, "{name}"),
+            Self::Const(ConstantValue::Value(value)) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// # Struct instance type
+/// Instance of generic struct type for type and const arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct StructInstanceType {
+    /// Generic struct type name
+    pub name: String,
+    /// Type and const arguments
+    pub type_arguments: Vec<GenericArgument>,
+}
+
+impl From<ast::StructInstanceType<'_>> for StructInstanceType {
+    fn from(value: ast::StructInstanceType<'_>) -> Self {
+        Self {
+            name: (*value.name.fragment()).to_string(),
+            type_arguments: value.type_arguments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Display for StructInstanceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}<{}>",
+            self.name,
+            self.type_arguments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// # Enum types
+/// Basic entity for enum (tagged-union) type. Enum value layout is
+/// discriminant followed by payload of the variant.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct EnumTypes {
+    /// Type name
+    pub name: String,
+    /// Enum variants in declaration order
+    pub variants: Vec<EnumVariantType>,
+}
+
+impl EnumTypes {
+    /// Get enum variant by variant name
+    #[must_use]
+    pub fn get_variant(&self, name: &str) -> Option<&EnumVariantType> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// Get discriminant type: minimal unsigned integer type that can
+    /// contain all variants discriminants.
+    #[must_use]
+    pub const fn discriminant_type(&self) -> PrimitiveTypes {
+        match self.variants.len() {
+            0..=0x100 => PrimitiveTypes::U8,
+            0x101..=0x1_0000 => PrimitiveTypes::U16,
+            _ => PrimitiveTypes::U32,
+        }
+    }
+
+    /// Get discriminant value of the enum variant with discriminant
+    /// type.
+    ///
+    /// ## Return
+    /// `None` if variant discriminant is out of discriminant type range.
+    #[must_use]
+    pub fn discriminant_value(&self, variant: &EnumVariantType) -> Option<PrimitiveValue> {
+        let discriminant = variant.discriminant;
+        match self.discriminant_type() {
+            PrimitiveTypes::U8 => u8::try_from(discriminant).ok().map(PrimitiveValue::U8),
+            PrimitiveTypes::U16 => u16::try_from(discriminant).ok().map(PrimitiveValue::U16),
+            _ => Some(PrimitiveValue::U32(discriminant)),
+        }
+    }
+}
+
+/// Variants count is checked by semantic analyzer, variants out of
+/// `u32` discriminant range are skipped.
+impl From<ast::EnumTypes<'_>> for EnumTypes {
+    fn from(value: ast::EnumTypes<'_>) -> Self {
+        Self {
+            name: value.name(),
+            variants: value
+                .variants
+                .into_iter()
+                .zip(0..=u32::MAX)
+                .map(|(variant, discriminant)| {
+                    let mut v: EnumVariantType = variant.into();
+                    v.discriminant = discriminant;
+                    v
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `EnumVariantType` is type for enum variants.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "codec", derive(Serialize, Deserialize))]
+pub struct EnumVariantType {
+    /// Variant name
+    pub name: String,
+    /// Variant discriminant value
+    pub discriminant: u32,
+    /// Variant payload types. Empty for unit variant
+    pub payload: Vec<Type>,
+}
+
+impl From<ast::EnumVariant<'_>> for EnumVariantType {
+    fn from(value: ast::EnumVariant<'_>) -> Self {
+        Self {
+            name: value.name(),
+            discriminant: 0,
+            payload: value.payload.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::utils::fixtures::name_expr;
 use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
 use semantic_analyzer::ast;
 use semantic_analyzer::ast::{CodeLocation, GetLocation, GetName, Ident, ValueName};
@@ -6,7 +7,7 @@ use semantic_analyzer::types::error::StateErrorKind;
 use semantic_analyzer::types::expression::{ExpressionResult, ExpressionResultValue};
 use semantic_analyzer::types::semantic::SemanticStackContext;
 use semantic_analyzer::types::types::{PrimitiveTypes, Type};
-use semantic_analyzer::types::{Binding, InnerValueName, PrimitiveValue, Value};
+use semantic_analyzer::types::{Binding, InnerValueName, PlaceIndex, PrimitiveValue, Value};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -23,6 +24,7 @@ fn binding_transform() {
     };
     let binding_ast = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(expr_ast.clone()),
     };
     assert_eq!(binding_ast.location(), CodeLocation::new(1, 0));
@@ -48,6 +50,7 @@ fn binding_wrong_expression() {
     };
     let binding = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(expr),
     };
     t.state.binding(&binding, &block_state);
@@ -72,6 +75,7 @@ fn binding_value_not_exist() {
     };
     let binding = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(expr),
     };
     t.state.binding(&binding, &block_state);
@@ -130,6 +134,7 @@ fn binding_value_not_mutable() {
     );
     let binding = ast::Binding {
         name: ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(expr),
     };
     t.state.binding(&binding, &block_state);
@@ -195,6 +200,7 @@ fn binding_value_found() {
     };
     let binding = ast::Binding {
         name: ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(new_expr),
     };
     t.state.binding(&binding, &block_state);
@@ -222,3 +228,267 @@ fn binding_value_found() {
         }
     );
 }
+
+fn primitive_expr(
+    value: ast::PrimitiveValue,
+) -> ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(value),
+        operation: None,
+    }
+}
+
+/// Set mutable value `p` with type: `struct St { a: u8, b: [i32; 3] }`
+fn set_struct_value(block_state: &Rc<RefCell<BlockState<CustomExpressionInstruction>>>) -> Value {
+    let s_ty = ast::StructTypes {
+        name: "St".into(),
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("a"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::U8),
+            },
+            ast::StructType {
+                attr_name: Ident::new("b"),
+                attr_type: ast::Type::Array(
+                    Box::new(ast::Type::Primitive(ast::PrimitiveTypes::I32)),
+                    3,
+                ),
+            },
+        ],
+    };
+    let value = Value {
+        inner_name: "p".into(),
+        inner_type: Type::Struct(s_ty.into()),
+        mutable: true,
+        alloca: false,
+        malloc: false,
+    };
+    block_state
+        .borrow_mut()
+        .values
+        .insert("p".into(), value.clone());
+    value
+}
+
+#[test]
+fn binding_place_transform() {
+    let binding_ast = ast::Binding {
+        name: ast::ValueName::new(Ident::new("p")),
+        path: vec![
+            ast::PlaceAccess::Field(ast::ValueName::new(Ident::new("b"))),
+            ast::PlaceAccess::Index(Box::new(primitive_expr(ast::PrimitiveValue::U8(2)))),
+        ],
+        value: Box::new(primitive_expr(ast::PrimitiveValue::I32(1))),
+    };
+    assert_eq!(binding_ast.clone().name(), "p");
+    let binding: Binding = binding_ast.into();
+    assert_eq!(binding.to_string(), "p.b[2]");
+    // For grcov
+    let _ = format!("{binding:?}");
+}
+
+#[test]
+fn binding_place_store() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let value = set_struct_value(&block_state);
+    // p.a = 10
+    let binding = ast::Binding {
+        name: ast::ValueName::new(Ident::new("p")),
+        path: vec![ast::PlaceAccess::Field(ast::ValueName::new(Ident::new(
+            "a",
+        )))],
+        value: Box::new(primitive_expr(ast::PrimitiveValue::U8(10))),
+    };
+    t.state.binding(&binding, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    // p.b[2] = 20
+    let binding = ast::Binding {
+        name: ast::ValueName::new(Ident::new("p")),
+        path: vec![
+            ast::PlaceAccess::Field(ast::ValueName::new(Ident::new("b"))),
+            ast::PlaceAccess::Index(Box::new(primitive_expr(ast::PrimitiveValue::U8(2)))),
+        ],
+        value: Box::new(primitive_expr(ast::PrimitiveValue::I32(20))),
+    };
+    t.state.binding(&binding, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::Store {
+            val: value.clone(),
+            path: vec![PlaceIndex::Field(0)],
+            expr_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(10)),
+            },
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::Store {
+            val: value,
+            path: vec![
+                PlaceIndex::Field(1),
+                PlaceIndex::Element(ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::U8),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(2)),
+                }),
+            ],
+            expr_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(20)),
+            },
+        }
+    );
+}
+
+#[test]
+fn binding_place_evaluation_order() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let value = set_struct_value(&block_state);
+    let values = [("i", PrimitiveTypes::U8), ("v", PrimitiveTypes::I32)].map(|(name, ty)| {
+        let value = Value {
+            inner_name: name.into(),
+            inner_type: Type::Primitive(ty),
+            mutable: false,
+            alloca: false,
+            malloc: false,
+        };
+        block_state
+            .borrow_mut()
+            .values
+            .insert(name.into(), value.clone());
+        value
+    });
+    // p.b[i] = v
+    let binding = ast::Binding {
+        name: ast::ValueName::new(Ident::new("p")),
+        path: vec![
+            ast::PlaceAccess::Field(ast::ValueName::new(Ident::new("b"))),
+            ast::PlaceAccess::Index(Box::new(name_expr("i"))),
+        ],
+        value: Box::new(name_expr("v")),
+    };
+    t.state.binding(&binding, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Place index is evaluated before binding value
+    let [i, v] = values;
+    assert_eq!(
+        block_state.borrow().get_context().clone().get(),
+        vec![
+            SemanticStackContext::ExpressionValue {
+                expression: i,
+                register_number: 1,
+            },
+            SemanticStackContext::ExpressionValue {
+                expression: v,
+                register_number: 2,
+            },
+            SemanticStackContext::Store {
+                val: value,
+                path: vec![
+                    PlaceIndex::Field(1),
+                    PlaceIndex::Element(ExpressionResult {
+                        expr_type: Type::Primitive(PrimitiveTypes::U8),
+                        expr_value: ExpressionResultValue::Register(1),
+                    }),
+                ],
+                expr_result: ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::I32),
+                    expr_value: ExpressionResultValue::Register(2),
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn binding_place_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    set_struct_value(&block_state);
+    let binding = |path, value| ast::Binding {
+        name: ast::ValueName::new(Ident::new("p")),
+        path,
+        value: Box::new(primitive_expr(value)),
+    };
+    let field = |name| ast::PlaceAccess::Field(ast::ValueName::new(Ident::new(name)));
+    let index = |value| ast::PlaceAccess::Index(Box::new(primitive_expr(value)));
+
+    // p.a = 10i32
+    t.state.binding(
+        &binding(vec![field("a")], ast::PrimitiveValue::I32(10)),
+        &block_state,
+    );
+    // p.c = 10
+    t.state.binding(
+        &binding(vec![field("c")], ast::PrimitiveValue::U8(10)),
+        &block_state,
+    );
+    // p.a.b = 10
+    t.state.binding(
+        &binding(vec![field("a"), field("b")], ast::PrimitiveValue::U8(10)),
+        &block_state,
+    );
+    // p.a[0] = 10
+    t.state.binding(
+        &binding(
+            vec![field("a"), index(ast::PrimitiveValue::U8(0))],
+            ast::PrimitiveValue::U8(10),
+        ),
+        &block_state,
+    );
+    // p.b[3] = 10
+    t.state.binding(
+        &binding(
+            vec![field("b"), index(ast::PrimitiveValue::U8(3))],
+            ast::PrimitiveValue::I32(10),
+        ),
+        &block_state,
+    );
+    // p.b[true] = 10
+    t.state.binding(
+        &binding(
+            vec![field("b"), index(ast::PrimitiveValue::Bool(true))],
+            ast::PrimitiveValue::I32(10),
+        ),
+        &block_state,
+    );
+    // p = 10
+    t.state
+        .binding(&binding(vec![], ast::PrimitiveValue::I32(10)), &block_state);
+    assert!(t.check_errors_len(7), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::WrongBindingType));
+    assert!(t.check_error_index(1, StateErrorKind::ValueNotStructField));
+    assert!(t.check_error_index(2, StateErrorKind::ValueNotStruct));
+    assert!(t.check_error_index(3, StateErrorKind::ValueNotArray));
+    assert!(t.check_error_index(4, StateErrorKind::ArrayIndexOutOfBounds));
+    assert!(t.check_error_index(5, StateErrorKind::ArrayIndexWrongType));
+    assert!(t.check_error_index(6, StateErrorKind::WrongBindingType));
+    assert!(block_state.borrow().get_context().get().is_empty());
+
+    // Immutable value place can't be changed
+    t.clean_errors();
+    block_state
+        .borrow_mut()
+        .values
+        .get_mut(&"p".into())
+        .unwrap()
+        .mutable = false;
+    t.state.binding(
+        &binding(vec![field("a")], ast::PrimitiveValue::U8(10)),
+        &block_state,
+    );
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueIsNotMutable));
+}
@@ -0,0 +1,306 @@
+use crate::utils::fixtures::{
+    function, let_binding, let_stm, name_expr, u8_type, value_expr, TestBodyStatement,
+};
+use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, CodeLocation};
+use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::types::condition::{IfBodyStatement, LoopBodyStatement};
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type};
+use semantic_analyzer::types::BodyStatement;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod utils;
+
+type TestLoopBodyStatement = ast::LoopBodyStatement<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+
+#[test]
+fn block_transform() {
+    let data: TestBodyStatement = ast::BodyStatement::Block(vec![
+        let_stm("x", None, value_expr(ast::PrimitiveValue::U8(1))),
+        ast::BodyStatement::Block(vec![]),
+    ]);
+    let data_into: BodyStatement = data.into();
+    // For grcov
+    let _ = format!("{data_into:?}");
+    let BodyStatement::Block(body) = data_into else {
+        panic!("expected block statement");
+    };
+    assert_eq!(body.len(), 2);
+    assert!(matches!(body[0], BodyStatement::LetBinding(_)));
+    assert_eq!(body[1], BodyStatement::Block(vec![]));
+
+    let data = ast::IfBodyStatement::<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    >::Block(vec![]);
+    let data_into: IfBodyStatement = data.into();
+    assert_eq!(data_into, IfBodyStatement::Block(vec![]));
+
+    let data: TestLoopBodyStatement =
+        ast::LoopBodyStatement::Block(vec![ast::LoopBodyStatement::Continue]);
+    let data_into: LoopBodyStatement = data.into();
+    assert_eq!(
+        data_into,
+        LoopBodyStatement::Block(vec![LoopBodyStatement::Continue])
+    );
+}
+
+#[test]
+fn block_statement_scope() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", false, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // { let x = 2u16; let y = x; }
+    let data = vec![
+        let_stm("x", None, value_expr(ast::PrimitiveValue::U16(2))),
+        let_stm("y", None, name_expr("x")),
+    ];
+    let return_type = Type::Primitive(PrimitiveTypes::U8);
+    assert_eq!(
+        t.state.block_statement(&data, &block_state, &return_type),
+        (false, None)
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(block_state.borrow().children.len(), 1);
+
+    // Inner values are visible only in the block, and shadowed value
+    // has own inner name
+    let inner_state = block_state.borrow().children[0].clone();
+    let inner_x = inner_state.borrow().get_value_name(&"x".into()).unwrap();
+    assert_eq!(inner_x.inner_name, "x.1".into());
+    assert_eq!(inner_x.inner_type, Type::Primitive(PrimitiveTypes::U16));
+    let inner_y = inner_state.borrow().get_value_name(&"y".into()).unwrap();
+    assert_eq!(inner_y.inner_type, Type::Primitive(PrimitiveTypes::U16));
+
+    let x = block_state.borrow().get_value_name(&"x".into()).unwrap();
+    assert_eq!(x.inner_name, "x.0".into());
+    assert_eq!(x.inner_type, Type::Primitive(PrimitiveTypes::U8));
+    assert!(block_state.borrow().get_value_name(&"y".into()).is_none());
+    assert!(block_state
+        .borrow()
+        .inner_values_name
+        .contains(&"x.1".into()));
+}
+
+#[test]
+fn block_statement_return() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let data = vec![ast::BodyStatement::Block(vec![ast::BodyStatement::Return(
+        value_expr(ast::PrimitiveValue::U8(3)),
+    )])];
+    let return_type = Type::Primitive(PrimitiveTypes::U8);
+    assert!(t.state.block_statement(&data, &block_state, &return_type).0);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert!(block_state.borrow().manual_return);
+    let inner_state = block_state.borrow().children[0].clone();
+    let nested_state = inner_state.borrow().children[0].clone();
+    let ctx = nested_state.borrow().get_context().get();
+    assert!(matches!(
+        ctx[0],
+        SemanticStackContext::JumpFunctionReturn { .. }
+    ));
+
+    // Wrong return type
+    let data = vec![ast::BodyStatement::Return(value_expr(
+        ast::PrimitiveValue::I32(3),
+    ))];
+    assert!(t.state.block_statement(&data, &block_state, &return_type).0);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::WrongReturnType));
+}
+
+#[test]
+fn block_statement_value() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // { let x = 1; { x } }
+    let data = vec![
+        let_stm("x", None, value_expr(ast::PrimitiveValue::U8(1))),
+        ast::BodyStatement::Block(vec![ast::BodyStatement::Expression(name_expr("x"))]),
+    ];
+    let return_type = Type::Primitive(PrimitiveTypes::U8);
+    let (is_return, value) = t.state.block_statement(&data, &block_state, &return_type);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert!(!is_return);
+    assert_eq!(value.unwrap().expr_type, return_type);
+    // Block value isn't return from the function
+    assert!(!block_state.borrow().manual_return);
+    let ctx = block_state.borrow().get_context().get();
+    assert!(!ctx
+        .iter()
+        .any(|ctx| matches!(ctx, SemanticStackContext::JumpFunctionReturn { .. })));
+
+    // Code after block value, and wrong block value type
+    let data = vec![
+        ast::BodyStatement::Expression(value_expr(ast::PrimitiveValue::I32(1))),
+        let_stm("y", None, value_expr(ast::PrimitiveValue::U8(1))),
+    ];
+    t.state.block_statement(&data, &block_state, &return_type);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::WrongReturnType));
+    assert!(t.check_error_index(1, StateErrorKind::ForbiddenCodeAfterReturnDeprecated));
+}
+
+#[test]
+fn block_statement_code_after_return() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let data = vec![
+        ast::BodyStatement::Return(value_expr(ast::PrimitiveValue::U8(3))),
+        let_stm("x", None, value_expr(ast::PrimitiveValue::U8(1))),
+    ];
+    let return_type = Type::Primitive(PrimitiveTypes::U8);
+    t.state.block_statement(&data, &block_state, &return_type);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ForbiddenCodeAfterReturnDeprecated));
+}
+
+#[test]
+fn block_statement_in_function_body() {
+    let mut t = SemanticTest::new();
+    // fn fn1() -> u8 { let x = 1; { let x = 2u16; } x }
+    let fn1 = function(
+        "fn1",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm("x", None, value_expr(ast::PrimitiveValue::U8(1))),
+            ast::BodyStatement::Block(vec![let_stm(
+                "x",
+                None,
+                value_expr(ast::PrimitiveValue::U16(2)),
+            )]),
+            ast::BodyStatement::Expression(name_expr("x")),
+        ],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // fn fn1() -> u8 { { return 1; } }
+    let mut t = SemanticTest::new();
+    let fn1 = function(
+        "fn1",
+        vec![],
+        u8_type(),
+        vec![ast::BodyStatement::Block(vec![ast::BodyStatement::Return(
+            value_expr(ast::PrimitiveValue::U8(1)),
+        )])],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = t.state.context[0].borrow().get_context().get();
+    assert!(matches!(
+        ctx.last().unwrap(),
+        SemanticStackContext::FunctionReturnLabel { .. }
+    ));
+
+    // fn fn1() -> u8 { { let x = 1; x } }
+    let mut t = SemanticTest::new();
+    let fn1 = function(
+        "fn1",
+        vec![],
+        u8_type(),
+        vec![ast::BodyStatement::Block(vec![
+            let_stm("x", None, value_expr(ast::PrimitiveValue::U8(1))),
+            ast::BodyStatement::Expression(name_expr("x")),
+        ])],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = t.state.context[0].borrow().get_context().get();
+    assert!(matches!(
+        ctx.last().unwrap(),
+        SemanticStackContext::ExpressionFunctionReturn { .. }
+    ));
+
+    // fn fn1() -> u8 { { let y = 1; } y }
+    let mut t = SemanticTest::new();
+    let fn1 = function(
+        "fn1",
+        vec![],
+        u8_type(),
+        vec![
+            ast::BodyStatement::Block(vec![let_stm(
+                "y",
+                None,
+                value_expr(ast::PrimitiveValue::U8(1)),
+            )]),
+            ast::BodyStatement::Expression(name_expr("y")),
+        ],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::ValueNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::ReturnNotFound));
+}
+
+#[test]
+fn block_statement_in_if_body() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let body = vec![ast::IfBodyStatement::Block(vec![
+        ast::IfBodyStatement::LetBinding(let_binding(
+            "x",
+            false,
+            None,
+            value_expr(ast::PrimitiveValue::U8(1)),
+        )),
+        ast::IfBodyStatement::Return(name_expr("x")),
+    ])];
+    let label_end = String::from("if_end").into();
+    assert!(t
+        .state
+        .if_condition_body(&body, &block_state, &label_end, None));
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(block_state.borrow().children.len(), 1);
+    assert!(block_state.borrow().get_value_name(&"x".into()).is_none());
+}
+
+#[test]
+fn block_statement_in_loop_body() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let body: Vec<TestLoopBodyStatement> = vec![ast::LoopBodyStatement::Block(vec![
+        ast::LoopBodyStatement::Block(vec![ast::LoopBodyStatement::Break]),
+    ])];
+    t.state.loop_statement(&body, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let loop_state = block_state.borrow().children[0].clone();
+    let outer_block_state = loop_state.borrow().children[0].clone();
+    let inner_block_state = outer_block_state.borrow().children[0].clone();
+    let ctx = inner_block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx[0],
+        SemanticStackContext::JumpTo {
+            label: String::from("loop_end").into()
+        }
+    );
+
+    // Break without value in the block of loop expression
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::Loop(ast::ExpressionLoop {
+            body,
+            location: CodeLocation::new(1, 1),
+        }),
+        operation: None,
+    };
+    t.state.expression(&expr, &block_state);
+    assert!(t.check_error(StateErrorKind::LoopExpressionBreakWithoutValue));
+}
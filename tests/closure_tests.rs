@@ -0,0 +1,332 @@
+use crate::utils::fixtures::{
+    function, indirect_call_expr, let_binding, let_stm, name_expr, param, u8_type, value_expr,
+    TestBodyStatement, TestExpression, TestMain,
+};
+use crate::utils::{CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, Ident};
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::expression::{
+    ExpressionResult, ExpressionResultValue, ExpressionValue,
+};
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{ClosureType, PrimitiveTypes, Type, TypeClass};
+use semantic_analyzer::types::{Closure, FunctionName, Value};
+
+mod utils;
+
+/// Closure expression: `|parameters| -> u8 { body }`
+fn closure_expr(
+    parameters: Vec<ast::FunctionParameter<'static>>,
+    body: Vec<TestBodyStatement>,
+) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::Closure(Box::new(ast::ExpressionClosure {
+            parameters,
+            result_type: u8_type(),
+            body,
+            location: CodeLocation::new(1, 0),
+        })),
+        operation: None,
+    }
+}
+
+fn u8_value(value: u8) -> TestExpression {
+    value_expr(ast::PrimitiveValue::U8(value))
+}
+
+fn closure(t: &SemanticTest<CustomExpressionInstruction>, name: &str) -> Closure {
+    t.state.global.closures[&String::from(name).into()].clone()
+}
+
+#[test]
+fn closure_transform() {
+    let expr = closure_expr(vec![param("x", u8_type())], vec![]);
+    let ast::ExpressionValue::Closure(data) = &expr.expression_value else {
+        panic!("expected closure");
+    };
+    assert_eq!(data.location(), CodeLocation::new(1, 0));
+    let value: ExpressionValue = expr.expression_value.into();
+    // For grcov
+    let _ = format!("{value:?}");
+    assert_eq!(value.to_string(), "|x| -> u8");
+
+    let ty = Type::Closure(ClosureType {
+        name: "closure.0".to_string(),
+        parameters: vec![Type::Primitive(PrimitiveTypes::U8)],
+        result_type: Box::new(Type::Primitive(PrimitiveTypes::U8)),
+    });
+    assert_eq!(ty.to_string(), "closure.0");
+    assert_eq!(ty.type_class(), TypeClass::Aggregate);
+    assert!(ty.get_function().is_none());
+}
+
+#[test]
+fn closure_captures() {
+    let mut t = SemanticTest::new();
+    // fn main() -> u8 {
+    //     let a = 1;
+    //     let mut b = 2;
+    //     let f = |x: u8| -> u8 { b = x; a };
+    //     f(3)
+    // }
+    let main_fn = function(
+        "main",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm("a", None, u8_value(1)),
+            ast::BodyStatement::LetBinding(let_binding("b", true, None, u8_value(2))),
+            let_stm(
+                "f",
+                None,
+                closure_expr(
+                    vec![param("x", u8_type())],
+                    vec![
+                        ast::BodyStatement::Binding(ast::Binding {
+                            name: ast::ValueName::new(Ident::new("b")),
+                            path: vec![],
+                            value: Box::new(name_expr("x")),
+                        }),
+                        ast::BodyStatement::Expression(name_expr("a")),
+                    ],
+                ),
+            ),
+            ast::BodyStatement::Expression(indirect_call_expr(name_expr("f"), vec![u8_value(3)])),
+        ],
+    );
+    let main_stm: TestMain = vec![ast::MainStatement::Function(main_fn)];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let closure = closure(&t, "closure.0");
+    assert_eq!(
+        closure.parameters,
+        vec![Type::Primitive(PrimitiveTypes::U8)]
+    );
+    assert_eq!(closure.inner_type, Type::Primitive(PrimitiveTypes::U8));
+    // Captures ordered by first use
+    assert_eq!(closure.captures.len(), 2);
+    // Mutable value captured by reference
+    assert_eq!(closure.captures[0].name, String::from("b").into());
+    assert!(closure.captures[0].by_reference);
+    assert!(closure.captures[0].value.alloca);
+    // Immutable value captured by value
+    assert_eq!(closure.captures[1].name, String::from("a").into());
+    assert!(!closure.captures[1].by_reference);
+
+    let environment = &closure.environment;
+    assert_eq!(environment.name, "closure.0.environment");
+    let a_attr = &environment.attributes[&String::from("a").into()];
+    assert_eq!(a_attr.attr_index, 1);
+    assert_eq!(a_attr.attr_type, Type::Primitive(PrimitiveTypes::U8));
+    let b_attr = &environment.attributes[&String::from("b").into()];
+    assert_eq!(b_attr.attr_index, 0);
+    assert_eq!(
+        b_attr.attr_type,
+        Type::MutableReference(Box::new(Type::Primitive(PrimitiveTypes::U8)))
+    );
+    assert_eq!(
+        closure.closure_type(),
+        Type::Closure(ClosureType {
+            name: "closure.0".to_string(),
+            parameters: vec![Type::Primitive(PrimitiveTypes::U8)],
+            result_type: Box::new(Type::Primitive(PrimitiveTypes::U8)),
+        })
+    );
+
+    // Closure body analyzed in separate context
+    assert_eq!(t.state.closure_context.len(), 1);
+    assert!(t.state.closure_context[0].borrow().enclosing.is_some());
+    assert_eq!(t.state.closure_context[0].borrow().captures.len(), 2);
+
+    let global_ctx = t.state.global.context.clone().get();
+    assert!(global_ctx.contains(&SemanticStackContext::Types {
+        type_decl: environment.clone(),
+    }));
+    assert!(
+        global_ctx.contains(&SemanticStackContext::ClosureDeclaration {
+            closure: Box::new(closure.clone()),
+        })
+    );
+
+    let ctx = t.state.context[0].borrow().get_context().get();
+    // Value captured by reference is allocated in memory since its
+    // declaration
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::LetBinding { let_decl, .. }
+            if *let_decl == closure.captures[0].value
+    )));
+    assert!(closure.captures[0].inner_value.alloca);
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::CreateClosure { closure: c, .. } if **c == closure
+    )));
+    let Some(SemanticStackContext::ClosureCall {
+        closure: call_closure,
+        params,
+        ..
+    }) = ctx
+        .iter()
+        .find(|ctx| matches!(ctx, SemanticStackContext::ClosureCall { .. }))
+    else {
+        panic!("expected closure call");
+    };
+    assert_eq!(**call_closure, closure);
+    assert_eq!(params.len(), 1);
+
+    // Value captured by reference is stored by address from the
+    // closure environment attribute
+    let closure_ctx = t.state.closure_context[0].borrow().get_context().get();
+    let u8_result = |register| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::U8),
+        expr_value: ExpressionResultValue::Register(register),
+    };
+    assert_eq!(
+        closure_ctx[1..4],
+        [
+            SemanticStackContext::ExpressionValue {
+                expression: Value {
+                    inner_name: String::from("x").into(),
+                    inner_type: Type::Primitive(PrimitiveTypes::U8),
+                    mutable: false,
+                    alloca: false,
+                    malloc: false,
+                },
+                register_number: 1,
+            },
+            SemanticStackContext::EnvironmentField {
+                attr_index: 0,
+                attr_type: Type::MutableReference(Box::new(Type::Primitive(PrimitiveTypes::U8))),
+                register_number: 2,
+            },
+            SemanticStackContext::StoreAddress {
+                address_register: 2,
+                expr_result: u8_result(1),
+            },
+        ]
+    );
+    assert!(!closure_ctx
+        .iter()
+        .any(|ctx| matches!(ctx, SemanticStackContext::Binding { .. })));
+}
+
+#[test]
+fn closure_nested_captures() {
+    let mut t = SemanticTest::new();
+    // fn main() -> u8 {
+    //     let a = 1;
+    //     let f = || -> u8 { let g = || -> u8 { a }; g() };
+    //     f()
+    // }
+    let main_fn = function(
+        "main",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm("a", None, u8_value(1)),
+            let_stm(
+                "f",
+                None,
+                closure_expr(
+                    vec![],
+                    vec![
+                        let_stm(
+                            "g",
+                            None,
+                            closure_expr(
+                                vec![],
+                                vec![ast::BodyStatement::Expression(name_expr("a"))],
+                            ),
+                        ),
+                        ast::BodyStatement::Expression(indirect_call_expr(name_expr("g"), vec![])),
+                    ],
+                ),
+            ),
+            ast::BodyStatement::Expression(indirect_call_expr(name_expr("f"), vec![])),
+        ],
+    );
+    let main_stm: TestMain = vec![ast::MainStatement::Function(main_fn)];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(t.state.global.closures.len(), 2);
+    assert_eq!(t.state.closure_context.len(), 2);
+
+    // Inner closure analyzed first
+    let inner = closure(&t, "closure.0");
+    let outer = closure(&t, "closure.1");
+    assert_eq!(inner.captures.len(), 1);
+    assert_eq!(inner.captures[0].name, String::from("a").into());
+    // Outer closure captures value on behalf of inner closure
+    assert_eq!(outer.captures.len(), 1);
+    assert_eq!(outer.captures[0].name, String::from("a").into());
+    assert_eq!(inner.captures[0].value, outer.captures[0].inner_value);
+    let outer_name: FunctionName = String::from("closure.1").into();
+    assert_eq!(outer.inner_name, outer_name);
+}
+
+#[test]
+fn closure_errors() {
+    let mut t = SemanticTest::new();
+    // fn main() -> u8 {
+    //     let a = 1;
+    //     let f = || -> u8 { a = 2; a };
+    //     f()
+    // }
+    let main_fn = function(
+        "main",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm("a", None, u8_value(1)),
+            let_stm(
+                "f",
+                None,
+                closure_expr(
+                    vec![],
+                    vec![
+                        ast::BodyStatement::Binding(ast::Binding {
+                            name: ast::ValueName::new(Ident::new("a")),
+                            path: vec![],
+                            value: Box::new(u8_value(2)),
+                        }),
+                        ast::BodyStatement::Expression(name_expr("a")),
+                    ],
+                ),
+            ),
+            ast::BodyStatement::Expression(indirect_call_expr(name_expr("f"), vec![])),
+        ],
+    );
+    let main_stm: TestMain = vec![ast::MainStatement::Function(main_fn)];
+    t.state.run(&main_stm);
+    // Closure with body errors is declared, so only body errors
+    // reported
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error(StateErrorKind::ValueIsNotMutable));
+    assert_eq!(t.state.global.closures.len(), 1);
+    t.clean_errors();
+
+    // fn fn1() -> u8 { let f = |x: u8| -> u8 { x }; f() }
+    let mut t = SemanticTest::new();
+    let fn1 = function(
+        "fn1",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm(
+                "f",
+                None,
+                closure_expr(
+                    vec![param("x", u8_type())],
+                    vec![ast::BodyStatement::Expression(name_expr("x"))],
+                ),
+            ),
+            ast::BodyStatement::Expression(indirect_call_expr(name_expr("f"), vec![])),
+        ],
+    );
+    let main_stm: TestMain = vec![ast::MainStatement::Function(fn1)];
+    t.state.run(&main_stm);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionParametersCountWrong));
+    assert!(t.check_error_index(1, StateErrorKind::ReturnNotFound));
+}
@@ -59,6 +59,7 @@ mod test {
         let body_let_binding = ast::BodyStatement::LetBinding(let_binding.clone());
         let body_binding = ast::BodyStatement::Binding(ast::Binding {
             name: ast::ValueName::new(Ident::new("x")),
+            path: vec![],
             value: Box::new(ast::Expression {
                 expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(
                     true,
@@ -70,7 +71,7 @@ mod test {
             name: ast::FunctionName::new(Ident::new("fn2")),
             parameters: vec![],
         });
-        let body_if = ast::BodyStatement::If(ast::IfStatement {
+        let body_if = ast::BodyStatement::If(Box::new(ast::IfStatement {
             condition: ast::IfCondition::Single(ast::Expression {
                 expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(
                     true,
@@ -93,9 +94,9 @@ mod test {
             )]),
             else_statement: None,
             else_if_statement: None,
-        });
+        }));
         let body_loop = ast::BodyStatement::Loop(vec![
-            ast::LoopBodyStatement::If(ast::IfStatement {
+            ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
                 condition: ast::IfCondition::Logic(ast::ExpressionLogicCondition {
                     left: ast::ExpressionCondition {
                         left: ast::Expression {
@@ -140,7 +141,7 @@ mod test {
                     ast::IfLoopBodyStatement::LetBinding(let_binding.clone()),
                     ast::IfLoopBodyStatement::Break,
                 ]),
-            }),
+            })),
             ast::LoopBodyStatement::FunctionCall(ast::FunctionCall {
                 name: ast::FunctionName::new(Ident::new("fn2")),
                 parameters: vec![],
@@ -234,6 +235,7 @@ mod test {
         let est = ast::ExpressionStructValue {
             name: ast::ValueName::new(Ident::new("x")),
             attribute: ast::ValueName::new(Ident::new("y")),
+            path: vec![],
         };
         let to_json = serde_json::to_string(&est).unwrap();
         let to_val = serde_json::from_str(&to_json).unwrap();
@@ -314,6 +316,7 @@ mod test {
         let ex_s = ExpressionStructValue {
             name: "x".to_string().into(),
             attribute: "y".to_string().into(),
+            path: vec![],
         };
         let to_json = serde_json::to_string(&ex_s).unwrap();
         let to_val = serde_json::from_str(&to_json).unwrap();
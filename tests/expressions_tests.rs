@@ -1,17 +1,19 @@
+use crate::utils::fixtures::set_enum_type;
 use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
 use semantic_analyzer::ast;
 use semantic_analyzer::ast::{
     CodeLocation, GetLocation, GetName, Ident, MAX_PRIORITY_LEVEL_FOR_EXPRESSIONS,
 };
 use semantic_analyzer::types::expression::{
-    Expression, ExpressionOperations, ExpressionResult, ExpressionStructValue, ExpressionValue,
+    Expression, ExpressionArrayElement, ExpressionOperations, ExpressionResult,
+    ExpressionStructValue, ExpressionValue, UnaryOperations,
 };
 use semantic_analyzer::types::semantic::{ExtendedSemanticContext, SemanticStackContext};
 use semantic_analyzer::types::{
     block_state::BlockState,
     error::StateErrorKind,
     expression::ExpressionResultValue,
-    types::{PrimitiveTypes, Type},
+    types::{CastKind, PrimitiveTypes, Type, TypeClass},
     Constant, ConstantExpression, ConstantName, ConstantValue, Function, PrimitiveValue, Value,
     ValueName,
 };
@@ -378,6 +380,7 @@ fn expression_ast_transform_primitive_struct_value() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("val")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expr_struct_val: ExpressionStructValue = expr_struct_val.into();
     assert_eq!(expr_struct_val.to_string(), "val");
@@ -580,6 +583,7 @@ fn expression_struct_value_not_found() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("val")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expr = ast::Expression {
         expression_value: ast::ExpressionValue::StructValue(expr_struct_val),
@@ -602,6 +606,7 @@ fn expression_struct_value_wrong_struct_type() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("x")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expr = ast::Expression {
         expression_value: ast::ExpressionValue::StructValue(expr_struct_val),
@@ -635,6 +640,7 @@ fn expression_struct_value_type_not_found() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("x")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expr = ast::Expression {
         expression_value: ast::ExpressionValue::StructValue(expr_struct_val),
@@ -676,6 +682,7 @@ fn expression_struct_value_wrong_expression_type() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("x")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expr = ast::Expression {
         expression_value: ast::ExpressionValue::StructValue(expr_struct_val),
@@ -728,6 +735,7 @@ fn expression_struct_value_wrong_struct_attribute() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("x")),
         attribute: ast::ValueName::new(Ident::new("attr2")),
+        path: vec![],
     };
     let expr = ast::Expression {
         expression_value: ast::ExpressionValue::StructValue(expr_struct_val),
@@ -780,6 +788,7 @@ fn expression_struct_value() {
     let expr_struct_val = ast::ExpressionStructValue {
         name: ast::ValueName::new(Ident::new("x")),
         attribute: ast::ValueName::new(Ident::new("attr1")),
+        path: vec![],
     };
     let expression_st_value = ast::ExpressionValue::StructValue(expr_struct_val);
     let expression_st_value_into: ExpressionValue = expression_st_value.clone().into();
@@ -830,7 +839,7 @@ fn expression_struct_value() {
         state[0],
         SemanticStackContext::ExpressionStructValue {
             expression: value,
-            index: 0,
+            path: vec![0],
             register_number: 1,
         }
     );
@@ -929,11 +938,11 @@ fn expression_operation() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
     let next_expr = ast::Expression {
-        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Char('b')),
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(2)),
         operation: None,
     };
     let expr = ast::Expression {
-        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Char('a')),
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(1)),
         operation: Some((ast::ExpressionOperations::Plus, Box::new(next_expr))),
     };
     let res = t.state.expression(&expr, &block_state).unwrap();
@@ -941,7 +950,7 @@ fn expression_operation() {
     assert_eq!(
         res,
         ExpressionResult {
-            expr_type: Type::Primitive(PrimitiveTypes::Char),
+            expr_type: Type::Primitive(PrimitiveTypes::U8),
             expr_value: ExpressionResultValue::Register(1),
         }
     );
@@ -952,12 +961,12 @@ fn expression_operation() {
         SemanticStackContext::ExpressionOperation {
             operation: ExpressionOperations::Plus,
             left_value: ExpressionResult {
-                expr_type: Type::Primitive(PrimitiveTypes::Char),
-                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Char('a')),
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(1)),
             },
             right_value: ExpressionResult {
-                expr_type: Type::Primitive(PrimitiveTypes::Char),
-                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Char('b')),
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(2)),
             },
             register_number: 1,
         }
@@ -1422,3 +1431,1101 @@ fn custom_expression() {
         assert_eq!(bs, bs_decoded);
     }
 }
+
+#[test]
+fn expression_unary_operation_transform() {
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation: ast::UnaryOperations::Minus,
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(3)),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    };
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "-3");
+
+    let op: UnaryOperations = ast::UnaryOperations::Minus.into();
+    assert_eq!(op, UnaryOperations::Minus);
+    let op: UnaryOperations = ast::UnaryOperations::Not.into();
+    assert_eq!(op, UnaryOperations::Not);
+    assert_eq!(op.to_string(), "!");
+    let op: UnaryOperations = ast::UnaryOperations::BitwiseNot.into();
+    assert_eq!(op, UnaryOperations::BitwiseNot);
+    assert_eq!(op.to_string(), "~");
+}
+
+#[test]
+fn expression_unary_operation() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let value_name = ast::ValueName::new(Ident::new("x"));
+    let value = Value {
+        inner_name: "x".into(),
+        inner_type: Type::Primitive(PrimitiveTypes::I32),
+        mutable: false,
+        alloca: false,
+        malloc: false,
+    };
+    block_state
+        .borrow_mut()
+        .values
+        .insert(value_name.clone().into(), value.clone());
+    // Expression: -x + 1
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation: ast::UnaryOperations::Minus,
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::ValueName(value_name),
+                operation: None,
+            }),
+        }),
+        operation: Some((
+            ast::ExpressionOperations::Plus,
+            Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(1)),
+                operation: None,
+            }),
+        )),
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error());
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::I32),
+            expr_value: ExpressionResultValue::Register(3),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 3);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionValue {
+            expression: value,
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::UnaryOperation {
+            operation: UnaryOperations::Minus,
+            value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            register_number: 2,
+        }
+    );
+    assert_eq!(
+        state[2],
+        SemanticStackContext::ExpressionOperation {
+            operation: ExpressionOperations::Plus,
+            left_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::Register(2),
+            },
+            right_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(1)),
+            },
+            register_number: 3,
+        }
+    );
+}
+
+#[test]
+fn expression_unary_operation_not_and_bitwise_not() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation: ast::UnaryOperations::Not,
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(
+                    true,
+                )),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::Bool));
+    assert_eq!(res.expr_value, ExpressionResultValue::Register(1));
+
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation: ast::UnaryOperations::BitwiseNot,
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(3)),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::U8));
+    assert_eq!(res.expr_value, ExpressionResultValue::Register(2));
+    assert!(t.is_empty_error());
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+}
+
+#[test]
+fn expression_unary_operation_wrong_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let unary_expr = |operation, value| ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation,
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(value),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    };
+    let expr = unary_expr(ast::UnaryOperations::Minus, ast::PrimitiveValue::U32(1));
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    let expr = unary_expr(ast::UnaryOperations::Not, ast::PrimitiveValue::I32(1));
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    let expr = unary_expr(
+        ast::UnaryOperations::BitwiseNot,
+        ast::PrimitiveValue::F32(1.),
+    );
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(3), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::UnaryOperationWrongType));
+    assert!(t.check_error_index(1, StateErrorKind::UnaryOperationWrongType));
+    assert!(t.check_error_index(2, StateErrorKind::UnaryOperationWrongType));
+    let state = block_state.borrow().get_context().clone().get();
+    assert!(state.is_empty());
+}
+
+#[test]
+fn expression_operation_comparison_result_type() {
+    assert!(ExpressionOperations::Less.is_comparison());
+    assert!(!ExpressionOperations::Plus.is_comparison());
+    let ty = Type::Primitive(PrimitiveTypes::I32);
+    assert_eq!(ExpressionOperations::Multiply.result_type(&ty), ty);
+    assert_eq!(
+        ExpressionOperations::GreatEq.result_type(&ty),
+        Type::Primitive(PrimitiveTypes::Bool)
+    );
+
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let next_expr = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(20)),
+        operation: None,
+    };
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(10)),
+        operation: Some((ast::ExpressionOperations::Less, Box::new(next_expr))),
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error());
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::Bool),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 1);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionOperation {
+            operation: ExpressionOperations::Less,
+            left_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(10)),
+            },
+            right_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(20)),
+            },
+            register_number: 1,
+        }
+    );
+}
+
+#[test]
+fn expression_operation_comparison_wrong_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let next_expr = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I64(10)),
+        operation: None,
+    };
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(20)),
+        operation: Some((ast::ExpressionOperations::Eq, Box::new(next_expr))),
+    };
+    let res = t.state.expression(&expr, &block_state);
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(
+        t.check_error(StateErrorKind::ComparisonOperationWrongType),
+        "Errors: {:?}",
+        t.state.errors[0]
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert!(state.is_empty());
+}
+
+#[test]
+fn expression_operation_invalid_for_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let op_expr = |op, left, right| ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::PrimitiveValue(left),
+        operation: Some((
+            op,
+            Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(right),
+                operation: None,
+            }),
+        )),
+    };
+    let invalid = [
+        op_expr(
+            ast::ExpressionOperations::ShiftLeft,
+            ast::PrimitiveValue::F64(1.),
+            ast::PrimitiveValue::F64(2.),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Xor,
+            ast::PrimitiveValue::F32(1.),
+            ast::PrimitiveValue::F32(2.),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Plus,
+            ast::PrimitiveValue::Char('a'),
+            ast::PrimitiveValue::Char('b'),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Plus,
+            ast::PrimitiveValue::Ptr,
+            ast::PrimitiveValue::Ptr,
+        ),
+        op_expr(
+            ast::ExpressionOperations::Divide,
+            ast::PrimitiveValue::Bool(true),
+            ast::PrimitiveValue::Bool(false),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Less,
+            ast::PrimitiveValue::Bool(true),
+            ast::PrimitiveValue::Bool(false),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Eq,
+            ast::PrimitiveValue::None,
+            ast::PrimitiveValue::None,
+        ),
+    ];
+    for expr in &invalid {
+        assert!(t.state.expression(expr, &block_state).is_none());
+    }
+    assert!(t.check_errors_len(invalid.len()));
+    for i in 0..invalid.len() {
+        assert!(t.check_error_index(i, StateErrorKind::InvalidOperationForType));
+    }
+    assert_eq!(
+        t.state.errors[0].value,
+        "ShiftLeft: expected integer, found f64"
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert!(state.is_empty());
+
+    t.clean_errors();
+    let valid = [
+        op_expr(
+            ast::ExpressionOperations::Xor,
+            ast::PrimitiveValue::Bool(true),
+            ast::PrimitiveValue::Bool(false),
+        ),
+        op_expr(
+            ast::ExpressionOperations::Less,
+            ast::PrimitiveValue::Char('a'),
+            ast::PrimitiveValue::Char('b'),
+        ),
+        op_expr(
+            ast::ExpressionOperations::NotEq,
+            ast::PrimitiveValue::Ptr,
+            ast::PrimitiveValue::Ptr,
+        ),
+        op_expr(
+            ast::ExpressionOperations::Divide,
+            ast::PrimitiveValue::F64(1.),
+            ast::PrimitiveValue::F64(2.),
+        ),
+    ];
+    for expr in &valid {
+        assert!(t.state.expression(expr, &block_state).is_some());
+    }
+    assert!(t.is_empty_error());
+}
+
+#[test]
+fn expression_operation_invalid_for_aggregate_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let ty = Type::Array(Box::new(Type::Primitive(PrimitiveTypes::I8)), 2);
+    assert_eq!(ty.type_class(), TypeClass::Aggregate);
+    assert_eq!(TypeClass::Aggregate.to_string(), "aggregate");
+    let value = Value {
+        inner_name: "x".into(),
+        inner_type: ty,
+        mutable: false,
+        alloca: false,
+        malloc: false,
+    };
+    block_state.borrow_mut().values.insert("x".into(), value);
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::ValueName(ast::ValueName::new(Ident::new("x"))),
+        operation: Some((
+            ast::ExpressionOperations::Plus,
+            Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::ValueName(ast::ValueName::new(Ident::new(
+                    "x",
+                ))),
+                operation: None,
+            }),
+        )),
+    };
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1));
+    assert!(t.check_error(StateErrorKind::InvalidOperationForType));
+}
+
+#[test]
+fn expression_cast_transform() {
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::Cast(ast::ExpressionCast {
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(3)),
+                operation: None,
+            }),
+            cast_type: ast::PrimitiveTypes::I64,
+        }),
+        operation: None,
+    };
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "3 as i64");
+}
+
+#[test]
+fn expression_cast() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // Expression: 3 as i64 + 2
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::Cast(ast::ExpressionCast {
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(3)),
+                operation: None,
+            }),
+            cast_type: ast::PrimitiveTypes::I64,
+        }),
+        operation: Some((
+            ast::ExpressionOperations::Plus,
+            Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I64(2)),
+                operation: None,
+            }),
+        )),
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::I64),
+            expr_value: ExpressionResultValue::Register(2),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::Cast {
+            value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(3)),
+            },
+            from: PrimitiveTypes::U8,
+            to: PrimitiveTypes::I64,
+            kind: CastKind::IntegerExtend,
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::ExpressionOperation {
+            operation: ExpressionOperations::Plus,
+            left_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I64),
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            right_value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I64),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I64(2)),
+            },
+            register_number: 2,
+        }
+    );
+}
+
+#[test]
+fn expression_cast_invalid() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let cast = |value, cast_type| ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::Cast(ast::ExpressionCast {
+            expression: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(value),
+                operation: None,
+            }),
+            cast_type,
+        }),
+        operation: None,
+    };
+    // Expression: 1 as bool
+    let res = t.state.expression(
+        &cast(ast::PrimitiveValue::I32(1), ast::PrimitiveTypes::Bool),
+        &block_state,
+    );
+    assert!(res.is_none());
+    // Expression: 1.2 as char
+    let res = t.state.expression(
+        &cast(ast::PrimitiveValue::F32(1.2), ast::PrimitiveTypes::Char),
+        &block_state,
+    );
+    assert!(res.is_none());
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::InvalidCast));
+    assert!(t.check_error_index(1, StateErrorKind::InvalidCast));
+    assert_eq!(t.state.errors[0].value, "i32 as bool");
+    assert!(block_state.borrow().get_context().get().is_empty());
+}
+
+fn array_expr(
+    values: &[ast::PrimitiveValue],
+) -> ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::ArrayValue(ast::ExpressionArrayValue {
+            values: values
+                .iter()
+                .map(|value| ast::Expression {
+                    expression_value: ast::ExpressionValue::PrimitiveValue(value.clone()),
+                    operation: None,
+                })
+                .collect(),
+            location: CodeLocation::new(1, 0),
+        }),
+        operation: None,
+    }
+}
+
+fn array_element_expr(
+    array: ast::Expression<
+        'static,
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    >,
+    index: ast::PrimitiveValue,
+) -> ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::ArrayElement(ast::ExpressionArrayElement {
+            array: Box::new(array),
+            index: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(index),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    }
+}
+
+#[test]
+fn expression_array_transform() {
+    let array = array_expr(&[ast::PrimitiveValue::U8(1), ast::PrimitiveValue::U8(2)]);
+    let array_into: Expression = array.clone().into();
+    assert_eq!(array_into.to_string(), "[1, 2]");
+
+    let expr = array_element_expr(array, ast::PrimitiveValue::U32(1));
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "[1, 2][1]");
+    let ExpressionValue::ArrayElement(ExpressionArrayElement { index, .. }) =
+        expr_into.expression_value
+    else {
+        panic!("Array element expected");
+    };
+    assert_eq!(index.to_string(), "1");
+}
+
+#[test]
+fn expression_array_element() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // Expression: [10, 20][1]
+    let expr = array_element_expr(
+        array_expr(&[ast::PrimitiveValue::I16(10), ast::PrimitiveValue::I16(20)]),
+        ast::PrimitiveValue::U64(1),
+    );
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::I16),
+            expr_value: ExpressionResultValue::Register(3),
+        }
+    );
+    let array_type = Type::Array(Box::new(Type::Primitive(PrimitiveTypes::I16)), 2);
+    let element = |value| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::I16),
+        expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I16(value)),
+    };
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 3);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionArrayValue {
+            values: vec![element(10), element(20)],
+            array_type: array_type.clone(),
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::ArrayElementAddress {
+            array: ExpressionResult {
+                expr_type: array_type,
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            index: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U64),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U64(1)),
+            },
+            register_number: 2,
+        }
+    );
+    assert_eq!(
+        state[2],
+        SemanticStackContext::Load {
+            value_type: Type::Primitive(PrimitiveTypes::I16),
+            address_register: 2,
+            register_number: 3,
+        }
+    );
+}
+
+#[test]
+fn expression_array_element_dynamic_index() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let value_name = ast::ValueName::new(Ident::new("i"));
+    block_state.borrow_mut().values.insert(
+        value_name.clone().into(),
+        Value {
+            inner_name: "i".into(),
+            inner_type: Type::Primitive(PrimitiveTypes::U32),
+            mutable: false,
+            alloca: false,
+            malloc: false,
+        },
+    );
+    // Expression: [true][i]
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::ArrayElement(ast::ExpressionArrayElement {
+            array: Box::new(array_expr(&[ast::PrimitiveValue::Bool(true)])),
+            index: Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::ValueName(value_name),
+                operation: None,
+            }),
+        }),
+        operation: None,
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::Bool));
+}
+
+#[test]
+fn expression_array_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // Empty array
+    let empty_array = ast::ExpressionArrayValue {
+        values: vec![],
+        location: CodeLocation::new(2, 10),
+    };
+    assert!(t.state.array_value(&empty_array, &block_state).is_none());
+    // Array elements with different types
+    assert!(t
+        .state
+        .expression(
+            &array_expr(&[ast::PrimitiveValue::U8(1), ast::PrimitiveValue::U16(2)]),
+            &block_state
+        )
+        .is_none());
+    // Index of non-array value
+    let not_array = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U8(1)),
+        operation: None,
+    };
+    assert!(t
+        .state
+        .expression(
+            &array_element_expr(not_array, ast::PrimitiveValue::U8(0)),
+            &block_state
+        )
+        .is_none());
+    let array = array_expr(&[ast::PrimitiveValue::U8(1), ast::PrimitiveValue::U8(2)]);
+    // Non-integer index
+    assert!(t
+        .state
+        .expression(
+            &array_element_expr(array.clone(), ast::PrimitiveValue::F32(1.)),
+            &block_state
+        )
+        .is_none());
+    // Constant index out of bounds
+    assert!(t
+        .state
+        .expression(
+            &array_element_expr(array.clone(), ast::PrimitiveValue::U8(2)),
+            &block_state
+        )
+        .is_none());
+    assert!(t
+        .state
+        .expression(
+            &array_element_expr(array, ast::PrimitiveValue::I8(-1)),
+            &block_state
+        )
+        .is_none());
+    assert!(t.check_errors_len(6), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::ArrayValueEmpty));
+    assert!(t.check_error_index(1, StateErrorKind::ArrayElementWrongType));
+    assert!(t.check_error_index(2, StateErrorKind::ValueNotArray));
+    assert!(t.check_error_index(3, StateErrorKind::ArrayIndexWrongType));
+    assert!(t.check_error_index(4, StateErrorKind::ArrayIndexOutOfBounds));
+    assert!(t.check_error_index(5, StateErrorKind::ArrayIndexOutOfBounds));
+    assert_eq!(t.state.errors[4].value, "index 2 for array length 2");
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(2, 10));
+}
+
+fn struct_literal_expr(
+    name: &'static str,
+    fields: Vec<(&'static str, ast::PrimitiveValue)>,
+) -> ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::StructLiteral(ast::ExpressionStructLiteral {
+            name: Ident::new(name),
+            fields: fields
+                .into_iter()
+                .map(|(field, value)| ast::StructLiteralField {
+                    name: ast::ValueName::new(Ident::new(field)),
+                    value: ast::Expression {
+                        expression_value: ast::ExpressionValue::PrimitiveValue(value),
+                        operation: None,
+                    },
+                })
+                .collect(),
+        }),
+        operation: None,
+    }
+}
+
+/// Declare type: `struct St { a: u8, b: bool }`
+fn set_struct_type(t: &mut SemanticTest<CustomExpressionInstruction>) -> Type {
+    let s_ty = ast::StructTypes {
+        name: Ident::new("St"),
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("a"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::U8),
+            },
+            ast::StructType {
+                attr_name: Ident::new("b"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+            },
+        ],
+    };
+    t.state.types(&s_ty);
+    assert!(t.is_empty_error());
+    Type::Struct(s_ty.into())
+}
+
+#[test]
+fn expression_struct_literal_transform() {
+    let expr = struct_literal_expr(
+        "St",
+        vec![
+            ("a", ast::PrimitiveValue::U8(1)),
+            ("b", ast::PrimitiveValue::Bool(true)),
+        ],
+    );
+    let ast::ExpressionValue::StructLiteral(literal) = &expr.expression_value else {
+        panic!("Struct literal expected");
+    };
+    assert_eq!(literal.location(), CodeLocation::new(1, 0));
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "St { a: 1, b: true }");
+}
+
+#[test]
+fn expression_struct_literal() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let struct_type = set_struct_type(&mut t);
+    // Fields order differs from struct attributes order
+    let expr = struct_literal_expr(
+        "St",
+        vec![
+            ("b", ast::PrimitiveValue::Bool(false)),
+            ("a", ast::PrimitiveValue::U8(3)),
+        ],
+    );
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: struct_type.clone(),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 1);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionStructLiteral {
+            struct_type,
+            values: vec![
+                ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::U8),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(3)),
+                },
+                ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::Bool),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Bool(false)),
+                },
+            ],
+            register_number: 1,
+        }
+    );
+}
+
+#[test]
+fn expression_struct_literal_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    set_struct_type(&mut t);
+    let a = ("a", ast::PrimitiveValue::U8(1));
+    let b = ("b", ast::PrimitiveValue::Bool(true));
+    for expr in [
+        struct_literal_expr("Unknown", vec![a.clone(), b.clone()]),
+        struct_literal_expr(
+            "St",
+            vec![a.clone(), b.clone(), ("c", ast::PrimitiveValue::U8(1))],
+        ),
+        struct_literal_expr("St", vec![a.clone(), b.clone(), a.clone()]),
+        struct_literal_expr("St", vec![("a", ast::PrimitiveValue::U16(1)), b]),
+        struct_literal_expr("St", vec![a]),
+        struct_literal_expr("St", vec![]),
+    ] {
+        assert!(t.state.expression(&expr, &block_state).is_none());
+    }
+    assert!(t.check_errors_len(6), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::TypeNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::StructLiteralFieldUnknown));
+    assert!(t.check_error_index(2, StateErrorKind::StructLiteralFieldDuplicate));
+    assert!(t.check_error_index(3, StateErrorKind::StructLiteralFieldWrongType));
+    assert!(t.check_error_index(4, StateErrorKind::StructLiteralFieldMissing));
+    assert!(t.check_error_index(5, StateErrorKind::StructLiteralFieldMissing));
+    assert_eq!(t.state.errors[4].value, "St: b");
+    assert_eq!(t.state.errors[5].value, "St: a, b");
+    assert!(block_state.borrow().get_context().get().is_empty());
+}
+
+#[test]
+fn expression_struct_value_nested() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // struct Point { x: i32, y: i32 }
+    let point = ast::StructTypes {
+        name: Ident::new("Point"),
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("x"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::I32),
+            },
+            ast::StructType {
+                attr_name: Ident::new("y"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::I32),
+            },
+        ],
+    };
+    // struct Line { start: Point, end: Point }
+    let line = ast::StructTypes {
+        name: Ident::new("Line"),
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("start"),
+                attr_type: ast::Type::Struct(point.clone()),
+            },
+            ast::StructType {
+                attr_name: Ident::new("end"),
+                attr_type: ast::Type::Struct(point.clone()),
+            },
+        ],
+    };
+    t.state.types(&point);
+    t.state.types(&line);
+    assert!(t.is_empty_error());
+    let value = Value {
+        inner_name: "line".into(),
+        inner_type: Type::Struct(line.into()),
+        mutable: false,
+        alloca: false,
+        malloc: false,
+    };
+    block_state
+        .borrow_mut()
+        .values
+        .insert("line".into(), value.clone());
+    let struct_value = |path: &[&'static str]| ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::StructValue(ast::ExpressionStructValue {
+            name: ast::ValueName::new(Ident::new("line")),
+            attribute: ast::ValueName::new(Ident::new(path[0])),
+            path: path[1..]
+                .iter()
+                .map(|name| ast::ValueName::new(Ident::new(name)))
+                .collect(),
+        }),
+        operation: None,
+    };
+
+    // Expression: line.end.y
+    let res = t
+        .state
+        .expression(&struct_value(&["end", "y"]), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::I32));
+    assert_eq!(res.expr_value, ExpressionResultValue::Register(2));
+    // Expression: line.start
+    let res = t
+        .state
+        .expression(&struct_value(&["start"]), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, Type::Struct(point.into()));
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionStructValue {
+            expression: value.clone(),
+            path: vec![1, 1],
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::ExpressionStructValue {
+            expression: value,
+            path: vec![0],
+            register_number: 3,
+        }
+    );
+
+    // Expression: line.start.z
+    assert!(t
+        .state
+        .expression(&struct_value(&["start", "z"]), &block_state)
+        .is_none());
+    // Expression: line.start.x.y
+    assert!(t
+        .state
+        .expression(&struct_value(&["start", "x", "y"]), &block_state)
+        .is_none());
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::ValueNotStructField));
+    assert!(t.check_error_index(1, StateErrorKind::ValueNotStruct));
+    assert_eq!(t.state.errors[0].value, "z");
+}
+
+fn enum_variant_expr(
+    variant: &'static str,
+    payload: Vec<ast::PrimitiveValue>,
+) -> ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::EnumVariant(ast::ExpressionEnumVariant {
+            name: Ident::new("Opt"),
+            variant: Ident::new(variant),
+            payload: payload
+                .into_iter()
+                .map(|value| ast::Expression {
+                    expression_value: ast::ExpressionValue::PrimitiveValue(value),
+                    operation: None,
+                })
+                .collect(),
+        }),
+        operation: None,
+    }
+}
+
+#[test]
+fn expression_enum_variant_transform() {
+    let expr = enum_variant_expr("Some", vec![ast::PrimitiveValue::U8(1)]);
+    let ast::ExpressionValue::EnumVariant(variant) = &expr.expression_value else {
+        panic!("Enum variant expected");
+    };
+    assert_eq!(variant.location(), CodeLocation::new(1, 0));
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "Opt::Some(1)");
+    let expr_into: Expression = enum_variant_expr("None", vec![]).into();
+    assert_eq!(expr_into.to_string(), "Opt::None");
+}
+
+#[test]
+fn expression_enum_variant() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let enum_type = set_enum_type(&mut t);
+    let expr = enum_variant_expr("None", vec![]);
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: enum_type.clone(),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+    let expr = enum_variant_expr("Some", vec![ast::PrimitiveValue::U8(3)]);
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: enum_type.clone(),
+            expr_value: ExpressionResultValue::Register(2),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionEnumVariant {
+            enum_type: enum_type.clone(),
+            discriminant: 0,
+            payload: vec![],
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::ExpressionEnumVariant {
+            enum_type,
+            discriminant: 1,
+            payload: vec![ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(3)),
+            }],
+            register_number: 2,
+        }
+    );
+}
+
+#[test]
+fn expression_enum_variant_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    set_enum_type(&mut t);
+    set_struct_type(&mut t);
+    let set_name = |name| {
+        let mut expr = enum_variant_expr("None", vec![]);
+        if let ast::ExpressionValue::EnumVariant(variant) = &mut expr.expression_value {
+            variant.name = Ident::new(name);
+        }
+        expr
+    };
+    for expr in [
+        set_name("Unknown"),
+        set_name("St"),
+        enum_variant_expr("Other", vec![]),
+        enum_variant_expr("None", vec![ast::PrimitiveValue::U8(1)]),
+        enum_variant_expr("Some", vec![]),
+        enum_variant_expr("Some", vec![ast::PrimitiveValue::Bool(true)]),
+    ] {
+        assert!(t.state.expression(&expr, &block_state).is_none());
+    }
+    assert!(t.check_errors_len(6), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::TypeNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::ValueNotEnum));
+    assert!(t.check_error_index(2, StateErrorKind::EnumVariantNotFound));
+    assert!(t.check_error_index(3, StateErrorKind::EnumVariantPayloadCountWrong));
+    assert!(t.check_error_index(4, StateErrorKind::EnumVariantPayloadCountWrong));
+    assert!(t.check_error_index(5, StateErrorKind::EnumVariantPayloadWrongType));
+    assert_eq!(t.state.errors[2].value, "Opt::Other");
+    assert_eq!(t.state.errors[3].value, "Opt::None: expected 0, found 1");
+    assert_eq!(
+        t.state.errors[5].value,
+        "Opt::Some: expected u8, found bool"
+    );
+    assert!(block_state.borrow().get_context().get().is_empty());
+}
@@ -0,0 +1,289 @@
+use crate::utils::fixtures::{
+    function, indirect_call_expr, let_stm, name_expr, param, u8_type, value_expr, TestExpression,
+    TestFunction, TestMain,
+};
+use crate::utils::SemanticTest;
+use semantic_analyzer::ast::{self, CodeLocation, GetName, Ident};
+use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::expression::{ExpressionResultValue, ExpressionValue};
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type, TypeClass};
+use semantic_analyzer::types::Function;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod utils;
+
+/// Function pointer type: `fn(u8) -> u8`
+fn u8_fn_type() -> ast::Type<'static> {
+    ast::Type::Function(vec![u8_type()], Box::new(u8_type()))
+}
+
+/// Function as value expression
+fn fn_value_expr(name: &'static str) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::FunctionValue(ast::FunctionName::new(Ident::new(
+            name,
+        ))),
+        operation: None,
+    }
+}
+
+/// `fn double(x: u8) -> u8 { x }`
+fn double_fn() -> TestFunction {
+    function(
+        "double",
+        vec![param("x", u8_type())],
+        u8_type(),
+        vec![ast::BodyStatement::Expression(name_expr("x"))],
+    )
+}
+
+fn u8_fn() -> Type {
+    Type::Function(
+        vec![Type::Primitive(PrimitiveTypes::U8)],
+        Box::new(Type::Primitive(PrimitiveTypes::U8)),
+    )
+}
+
+#[test]
+fn function_pointer_transform() {
+    let ty = ast::Type::Function(
+        vec![u8_type(), ast::Type::Primitive(ast::PrimitiveTypes::I32)],
+        Box::new(ast::Type::Primitive(ast::PrimitiveTypes::Bool)),
+    );
+    assert_eq!(ty.name(), "fn(u8, i32) -> bool");
+    let ty_into: Type = ty.into();
+    // For grcov
+    let _ = format!("{ty_into:?}");
+    assert_eq!(ty_into.to_string(), "fn(u8, i32) -> bool");
+    assert_eq!(ty_into.type_class(), TypeClass::Pointer);
+    assert_eq!(
+        ty_into.get_function(),
+        Some((
+            vec![
+                Type::Primitive(PrimitiveTypes::U8),
+                Type::Primitive(PrimitiveTypes::I32)
+            ],
+            Type::Primitive(PrimitiveTypes::Bool)
+        ))
+    );
+    assert_ne!(ty_into, u8_fn());
+    assert!(Type::Primitive(PrimitiveTypes::U8).get_function().is_none());
+
+    let func = Function {
+        inner_name: String::from("double").into(),
+        inner_type: Type::Primitive(PrimitiveTypes::U8),
+        parameters: vec![Type::Primitive(PrimitiveTypes::U8)],
+    };
+    assert_eq!(func.function_type(), u8_fn());
+
+    // Generic function pointer type
+    let t = Type::Parameter(String::from("T").into());
+    let generic_fn = Type::Function(vec![t.clone()], Box::new(t));
+    assert!(generic_fn.has_type_parameters());
+    let mut type_arguments = HashMap::new();
+    assert!(generic_fn.infer_type_arguments(&u8_fn(), &mut type_arguments));
+    assert_eq!(
+        type_arguments[&String::from("T").into()],
+        Type::Primitive(PrimitiveTypes::U8)
+    );
+    assert_eq!(
+        generic_fn.substitute_type_parameters(&type_arguments, &HashMap::new()),
+        u8_fn()
+    );
+
+    let value: ExpressionValue = fn_value_expr("double").expression_value.into();
+    assert_eq!(value.to_string(), "double");
+    let value: ExpressionValue =
+        indirect_call_expr(name_expr("f"), vec![value_expr(ast::PrimitiveValue::U8(1))])
+            .expression_value
+            .into();
+    assert_eq!(value.to_string(), "f(1)");
+}
+
+#[test]
+fn function_value() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.function_declaration(&double_fn());
+    let res = t
+        .state
+        .expression(&fn_value_expr("double"), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, u8_fn());
+    assert_eq!(res.expr_value, ExpressionResultValue::Register(1));
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx[0],
+        SemanticStackContext::FunctionAddress {
+            function: t.state.global.functions[&String::from("double").into()].clone(),
+            register_number: 1,
+        }
+    );
+
+    // Function not found
+    assert!(t
+        .state
+        .expression(&fn_value_expr("triple"), &block_state)
+        .is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionNotFound));
+    t.clean_errors();
+
+    // Generic function can't be used as value
+    let id_fn = function(
+        "id",
+        vec![param(
+            "x",
+            ast::Type::Parameter(ast::TypeParameterName::new(Ident::new("T"))),
+        )],
+        ast::Type::Parameter(ast::TypeParameterName::new(Ident::new("T"))),
+        vec![ast::BodyStatement::Expression(name_expr("x"))],
+    )
+    .with_type_parameters(vec![ast::TypeParameterName::new(Ident::new("T"))]);
+    t.state.function_declaration(&id_fn);
+    assert!(t
+        .state
+        .expression(&fn_value_expr("id"), &block_state)
+        .is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeParameterNotInferred));
+}
+
+#[test]
+fn function_pointer_indirect_call() {
+    let mut t = SemanticTest::new();
+    // fn apply(f: fn(u8) -> u8, x: u8) -> u8 { f(x) }
+    let apply_fn = function(
+        "apply",
+        vec![param("f", u8_fn_type()), param("x", u8_type())],
+        u8_type(),
+        vec![ast::BodyStatement::Expression(indirect_call_expr(
+            name_expr("f"),
+            vec![name_expr("x")],
+        ))],
+    );
+    // fn main() -> u8 {
+    //     let table = [double, double];
+    //     let f: fn(u8) -> u8 = table[1];
+    //     apply(f, 2)
+    // }
+    let main_fn = function(
+        "main",
+        vec![],
+        u8_type(),
+        vec![
+            let_stm(
+                "table",
+                None,
+                ast::Expression {
+                    expression_value: ast::ExpressionValue::ArrayValue(ast::ExpressionArrayValue {
+                        values: vec![fn_value_expr("double"), fn_value_expr("double")],
+                        location: CodeLocation::new(1, 0),
+                    }),
+                    operation: None,
+                },
+            ),
+            let_stm(
+                "f",
+                Some(u8_fn_type()),
+                ast::Expression {
+                    expression_value: ast::ExpressionValue::ArrayElement(
+                        ast::ExpressionArrayElement {
+                            array: Box::new(name_expr("table")),
+                            index: Box::new(value_expr(ast::PrimitiveValue::U32(1))),
+                        },
+                    ),
+                    operation: None,
+                },
+            ),
+            ast::BodyStatement::Expression(ast::Expression {
+                expression_value: ast::ExpressionValue::FunctionCall(ast::FunctionCall {
+                    name: ast::FunctionName::new(Ident::new("apply")),
+                    parameters: vec![name_expr("f"), value_expr(ast::PrimitiveValue::U8(2))],
+                }),
+                operation: None,
+            }),
+        ],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Function(double_fn()),
+        ast::MainStatement::Function(apply_fn),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        t.state.global.functions[&String::from("apply").into()].parameters[0],
+        u8_fn()
+    );
+
+    let ctx = t.state.context[1].borrow().get_context().get();
+    let Some(SemanticStackContext::IndirectCall {
+        callee,
+        params,
+        register_number,
+    }) = ctx
+        .iter()
+        .find(|ctx| matches!(ctx, SemanticStackContext::IndirectCall { .. }))
+    else {
+        panic!("expected indirect call");
+    };
+    assert_eq!(callee.expr_type, u8_fn());
+    assert_eq!(params.len(), 1);
+    assert_eq!(*register_number, 3);
+}
+
+#[test]
+fn function_pointer_indirect_call_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.function_declaration(&double_fn());
+
+    // Callee isn't function pointer
+    let expr = indirect_call_expr(value_expr(ast::PrimitiveValue::U8(1)), vec![]);
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueNotFunction));
+    t.clean_errors();
+
+    // Wrong parameters count
+    let expr = indirect_call_expr(fn_value_expr("double"), vec![]);
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionParametersCountWrong));
+    t.clean_errors();
+
+    // Wrong parameter type
+    let expr = indirect_call_expr(
+        fn_value_expr("double"),
+        vec![value_expr(ast::PrimitiveValue::Bool(true))],
+    );
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionParameterTypeWrong));
+    t.clean_errors();
+
+    // Function pointer type with unknown types
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param(
+            "f",
+            ast::Type::Function(
+                vec![ast::Type::Struct(ast::StructTypes {
+                    name: Ident::new("Point"),
+                    attributes: vec![],
+                })],
+                Box::new(u8_type()),
+            ),
+        )],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+}
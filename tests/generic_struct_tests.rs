@@ -0,0 +1,552 @@
+use crate::utils::fixtures::{
+    function, name_expr, param, type_param, u8_type, value_expr, TestExpression, TestMain,
+};
+use crate::utils::SemanticTest;
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, GetName, Ident};
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{
+    GenericArgument, GenericParameter, GenericStructTypes, PrimitiveTypes, StructInstanceType,
+    Type, TypeClass,
+};
+use semantic_analyzer::types::FunctionName;
+
+mod utils;
+
+fn i32_type() -> ast::Type<'static> {
+    ast::Type::Primitive(ast::PrimitiveTypes::I32)
+}
+
+/// Generic struct instance type: `name<type_arguments>`
+fn instance_type(
+    name: &'static str,
+    type_arguments: Vec<ast::Type<'static>>,
+) -> ast::Type<'static> {
+    ast::Type::StructInstance(ast::StructInstanceType {
+        name: Ident::new(name),
+        type_arguments: type_arguments
+            .into_iter()
+            .map(ast::GenericArgument::Type)
+            .collect(),
+    })
+}
+
+/// `struct Pair<T, U> { a: T, b: U }`
+fn pair_type() -> ast::GenericStructTypes<'static> {
+    ast::GenericStructTypes {
+        name: Ident::new("Pair"),
+        type_parameters: vec![
+            ast::GenericParameter::Type(ast::TypeParameterName::new(Ident::new("T"))),
+            ast::GenericParameter::Type(ast::TypeParameterName::new(Ident::new("U"))),
+        ],
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("a"),
+                attr_type: type_param("T"),
+            },
+            ast::StructType {
+                attr_name: Ident::new("b"),
+                attr_type: type_param("U"),
+            },
+        ],
+    }
+}
+
+/// `struct Buffer<T, N> { data: [T; N] }`
+fn buffer_type() -> ast::GenericStructTypes<'static> {
+    ast::GenericStructTypes {
+        name: Ident::new("Buffer"),
+        type_parameters: vec![
+            ast::GenericParameter::Type(ast::TypeParameterName::new(Ident::new("T"))),
+            ast::GenericParameter::Const(ast::TypeParameterName::new(Ident::new("N"))),
+        ],
+        attributes: vec![ast::StructType {
+            attr_name: Ident::new("data"),
+            attr_type: ast::Type::ParameterArray(
+                Box::new(type_param("T")),
+                ast::TypeParameterName::new(Ident::new("N")),
+            ),
+        }],
+    }
+}
+
+/// Buffer instance type: `Buffer<type_argument, size>`
+fn buffer_instance(
+    type_argument: ast::Type<'static>,
+    size: ast::ConstantValue<'static>,
+) -> ast::Type<'static> {
+    ast::Type::StructInstance(ast::StructInstanceType {
+        name: Ident::new("Buffer"),
+        type_arguments: vec![
+            ast::GenericArgument::Type(type_argument),
+            ast::GenericArgument::Const(size),
+        ],
+    })
+}
+
+/// Struct attribute value expression: `name.attribute`
+fn attr_expr(name: &'static str, attribute: &'static str) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::StructValue(ast::ExpressionStructValue {
+            name: ast::ValueName::new(Ident::new(name)),
+            attribute: ast::ValueName::new(Ident::new(attribute)),
+            path: vec![],
+        }),
+        operation: None,
+    }
+}
+
+fn pair_u8_i32() -> Type {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&pair_type());
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param(
+            "p",
+            instance_type("Pair", vec![u8_type(), i32_type()]),
+        )],
+        u8_type(),
+        vec![],
+    ));
+    t.state.global.types[&String::from("Pair<u8, i32>").into()].clone()
+}
+
+#[test]
+fn generic_struct_transform() {
+    let data = pair_type();
+    assert_eq!(data.name(), "Pair");
+    assert_eq!(data.location(), CodeLocation::new(1, 0));
+    let data_into: GenericStructTypes = data.into();
+    // For grcov
+    let _ = format!("{data_into:?}");
+    assert_eq!(data_into.name, "Pair");
+    assert_eq!(
+        data_into.type_parameters,
+        vec![
+            GenericParameter::Type(String::from("T").into()),
+            GenericParameter::Type(String::from("U").into())
+        ]
+    );
+    let attr = &data_into.attributes[&"b".into()];
+    assert_eq!(attr.attr_index, 1);
+    assert_eq!(attr.attr_type, Type::Parameter(String::from("U").into()));
+
+    let ty = instance_type("Pair", vec![u8_type(), type_param("T")]);
+    assert_eq!(ty.name(), "Pair<u8, T>");
+    let ast::Type::StructInstance(instance) = &ty else {
+        panic!("expected struct instance type");
+    };
+    assert_eq!(instance.location(), CodeLocation::new(1, 0));
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into.to_string(), "Pair<u8, T>");
+    assert_eq!(ty_into.type_class(), TypeClass::Aggregate);
+    assert!(ty_into.has_type_parameters());
+    assert_eq!(
+        ty_into,
+        Type::StructInstance(StructInstanceType {
+            name: "Pair".to_string(),
+            type_arguments: vec![
+                GenericArgument::Type(Type::Primitive(PrimitiveTypes::U8)),
+                GenericArgument::Type(Type::Parameter(String::from("T").into()))
+            ],
+        })
+    );
+    let ty_into: Type = instance_type("Pair", vec![u8_type(), u8_type()]).into();
+    assert!(!ty_into.has_type_parameters());
+}
+
+#[test]
+fn generic_struct_declaration() {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&pair_type());
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        t.state.global.generic_types[&String::from("Pair").into()],
+        pair_type().into()
+    );
+    // Generic struct type isn't declared as type
+    assert!(t.state.global.types.is_empty());
+    assert!(t.state.global.context.clone().get().is_empty());
+
+    t.state.generic_types(&pair_type());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeAlreadyExist));
+    t.clean_errors();
+
+    t.state.types(&ast::StructTypes {
+        name: Ident::new("Pair"),
+        attributes: vec![],
+    });
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeAlreadyExist));
+    t.clean_errors();
+
+    let mut data = pair_type();
+    data.name = Ident::new("Pair2");
+    data.type_parameters[1] =
+        ast::GenericParameter::Const(ast::TypeParameterName::new(Ident::new("T")));
+    t.state.generic_types(&data);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeParameterAlreadyExist));
+}
+
+#[test]
+fn generic_struct_instance() {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&pair_type());
+    let pair = instance_type("Pair", vec![u8_type(), i32_type()]);
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param("p", pair.clone()), param("q", pair)],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let ty = t.state.global.types[&String::from("Pair<u8, i32>").into()].clone();
+    let struct_type = ty.get_struct().unwrap();
+    assert_eq!(struct_type.name, "Pair<u8, i32>");
+    assert_eq!(
+        struct_type.attributes[&"a".into()].attr_type,
+        Type::Primitive(PrimitiveTypes::U8)
+    );
+    assert_eq!(
+        struct_type.attributes[&"b".into()].attr_type,
+        Type::Primitive(PrimitiveTypes::I32)
+    );
+    assert_eq!(
+        struct_type.instance,
+        Some(StructInstanceType {
+            name: "Pair".to_string(),
+            type_arguments: vec![
+                GenericArgument::Type(Type::Primitive(PrimitiveTypes::U8)),
+                GenericArgument::Type(Type::Primitive(PrimitiveTypes::I32))
+            ],
+        })
+    );
+    assert_eq!(
+        t.state.global.functions[&String::from("fn1").into()].parameters,
+        vec![ty.clone(), ty.clone()]
+    );
+    // Struct instance declared once
+    let ctx = t.state.global.context.clone().get();
+    assert_eq!(ctx.len(), 2);
+    assert_eq!(
+        ctx[0],
+        SemanticStackContext::Types {
+            type_decl: struct_type
+        }
+    );
+    assert!(matches!(
+        ctx[1],
+        SemanticStackContext::FunctionDeclaration { .. }
+    ));
+    assert_eq!(pair_u8_i32(), ty);
+}
+
+#[test]
+fn generic_struct_nested_instance() {
+    let mut t = SemanticTest::new();
+    // struct Node<T> { value: T, next: *Node<T> }
+    let node = ast::GenericStructTypes {
+        name: Ident::new("Node"),
+        type_parameters: vec![ast::GenericParameter::Type(ast::TypeParameterName::new(
+            Ident::new("T"),
+        ))],
+        attributes: vec![
+            ast::StructType {
+                attr_name: Ident::new("value"),
+                attr_type: type_param("T"),
+            },
+            ast::StructType {
+                attr_name: Ident::new("next"),
+                attr_type: ast::Type::Pointer(Box::new(instance_type(
+                    "Node",
+                    vec![type_param("T")],
+                ))),
+            },
+        ],
+    };
+    // struct List { head: Node<Pair<u8, u8>> }
+    let list = ast::StructTypes {
+        name: Ident::new("List"),
+        attributes: vec![ast::StructType {
+            attr_name: Ident::new("head"),
+            attr_type: instance_type(
+                "Node",
+                vec![instance_type("Pair", vec![u8_type(), u8_type()])],
+            ),
+        }],
+    };
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Types(list),
+        ast::MainStatement::GenericTypes(node),
+        ast::MainStatement::GenericTypes(pair_type()),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let pair = t.state.global.types[&String::from("Pair<u8, u8>").into()].clone();
+    let node = t.state.global.types[&String::from("Node<Pair<u8, u8>>").into()]
+        .get_struct()
+        .unwrap();
+    assert_eq!(node.attributes[&"value".into()].attr_type, pair);
+    let Type::Pointer(next) = &node.attributes[&"next".into()].attr_type else {
+        panic!("expected pointer type");
+    };
+    assert_eq!(next.to_string(), "Node<Pair<u8, u8>>");
+    let list = t.state.global.types[&String::from("List").into()]
+        .get_struct()
+        .unwrap();
+    assert_eq!(
+        list.attributes[&"head".into()].attr_type,
+        Type::Struct(node)
+    );
+    assert_eq!(t.state.global.types.len(), 3);
+}
+
+#[test]
+fn generic_struct_instance_errors() {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&pair_type());
+    // Wrong type arguments count
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param("p", instance_type("Pair", vec![u8_type()]))],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    t.clean_errors();
+
+    // Generic struct type not found
+    t.state.function_declaration(&function(
+        "fn2",
+        vec![],
+        instance_type("Triple", vec![u8_type()]),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    t.clean_errors();
+
+    // Type parameter outside of generic function
+    t.state.function_declaration(&function(
+        "fn3",
+        vec![],
+        instance_type("Pair", vec![u8_type(), type_param("T")]),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    assert!(t.state.global.types.is_empty());
+}
+
+#[test]
+fn generic_struct_in_generic_function() {
+    let mut t = SemanticTest::new();
+    // fn first<T>(p: Pair<T, T>) -> T { p.a }
+    let first_fn = function(
+        "first",
+        vec![param(
+            "p",
+            instance_type("Pair", vec![type_param("T"), type_param("T")]),
+        )],
+        type_param("T"),
+        vec![ast::BodyStatement::Expression(attr_expr("p", "a"))],
+    )
+    .with_type_parameters(vec![ast::TypeParameterName::new(Ident::new("T"))]);
+    // fn main(p: Pair<u8, u8>, q: Pair<u8, i32>) -> u8 { first(q); first(p) }
+    let main_fn = function(
+        "main",
+        vec![
+            param("p", instance_type("Pair", vec![u8_type(), u8_type()])),
+            param("q", instance_type("Pair", vec![u8_type(), i32_type()])),
+        ],
+        u8_type(),
+        vec![
+            ast::BodyStatement::FunctionCall(ast::FunctionCall {
+                name: ast::FunctionName::new(Ident::new("first")),
+                parameters: vec![name_expr("q")],
+            }),
+            ast::BodyStatement::Expression(ast::Expression {
+                expression_value: ast::ExpressionValue::FunctionCall(ast::FunctionCall {
+                    name: ast::FunctionName::new(Ident::new("first")),
+                    parameters: vec![name_expr("p")],
+                }),
+                operation: None,
+            }),
+        ],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::GenericTypes(pair_type()),
+        ast::MainStatement::Function(first_fn),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error(StateErrorKind::FunctionParameterTypeWrong));
+
+    let pair = t.state.global.types[&String::from("Pair<u8, u8>").into()].clone();
+    let first: FunctionName = String::from("first<u8>").into();
+    assert_eq!(t.state.global.instantiations.len(), 1);
+    assert_eq!(t.state.global.functions[&first].parameters, vec![pair]);
+    assert_eq!(
+        t.state.global.functions[&first].inner_type,
+        Type::Primitive(PrimitiveTypes::U8)
+    );
+    assert_eq!(t.state.context.len(), 2);
+}
+
+#[test]
+fn generic_struct_literal() {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&pair_type());
+    // fn fn1() -> Pair<u8, i32> { Pair<u8, i32> { a: 1, b: 2 } }
+    let fn1 = function(
+        "fn1",
+        vec![],
+        instance_type("Pair", vec![u8_type(), i32_type()]),
+        vec![ast::BodyStatement::Expression(ast::Expression {
+            expression_value: ast::ExpressionValue::StructLiteral(ast::ExpressionStructLiteral {
+                name: Ident::new("Pair<u8, i32>"),
+                fields: vec![
+                    ast::StructLiteralField {
+                        name: ast::ValueName::new(Ident::new("a")),
+                        value: value_expr(ast::PrimitiveValue::U8(1)),
+                    },
+                    ast::StructLiteralField {
+                        name: ast::ValueName::new(Ident::new("b")),
+                        value: value_expr(ast::PrimitiveValue::I32(2)),
+                    },
+                ],
+            }),
+            operation: None,
+        })],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+}
+
+#[test]
+fn generic_struct_const_parameter() {
+    let data = buffer_type();
+    assert_eq!(data.type_parameters[1].name(), "N");
+    assert_eq!(data.type_parameters[1].location(), CodeLocation::new(1, 0));
+    assert_eq!(data.attributes[0].attr_type.name(), "[\"T\";N]");
+    let data_into: GenericStructTypes = data.into();
+    assert_eq!(
+        data_into.type_parameters[1],
+        GenericParameter::Const(String::from("N").into())
+    );
+    let attr_type = &data_into.attributes[&"data".into()].attr_type;
+    assert_eq!(attr_type.to_string(), "[\"T\";N]");
+    assert_eq!(attr_type.type_class(), TypeClass::Aggregate);
+    assert!(attr_type.has_type_parameters());
+
+    let size = ast::ConstantName::new(Ident::new("SIZE"));
+    let ty = buffer_instance(u8_type(), ast::ConstantValue::Constant(size.clone()));
+    assert_eq!(ty.name(), "Buffer<u8, SIZE>");
+    let ty_into: Type = ty.clone().into();
+    assert_eq!(ty_into.to_string(), "Buffer<u8, SIZE>");
+    assert!(!ty_into.has_type_parameters());
+
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&buffer_type());
+    // const SIZE: u32 = 4;
+    t.state.constant(&ast::Constant {
+        name: size,
+        constant_type: ast::Type::Primitive(ast::PrimitiveTypes::U32),
+        constant_value: ast::ConstantExpression {
+            value: ast::ConstantValue::Value(ast::PrimitiveValue::U32(4)),
+            operation: None,
+        },
+    });
+    // fn fn1(p: Buffer<u8, 4>, q: Buffer<u8, SIZE>) -> u8
+    let buffer = buffer_instance(
+        u8_type(),
+        ast::ConstantValue::Value(ast::PrimitiveValue::U8(4)),
+    );
+    assert_eq!(buffer.name(), "Buffer<u8, U8(4)>");
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param("p", buffer), param("q", ty)],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Instance with constant const argument is the same instance
+    assert_eq!(t.state.global.types.len(), 1);
+    let ty = t.state.global.types[&String::from("Buffer<u8, 4>").into()].clone();
+    let struct_type = ty.get_struct().unwrap();
+    assert_eq!(struct_type.name, "Buffer<u8, 4>");
+    assert_eq!(
+        struct_type.attributes[&"data".into()].attr_type,
+        Type::Array(Box::new(Type::Primitive(PrimitiveTypes::U8)), 4)
+    );
+    assert_eq!(
+        struct_type.instance,
+        Some(StructInstanceType {
+            name: "Buffer".to_string(),
+            type_arguments: vec![
+                GenericArgument::Type(Type::Primitive(PrimitiveTypes::U8)),
+                GenericArgument::value(4)
+            ],
+        })
+    );
+    assert_eq!(
+        t.state.global.functions[&String::from("fn1").into()].parameters,
+        vec![ty.clone(), ty]
+    );
+}
+
+#[test]
+fn generic_struct_const_argument_errors() {
+    let mut t = SemanticTest::new();
+    t.state.generic_types(&buffer_type());
+    // Const argument isn't declared constant
+    t.state.function_declaration(&function(
+        "fn1",
+        vec![param(
+            "p",
+            buffer_instance(
+                u8_type(),
+                ast::ConstantValue::Constant(ast::ConstantName::new(Ident::new("LEN"))),
+            ),
+        )],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ConstArgumentNotConstant));
+    assert_eq!(t.state.errors[0].value, "Buffer<u8, LEN>");
+    t.clean_errors();
+
+    // Const argument is negative
+    t.state.function_declaration(&function(
+        "fn2",
+        vec![],
+        buffer_instance(
+            u8_type(),
+            ast::ConstantValue::Value(ast::PrimitiveValue::I32(-1)),
+        ),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ConstArgumentNotConstant));
+    t.clean_errors();
+
+    // Type argument for const parameter
+    t.state.function_declaration(&function(
+        "fn3",
+        vec![],
+        instance_type("Buffer", vec![u8_type(), u8_type()]),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    assert!(t.state.global.types.is_empty());
+}
@@ -0,0 +1,445 @@
+use crate::utils::fixtures::{
+    call_expr, function, name_expr, param, type_param, u8_type, value_expr, TestBodyStatement,
+    TestFunction, TestMain,
+};
+use crate::utils::SemanticTest;
+use semantic_analyzer::ast::{self, GetLocation, GetName, Ident};
+use semantic_analyzer::semantic::MAX_INSTANTIATION_DEPTH;
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type, TypeClass, TypeParameterName};
+use semantic_analyzer::types::{FunctionInstantiation, FunctionName, FunctionStatement};
+use std::collections::HashMap;
+
+mod utils;
+
+/// Generic function: `fn name<type_parameters>(parameters) -> result_type`
+fn generic_function(
+    name: &'static str,
+    type_parameters: Vec<&'static str>,
+    parameters: Vec<ast::FunctionParameter<'static>>,
+    result_type: ast::Type<'static>,
+    body: Vec<TestBodyStatement>,
+) -> TestFunction {
+    function(name, parameters, result_type, body).with_type_parameters(
+        type_parameters
+            .into_iter()
+            .map(|name| ast::TypeParameterName::new(Ident::new(name)))
+            .collect(),
+    )
+}
+
+/// `fn id<T>(a: T) -> T { let b: T = a; b }`
+fn id_function() -> TestFunction {
+    generic_function(
+        "id",
+        vec!["T"],
+        vec![param("a", type_param("T"))],
+        type_param("T"),
+        vec![
+            ast::BodyStatement::LetBinding(ast::LetBinding {
+                name: ast::ValueName::new(Ident::new("b")),
+                mutable: false,
+                value_type: Some(type_param("T")),
+                value: Box::new(name_expr("a")),
+            }),
+            ast::BodyStatement::Expression(name_expr("b")),
+        ],
+    )
+}
+
+fn main_function(body: Vec<TestBodyStatement>) -> TestFunction {
+    function("main", vec![], u8_type(), body)
+}
+
+#[test]
+fn generic_function_transform() {
+    let ty = type_param("T");
+    assert_eq!(ty.name(), "T");
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into, Type::Parameter(String::from("T").into()));
+    assert_eq!(ty_into.to_string(), "T");
+    assert_eq!(ty_into.type_class(), TypeClass::None);
+
+    let type_parameter = ast::TypeParameterName::new(Ident::new("T"));
+    assert_eq!(type_parameter.location().line(), 1);
+    assert_eq!(type_parameter.name(), "T");
+
+    let fn_into: FunctionStatement = id_function().into();
+    // For grcov
+    let _ = format!("{fn_into:?}");
+    assert_eq!(fn_into.type_parameters, vec![String::from("T").into()]);
+    assert_eq!(
+        fn_into.result_type,
+        Type::Parameter(String::from("T").into())
+    );
+}
+
+#[test]
+fn generic_type_arguments() {
+    let t: TypeParameterName = String::from("T").into();
+    let u8_ty = Type::Primitive(PrimitiveTypes::U8);
+    let i32_ty = Type::Primitive(PrimitiveTypes::I32);
+    let param_ty = Type::Tuple(vec![
+        Type::Parameter(t.clone()),
+        Type::Array(Box::new(Type::Parameter(t.clone())), 2),
+        Type::Reference(Box::new(Type::Parameter(t.clone()))),
+    ]);
+
+    let mut type_arguments = HashMap::new();
+    let arg_ty = Type::Tuple(vec![
+        u8_ty.clone(),
+        Type::Array(Box::new(u8_ty.clone()), 2),
+        Type::Reference(Box::new(u8_ty.clone())),
+    ]);
+    assert!(param_ty.infer_type_arguments(&arg_ty, &mut type_arguments));
+    assert_eq!(type_arguments.get(&t), Some(&u8_ty));
+    assert_eq!(
+        param_ty.substitute_type_parameters(&type_arguments, &HashMap::new()),
+        arg_ty
+    );
+
+    // Type argument already inferred as other type
+    let mut type_arguments = HashMap::new();
+    let arg_ty = Type::Tuple(vec![
+        u8_ty.clone(),
+        Type::Array(Box::new(i32_ty.clone()), 2),
+        Type::Reference(Box::new(u8_ty.clone())),
+    ]);
+    assert!(!param_ty.infer_type_arguments(&arg_ty, &mut type_arguments));
+
+    // Wrong array size and type kind
+    let mut type_arguments = HashMap::new();
+    let param_ty = Type::Array(Box::new(Type::Parameter(t.clone())), 2);
+    assert!(!param_ty.infer_type_arguments(
+        &Type::Array(Box::new(u8_ty.clone()), 3),
+        &mut type_arguments
+    ));
+    assert!(!param_ty.infer_type_arguments(&u8_ty, &mut type_arguments));
+    assert!(
+        !Type::Pointer(Box::new(Type::Parameter(t.clone()))).infer_type_arguments(
+            &Type::Reference(Box::new(u8_ty.clone())),
+            &mut type_arguments
+        )
+    );
+    assert!(type_arguments.is_empty());
+
+    // Type parameter without type argument isn't changed
+    assert_eq!(
+        Type::Pointer(Box::new(Type::Parameter(t.clone())))
+            .substitute_type_parameters(&HashMap::new(), &HashMap::new()),
+        Type::Pointer(Box::new(Type::Parameter(t)))
+    );
+}
+
+#[test]
+fn generic_function_declaration() {
+    let mut t = SemanticTest::new();
+    t.state.function_declaration(&id_function());
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let name: FunctionName = String::from("id").into();
+    let generic = t.state.global.generic_functions.get(&name).unwrap();
+    assert_eq!(generic.inner_name, name);
+    assert_eq!(generic.type_parameters, vec![String::from("T").into()]);
+    assert_eq!(
+        generic.parameters,
+        vec![Type::Parameter(String::from("T").into())]
+    );
+    // Generic function isn't declared as function
+    assert!(t.state.global.functions.is_empty());
+    assert!(t.state.global.context.clone().get().is_empty());
+
+    t.state.function_declaration(&id_function());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionAlreadyExist));
+    t.clean_errors();
+
+    t.state
+        .function_declaration(&generic_function("id", vec![], vec![], u8_type(), vec![]));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::FunctionAlreadyExist));
+}
+
+#[test]
+fn generic_function_declaration_errors() {
+    let mut t = SemanticTest::new();
+    t.state.function_declaration(&generic_function(
+        "pair",
+        vec!["T", "T"],
+        vec![],
+        u8_type(),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeParameterAlreadyExist));
+    t.clean_errors();
+
+    t.state.function_declaration(&generic_function(
+        "pair",
+        vec!["T"],
+        vec![param("a", type_param("U"))],
+        type_param("T"),
+        vec![],
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    assert!(t.state.global.generic_functions.is_empty());
+    t.clean_errors();
+
+    // Type parameter isn't visible outside generic function
+    let fn1 = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("fn1")),
+        vec![],
+        type_param("T"),
+        vec![],
+    );
+    t.state.function_declaration(&fn1);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+}
+
+#[test]
+fn generic_function_instantiation() {
+    let mut t = SemanticTest::new();
+    // fn main() -> u8 { id(2i32); id(3u8); id(1u8) }
+    let main_fn = main_function(vec![
+        ast::BodyStatement::FunctionCall(ast::FunctionCall {
+            name: ast::FunctionName::new(Ident::new("id")),
+            parameters: vec![value_expr(ast::PrimitiveValue::I32(2))],
+        }),
+        ast::BodyStatement::FunctionCall(ast::FunctionCall {
+            name: ast::FunctionName::new(Ident::new("id")),
+            parameters: vec![value_expr(ast::PrimitiveValue::U8(3))],
+        }),
+        ast::BodyStatement::Expression(call_expr(
+            "id",
+            vec![value_expr(ast::PrimitiveValue::U8(1))],
+        )),
+    ]);
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Function(id_function()),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Instantiation declared once for each type arguments
+    let id_i32: FunctionName = String::from("id<i32>").into();
+    let id_u8: FunctionName = String::from("id<u8>").into();
+    assert_eq!(t.state.global.instantiations.len(), 2);
+    assert_eq!(
+        t.state.global.instantiations[&id_u8],
+        FunctionInstantiation {
+            function: String::from("id").into(),
+            type_arguments: vec![Type::Primitive(PrimitiveTypes::U8)],
+        }
+    );
+    let fn_u8 = &t.state.global.functions[&id_u8];
+    assert_eq!(fn_u8.inner_type, Type::Primitive(PrimitiveTypes::U8));
+    assert_eq!(fn_u8.parameters, vec![Type::Primitive(PrimitiveTypes::U8)]);
+    assert_eq!(
+        t.state.global.functions[&id_i32].inner_type,
+        Type::Primitive(PrimitiveTypes::I32)
+    );
+
+    // Instantiations declarations have the same order as bodies
+    let ctx = t.state.global.context.get();
+    let declarations: Vec<_> = ctx
+        .iter()
+        .map(|ctx| match ctx {
+            SemanticStackContext::FunctionDeclaration { fn_decl } => fn_decl.clone(),
+            _ => panic!("expected function declaration"),
+        })
+        .collect();
+    assert_eq!(declarations.len(), 3);
+    assert_eq!(declarations[0].name, String::from("main").into());
+    assert_eq!(declarations[1].name, id_i32);
+    assert_eq!(declarations[2].name, id_u8);
+    assert!(declarations[2].type_parameters.is_empty());
+    assert_eq!(
+        declarations[2].result_type,
+        Type::Primitive(PrimitiveTypes::U8)
+    );
+    assert_eq!(
+        declarations[2].parameters[0].parameter_type,
+        Type::Primitive(PrimitiveTypes::U8)
+    );
+
+    assert_eq!(t.state.context.len(), 3);
+    let ctx = t.state.context[2].borrow().get_context().get();
+    let Some(SemanticStackContext::LetBinding { let_decl, .. }) = ctx
+        .iter()
+        .find(|ctx| matches!(ctx, SemanticStackContext::LetBinding { .. }))
+    else {
+        panic!("expected let binding");
+    };
+    assert_eq!(let_decl.inner_type, Type::Primitive(PrimitiveTypes::U8));
+    let SemanticStackContext::Call { call, .. } =
+        &t.state.context[0].borrow().get_context().get()[0]
+    else {
+        panic!("expected call");
+    };
+    assert_eq!(call.inner_name, id_i32);
+}
+
+#[test]
+fn generic_function_nested_instantiation() {
+    let mut t = SemanticTest::new();
+    // fn wrap<T>(a: T) -> T { id(a) }
+    let wrap_fn = generic_function(
+        "wrap",
+        vec!["T"],
+        vec![param("a", type_param("T"))],
+        type_param("T"),
+        vec![ast::BodyStatement::Expression(call_expr(
+            "id",
+            vec![name_expr("a")],
+        ))],
+    );
+    // fn main() -> u8 { wrap(1u8) }
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "wrap",
+        vec![value_expr(ast::PrimitiveValue::U8(1))],
+    ))]);
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Function(id_function()),
+        ast::MainStatement::Function(main_fn),
+        ast::MainStatement::Function(wrap_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert!(t
+        .state
+        .global
+        .instantiations
+        .contains_key(&String::from("wrap<u8>").into()));
+    assert!(t
+        .state
+        .global
+        .instantiations
+        .contains_key(&String::from("id<u8>").into()));
+    assert_eq!(t.state.context.len(), 3);
+}
+
+#[test]
+fn generic_function_call_errors() {
+    let mut t = SemanticTest::new();
+    // fn pair<T>(a: T, b: T) -> T { a }
+    let pair_fn = generic_function(
+        "pair",
+        vec!["T"],
+        vec![param("a", type_param("T")), param("b", type_param("T"))],
+        type_param("T"),
+        vec![ast::BodyStatement::Expression(name_expr("a"))],
+    );
+    // fn make<T>() -> u8 { 1 }
+    let make_fn = generic_function(
+        "make",
+        vec!["T"],
+        vec![],
+        u8_type(),
+        vec![ast::BodyStatement::Expression(value_expr(
+            ast::PrimitiveValue::U8(1),
+        ))],
+    );
+    t.state.function_declaration(&pair_fn);
+    t.state.function_declaration(&make_fn);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "pair",
+        vec![value_expr(ast::PrimitiveValue::U8(1))],
+    ))]);
+    t.state.function_body(&main_fn);
+    assert!(t.check_error_index(0, StateErrorKind::FunctionParametersCountWrong));
+    t.clean_errors();
+
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "pair",
+        vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::I32(1)),
+        ],
+    ))]);
+    t.state.function_body(&main_fn);
+    assert!(t.check_error_index(0, StateErrorKind::FunctionParameterTypeWrong));
+    t.clean_errors();
+
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "make",
+        vec![],
+    ))]);
+    t.state.function_body(&main_fn);
+    assert!(t.check_error_index(0, StateErrorKind::TypeParameterNotInferred));
+    assert!(t.state.global.instantiations.is_empty());
+    t.clean_errors();
+
+    // Instantiation body is analyzed by generic function body
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "pair",
+        vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::U8(2)),
+        ],
+    ))]);
+    t.state.function_body(&main_fn);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let contexts = t.state.context.len();
+    t.state.function_body(&make_fn);
+    assert_eq!(t.state.context.len(), contexts);
+    t.state.function_body(&pair_fn);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(t.state.context.len(), contexts + 1);
+    // Instantiation body analyzed only once
+    t.state.function_body(&pair_fn);
+    assert_eq!(t.state.context.len(), contexts + 1);
+}
+
+#[test]
+fn generic_function_instantiation_too_deep() {
+    let mut t = SemanticTest::new();
+    // fn f<T>(a: T) -> u8 { f(&a) }
+    let f_fn = generic_function(
+        "f",
+        vec!["T"],
+        vec![param("a", type_param("T"))],
+        u8_type(),
+        vec![ast::BodyStatement::Expression(call_expr(
+            "f",
+            vec![ast::Expression {
+                expression_value: ast::ExpressionValue::AddressOf(ast::ExpressionAddressOf {
+                    value: ast::ValueName::new(Ident::new("a")),
+                    mutable: false,
+                }),
+                operation: None,
+            }],
+        ))],
+    );
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "f",
+        vec![value_expr(ast::PrimitiveValue::U8(1))],
+    ))]);
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Function(f_fn),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert_eq!(t.state.global.instantiations.len(), MAX_INSTANTIATION_DEPTH);
+    assert!(t.check_error_index(0, StateErrorKind::GenericInstantiationTooDeep));
+}
+
+#[test]
+fn generic_function_instantiation_without_body() {
+    let mut t = SemanticTest::new();
+    // Generic function declared, but its body isn't analyzed
+    t.state.function_declaration(&id_function());
+    let main_fn = main_function(vec![ast::BodyStatement::Expression(call_expr(
+        "id",
+        vec![value_expr(ast::PrimitiveValue::U8(1))],
+    ))]);
+    let main_stm: TestMain = vec![ast::MainStatement::Function(main_fn)];
+    t.state.run(&main_stm);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error(StateErrorKind::FunctionNotFound));
+    assert_eq!(t.state.errors[0].value, "id<u8>");
+}
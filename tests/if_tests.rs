@@ -1,10 +1,11 @@
+use crate::utils::fixtures::value_expr;
 use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
 use semantic_analyzer::ast;
 use semantic_analyzer::ast::{CodeLocation, GetLocation, Ident};
 use semantic_analyzer::types::block_state::BlockState;
 use semantic_analyzer::types::condition::{
-    Condition, ExpressionCondition, ExpressionLogicCondition, IfBodyStatement, IfBodyStatements,
-    IfCondition, IfLoopBodyStatement, IfStatement, LogicCondition,
+    Condition, ConditionTruthiness, ExpressionCondition, ExpressionLogicCondition, IfBodyStatement,
+    IfBodyStatements, IfCondition, IfLoopBodyStatement, IfStatement, LogicCondition,
 };
 use semantic_analyzer::types::error::StateErrorKind;
 use semantic_analyzer::types::expression::{Expression, ExpressionResult, ExpressionResultValue};
@@ -37,6 +38,7 @@ fn if_single_transform() {
     };
     let binding = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -58,7 +60,7 @@ fn if_single_transform() {
         ast::IfBodyStatement::LetBinding(let_binding.clone()),
         ast::IfBodyStatement::Binding(binding.clone()),
         ast::IfBodyStatement::FunctionCall(fn_call.clone()),
-        ast::IfBodyStatement::If(if_statement2.clone()),
+        ast::IfBodyStatement::If(Box::new(if_statement2.clone())),
         ast::IfBodyStatement::Loop(vec![loop_statement.clone()]),
         ast::IfBodyStatement::Return(return_statement.clone()),
     ]);
@@ -204,6 +206,7 @@ fn if_logic_transform() {
     };
     let binding = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -226,7 +229,7 @@ fn if_logic_transform() {
         ast::IfLoopBodyStatement::LetBinding(let_binding.clone()),
         ast::IfLoopBodyStatement::Binding(binding.clone()),
         ast::IfLoopBodyStatement::FunctionCall(fn_call.clone()),
-        ast::IfLoopBodyStatement::If(if_statement2.clone()),
+        ast::IfLoopBodyStatement::If(Box::new(if_statement2.clone())),
         ast::IfLoopBodyStatement::Loop(vec![loop_statement.clone()]),
         ast::IfLoopBodyStatement::Return(return_statement.clone()),
         ast::IfLoopBodyStatement::Break,
@@ -324,6 +327,8 @@ fn check_if_and_else_if_statement_duplicate() {
 fn if_condition_calculation_simple() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
 
     // Simple without operations
     let condition1 = ast::IfCondition::Single(ast::Expression {
@@ -740,6 +745,8 @@ fn if_condition_primitive_type_only_check() {
 fn else_if_statement() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -905,6 +912,8 @@ fn else_if_statement() {
 fn if_body_statements() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -934,6 +943,7 @@ fn if_body_statements() {
     });
     let if_body_binding = ast::IfBodyStatement::Binding(ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -943,7 +953,7 @@ fn if_body_statements() {
         name: ast::FunctionName::new(Ident::new("fn2")),
         parameters: vec![],
     });
-    let if_body_if = ast::IfBodyStatement::If(ast::IfStatement {
+    let if_body_if = ast::IfBodyStatement::If(Box::new(ast::IfStatement {
         condition: ast::IfCondition::Single(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -956,7 +966,7 @@ fn if_body_statements() {
         )]),
         else_statement: None,
         else_if_statement: None,
-    });
+    }));
     let if_body_loop = ast::IfBodyStatement::Loop(vec![ast::LoopBodyStatement::FunctionCall(
         ast::FunctionCall {
             name: ast::FunctionName::new(Ident::new("fn2")),
@@ -1182,6 +1192,8 @@ fn if_body_statements() {
 fn if_loop_body_statements() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1221,6 +1233,7 @@ fn if_loop_body_statements() {
     });
     let if_body_binding = ast::IfLoopBodyStatement::Binding(ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -1230,7 +1243,7 @@ fn if_loop_body_statements() {
         name: ast::FunctionName::new(Ident::new("fn2")),
         parameters: vec![],
     });
-    let if_body_if = ast::IfLoopBodyStatement::If(ast::IfStatement {
+    let if_body_if = ast::IfLoopBodyStatement::If(Box::new(ast::IfStatement {
         condition: ast::IfCondition::Single(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -1243,7 +1256,7 @@ fn if_loop_body_statements() {
         )]),
         else_statement: None,
         else_if_statement: None,
-    });
+    }));
     let if_body_loop = ast::IfLoopBodyStatement::Loop(vec![ast::LoopBodyStatement::FunctionCall(
         ast::FunctionCall {
             name: ast::FunctionName::new(Ident::new("fn3")),
@@ -1469,6 +1482,8 @@ fn if_loop_body_statements() {
 fn if_loop_body_instructions_after_return() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1518,6 +1533,8 @@ fn if_loop_body_instructions_after_return() {
 fn else_if_loop_body_instructions_after_return() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1570,6 +1587,8 @@ fn else_if_loop_body_instructions_after_return() {
 fn if_body_instructions_after_return() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1619,6 +1638,8 @@ fn if_body_instructions_after_return() {
 fn if_else_body_instructions_after_return() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1671,6 +1692,8 @@ fn if_else_body_instructions_after_return() {
 fn if_loop_body_instructions_after_break() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1716,6 +1739,8 @@ fn if_loop_body_instructions_after_break() {
 fn if_loop_body_instructions_after_continue() {
     let block_state = Rc::new(RefCell::new(BlockState::new(None)));
     let mut t = SemanticTest::new();
+    // Integer single if-condition is used
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
     let if_expr = ast::Expression {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::U64(1)),
         operation: None,
@@ -1756,3 +1781,384 @@ fn if_loop_body_instructions_after_continue() {
         t.state.errors[0]
     );
 }
+
+#[test]
+fn if_condition_single_wrong_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    assert_eq!(t.state.condition_truthiness, ConditionTruthiness::Bool);
+    let label_if_begin: LabelName = String::from("if_begin").into();
+    let label_if_else: LabelName = String::from("if_else").into();
+    let label_if_end: LabelName = String::from("if_end").into();
+    let condition = |value| {
+        ast::IfCondition::Single(ast::Expression {
+            expression_value: ast::ExpressionValue::<
+                CustomExpressionInstruction,
+                CustomExpression<CustomExpressionInstruction>,
+            >::PrimitiveValue(value),
+            operation: None,
+        })
+    };
+
+    // Default policy accepts only `bool`
+    for value in [
+        ast::PrimitiveValue::I32(1),
+        ast::PrimitiveValue::Ptr,
+        ast::PrimitiveValue::F64(1.),
+    ] {
+        t.state.if_condition_calculation(
+            &condition(value),
+            &block_state,
+            &label_if_begin,
+            &label_if_else,
+            &label_if_end,
+            false,
+        );
+    }
+    assert!(t.check_errors_len(3), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::IfConditionWrongType));
+    assert!(t.check_error_index(1, StateErrorKind::IfConditionWrongType));
+    assert!(t.check_error_index(2, StateErrorKind::IfConditionWrongType));
+    assert!(block_state.borrow().get_context().get().is_empty());
+
+    // Pointer accepted only by permissive policy
+    t.clean_errors();
+    t.state.condition_truthiness = ConditionTruthiness::Integer;
+    t.state.if_condition_calculation(
+        &condition(ast::PrimitiveValue::Ptr),
+        &block_state,
+        &label_if_begin,
+        &label_if_else,
+        &label_if_end,
+        false,
+    );
+    assert!(t.check_errors_len(1));
+    t.clean_errors();
+    t.state.condition_truthiness = ConditionTruthiness::IntegerAndPointer;
+    t.state.if_condition_calculation(
+        &condition(ast::PrimitiveValue::Ptr),
+        &block_state,
+        &label_if_begin,
+        &label_if_else,
+        &label_if_end,
+        false,
+    );
+    assert!(t.is_empty_error());
+    t.state.if_condition_calculation(
+        &condition(ast::PrimitiveValue::Bool(true)),
+        &block_state,
+        &label_if_begin,
+        &label_if_else,
+        &label_if_end,
+        false,
+    );
+    assert!(t.is_empty_error());
+    assert_eq!(block_state.borrow().get_context().get().len(), 2);
+}
+
+#[test]
+fn if_condition_calculation_logic_short_circuit() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    assert!(!t.state.logic_condition_short_circuit);
+    t.state.logic_condition_short_circuit = true;
+
+    let expr = |value| ast::Expression {
+        expression_value: ast::ExpressionValue::<
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >::PrimitiveValue(ast::PrimitiveValue::I8(value)),
+        operation: None,
+    };
+    let expr_condition = |left, right| ast::ExpressionCondition {
+        left: expr(left),
+        condition: ast::Condition::Eq,
+        right: expr(right),
+    };
+    let condition_result =
+        |left, right, register_number| SemanticStackContext::ConditionExpression {
+            left_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(left)),
+            },
+            right_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(right)),
+            },
+            condition: Condition::Eq,
+            register_number,
+        };
+    let label_if_begin: LabelName = String::from("if_begin").into();
+    let label_if_else: LabelName = String::from("if_else").into();
+    let label_if_end: LabelName = String::from("if_end").into();
+
+    // Logic condition: 1 == 2 && 3 == 4 || 5 == 6
+    let condition = ast::IfCondition::Logic(ast::ExpressionLogicCondition {
+        left: expr_condition(1, 2),
+        right: Some((
+            ast::LogicCondition::And,
+            Box::new(ast::ExpressionLogicCondition {
+                left: expr_condition(3, 4),
+                right: Some((
+                    ast::LogicCondition::Or,
+                    Box::new(ast::ExpressionLogicCondition {
+                        left: expr_condition(5, 6),
+                        right: None,
+                    }),
+                )),
+            }),
+        )),
+    });
+    t.state.if_condition_calculation(
+        &condition,
+        &block_state,
+        &label_if_begin,
+        &label_if_else,
+        &label_if_end,
+        true,
+    );
+    assert!(t.is_empty_error());
+
+    let label_next1: LabelName = String::from("logic_next").into();
+    let label_next2: LabelName = String::from("logic_next.0").into();
+    let ctx = block_state.borrow().get_context().clone().get();
+    assert_eq!(ctx.len(), 8);
+    assert_eq!(ctx[0], condition_result(1, 2, 1));
+    // `And` jumps to `else` if left condition is false
+    assert_eq!(
+        ctx[1],
+        SemanticStackContext::IfConditionLogic {
+            label_if_begin: label_next1.clone(),
+            label_if_end: label_if_else.clone(),
+            result_register: 1,
+        }
+    );
+    assert_eq!(
+        ctx[2],
+        SemanticStackContext::SetLabel { label: label_next1 }
+    );
+    assert_eq!(ctx[3], condition_result(3, 4, 2));
+    // `Or` jumps to if-body if left condition is true
+    assert_eq!(
+        ctx[4],
+        SemanticStackContext::IfConditionLogic {
+            label_if_begin: label_if_begin.clone(),
+            label_if_end: label_next2.clone(),
+            result_register: 2,
+        }
+    );
+    assert_eq!(
+        ctx[5],
+        SemanticStackContext::SetLabel { label: label_next2 }
+    );
+    assert_eq!(ctx[6], condition_result(5, 6, 3));
+    assert_eq!(
+        ctx[7],
+        SemanticStackContext::IfConditionLogic {
+            label_if_begin,
+            label_if_end: label_if_else,
+            result_register: 3,
+        }
+    );
+}
+
+fn if_expression_value(
+    body: Vec<
+        ast::IfBodyStatement<
+            'static,
+            CustomExpressionInstruction,
+            CustomExpression<CustomExpressionInstruction>,
+        >,
+    >,
+    value: ast::PrimitiveValue,
+    else_value: ast::PrimitiveValue,
+) -> ast::ExpressionIf<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::ExpressionIf {
+        condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+        body: ast::ExpressionBranch {
+            body,
+            value: Box::new(value_expr(value)),
+        },
+        else_body: ast::ExpressionBranch {
+            body: vec![],
+            value: Box::new(value_expr(else_value)),
+        },
+    }
+}
+
+#[test]
+fn if_expression_transform() {
+    let if_expr = if_expression_value(
+        vec![],
+        ast::PrimitiveValue::U8(1),
+        ast::PrimitiveValue::U8(2),
+    );
+    let expr_value = ast::ExpressionValue::If(Box::new(if_expr.clone()));
+    let expr_value_into: semantic_analyzer::types::expression::ExpressionValue = expr_value.into();
+    assert_eq!(expr_value_into.to_string(), "if { 1 } else { 2 }");
+    let semantic_analyzer::types::expression::ExpressionValue::If(val) = expr_value_into else {
+        unreachable!()
+    };
+    assert_eq!(val.condition, if_expr.condition.clone().into());
+    assert!(val.body.body.is_empty());
+    assert_eq!(
+        *val.body.value,
+        value_expr(ast::PrimitiveValue::U8(1)).into()
+    );
+    assert_eq!(
+        *val.else_body.value,
+        value_expr(ast::PrimitiveValue::U8(2)).into()
+    );
+}
+
+#[test]
+fn if_expression() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let if_expr = if_expression_value(
+        vec![ast::IfBodyStatement::LetBinding(ast::LetBinding {
+            name: ast::ValueName::new(Ident::new("y")),
+            mutable: false,
+            value_type: None,
+            value: Box::new(value_expr(ast::PrimitiveValue::U8(3))),
+        })],
+        ast::PrimitiveValue::U8(1),
+        ast::PrimitiveValue::U8(2),
+    );
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::If(Box::new(if_expr)),
+        operation: None,
+    };
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::U8),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+    // Branch values doesn't leak to the outer state
+    assert!(block_state.borrow().values.is_empty());
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let u8_result = |val| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::U8),
+        expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(val)),
+    };
+    let branch_state = block_state.borrow().children[0].borrow().children[0].clone();
+    let let_decl = branch_state
+        .borrow()
+        .get_value_name(&String::from("y").into())
+        .unwrap();
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx,
+        vec![
+            SemanticStackContext::IfConditionExpression {
+                expr_result: ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::Bool),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Bool(true)),
+                },
+                label_if_begin: label("if_begin"),
+                label_if_end: label("if_else"),
+            },
+            SemanticStackContext::SetLabel {
+                label: label("if_begin")
+            },
+            SemanticStackContext::LetBinding {
+                let_decl,
+                expr_result: u8_result(3),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("if_branch_end")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("if_branch_end")
+            },
+            SemanticStackContext::MergeValue {
+                label: label("if_end"),
+                expr_result: u8_result(1),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("if_end")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("if_else")
+            },
+            SemanticStackContext::JumpTo {
+                label: label("if_branch_end.0")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("if_branch_end.0")
+            },
+            SemanticStackContext::MergeValue {
+                label: label("if_end"),
+                expr_result: u8_result(2),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("if_end")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("if_end")
+            },
+            SemanticStackContext::MergeResult {
+                label: label("if_end"),
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                register_number: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn if_expression_with_return() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    // Branch with return doesn't yield value
+    let if_expr = if_expression_value(
+        vec![ast::IfBodyStatement::Return(value_expr(
+            ast::PrimitiveValue::U8(0),
+        ))],
+        ast::PrimitiveValue::U8(1),
+        ast::PrimitiveValue::U8(2),
+    );
+    let res = t.state.if_expression(&if_expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::U8));
+    let ctx = block_state.borrow().get_context().get();
+    let merge_values = ctx
+        .iter()
+        .filter(|ctx| matches!(ctx, SemanticStackContext::MergeValue { .. }))
+        .count();
+    assert_eq!(merge_values, 1);
+
+    // All branches return: expression never yields value
+    let mut if_expr = if_expr;
+    if_expr.else_body.body = if_expr.body.body.clone();
+    assert!(t.state.if_expression(&if_expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::IfExpressionWithoutValue));
+}
+
+#[test]
+fn if_expression_branch_type_mismatch() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let if_expr = if_expression_value(
+        vec![],
+        ast::PrimitiveValue::U8(1),
+        ast::PrimitiveValue::Bool(false),
+    );
+    assert!(t.state.if_expression(&if_expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ExpressionBranchTypeMismatch));
+    assert_eq!(t.state.errors[0].value, "expected u8, found bool");
+}
@@ -185,3 +185,71 @@ fn let_binding_value_found() {
         &val2
     );
 }
+
+#[test]
+fn let_binding_comparison_expression() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // let ok: bool = 1 < 2
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(1)),
+        operation: Some((
+            ast::ExpressionOperations::Less,
+            Box::new(ast::Expression {
+                expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(2)),
+                operation: None,
+            }),
+        )),
+    };
+    let let_binding = ast::LetBinding {
+        name: ast::ValueName::new(Ident::new("ok")),
+        mutable: false,
+        value_type: Some(ast::Type::Primitive(ast::PrimitiveTypes::Bool)),
+        value: Box::new(expr),
+    };
+    t.state.let_binding(&let_binding, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let val = block_state.borrow().get_value_name(&"ok".into()).unwrap();
+    assert_eq!(val.inner_type, Type::Primitive(PrimitiveTypes::Bool));
+}
+
+#[test]
+fn let_binding_array_value() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let array = |len: u8| ast::Expression {
+        expression_value: ast::ExpressionValue::ArrayValue(ast::ExpressionArrayValue {
+            values: (0..len)
+                .map(|value| ast::Expression {
+                    expression_value: ast::ExpressionValue::PrimitiveValue(
+                        ast::PrimitiveValue::U8(value),
+                    ),
+                    operation: None,
+                })
+                .collect(),
+            location: CodeLocation::new(1, 0),
+        }),
+        operation: None,
+    };
+    let let_binding = |len| ast::LetBinding {
+        name: ast::ValueName::new(Ident::new("arr")),
+        mutable: false,
+        value_type: Some(ast::Type::Array(
+            Box::new(ast::Type::Primitive(ast::PrimitiveTypes::U8)),
+            3,
+        )),
+        value: Box::new(array(len)),
+    };
+    // let arr: [u8; 3] = [0, 1, 2]
+    t.state.let_binding(&let_binding(3), &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let val = block_state.borrow().get_value_name(&"arr".into()).unwrap();
+    assert_eq!(
+        val.inner_type,
+        Type::Array(Box::new(Type::Primitive(PrimitiveTypes::U8)), 3)
+    );
+    // let arr: [u8; 3] = [0, 1]
+    t.state.let_binding(&let_binding(2), &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::WrongLetType));
+}
@@ -1,13 +1,17 @@
+use crate::utils::fixtures::{name_expr, value_expr};
 use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
 use semantic_analyzer::ast;
-use semantic_analyzer::ast::Ident;
-use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::ast::{CodeLocation, GetLocation, Ident};
+use semantic_analyzer::types::block_state::{BlockState, LoopLabels};
+use semantic_analyzer::types::condition::Condition;
 use semantic_analyzer::types::condition::LoopBodyStatement;
 use semantic_analyzer::types::error::StateErrorKind;
-use semantic_analyzer::types::expression::{ExpressionResult, ExpressionResultValue};
+use semantic_analyzer::types::expression::{
+    ExpressionOperations, ExpressionResult, ExpressionResultValue,
+};
 use semantic_analyzer::types::semantic::SemanticStackContext;
 use semantic_analyzer::types::types::{PrimitiveTypes, Type};
-use semantic_analyzer::types::{Function, PrimitiveValue, Value};
+use semantic_analyzer::types::{Function, LabelName, PrimitiveValue, Value};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -29,6 +33,7 @@ fn loop_transform() {
     };
     let binding = ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -52,15 +57,55 @@ fn loop_transform() {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::F32(1.2)),
         operation: None,
     };
+    let match_statement = ast::MatchStatement {
+        value: return_statement.clone(),
+        arms: vec![],
+    };
+    let while_statement = ast::WhileStatement {
+        label: None,
+        condition: if_statement.condition.clone(),
+        body: vec![loop_statement.clone()],
+    };
+    let for_statement = ast::ForStatement {
+        label: None,
+        name: ast::ValueName::new(Ident::new("i")),
+        start: return_statement.clone(),
+        end: return_statement.clone(),
+        step: None,
+        body: vec![loop_statement.clone()],
+    };
+    let loop_label = ast::LoopLabel::new(Ident::new("outer"));
+    let labeled_loop = ast::LabeledLoopStatement {
+        label: loop_label.clone(),
+        body: vec![loop_statement.clone()],
+    };
+    assert_eq!(labeled_loop.location(), CodeLocation::new(1, 0));
+    let let_tuple_binding = ast::LetTupleBinding {
+        names: vec![ast::LetTupleName {
+            name: ast::ValueName::new(Ident::new("y")),
+            mutable: true,
+        }],
+        value_type: None,
+        value: Box::new(return_statement.clone()),
+    };
     let loop_stmts = vec![
         ast::LoopBodyStatement::LetBinding(let_binding.clone()),
+        ast::LoopBodyStatement::LetTupleBinding(let_tuple_binding.clone()),
         ast::LoopBodyStatement::Binding(binding.clone()),
         ast::LoopBodyStatement::FunctionCall(fn_call.clone()),
-        ast::LoopBodyStatement::If(if_statement.clone()),
+        ast::LoopBodyStatement::If(Box::new(if_statement.clone())),
         ast::LoopBodyStatement::Loop(vec![loop_statement.clone()]),
+        ast::LoopBodyStatement::LabeledLoop(labeled_loop.clone()),
+        ast::LoopBodyStatement::While(Box::new(while_statement.clone())),
+        ast::LoopBodyStatement::For(Box::new(for_statement.clone())),
+        ast::LoopBodyStatement::Match(match_statement.clone()),
+        ast::LoopBodyStatement::Block(vec![loop_statement.clone()]),
         ast::LoopBodyStatement::Return(return_statement.clone()),
         ast::LoopBodyStatement::Break,
         ast::LoopBodyStatement::Continue,
+        ast::LoopBodyStatement::BreakTo(loop_label.clone()),
+        ast::LoopBodyStatement::ContinueTo(loop_label.clone()),
+        ast::LoopBodyStatement::BreakValue(return_statement.clone()),
     ];
     // For grcov
     let _ = format!("{loop_stmts:#?}");
@@ -70,10 +115,22 @@ fn loop_transform() {
         let _ = format!("{loop_stmt_into:#?}");
         match loop_stmt_into {
             LoopBodyStatement::LetBinding(val) => assert_eq!(val, let_binding.clone().into()),
+            LoopBodyStatement::LetTupleBinding(val) => {
+                assert_eq!(val, let_tuple_binding.clone().into());
+                assert_eq!(val.to_string(), "(mut y)");
+            }
             LoopBodyStatement::Binding(val) => assert_eq!(val, binding.clone().into()),
             LoopBodyStatement::FunctionCall(val) => assert_eq!(val, fn_call.clone().into()),
             LoopBodyStatement::If(val) => assert_eq!(val, if_statement.clone().into()),
             LoopBodyStatement::Loop(val) => assert_eq!(val, vec![loop_statement.clone().into()]),
+            LoopBodyStatement::LabeledLoop(val) => {
+                assert_eq!(val, labeled_loop.clone().into());
+                assert_eq!(val.label.to_string(), "outer");
+            }
+            LoopBodyStatement::While(val) => assert_eq!(val, while_statement.clone().into()),
+            LoopBodyStatement::For(val) => assert_eq!(val, for_statement.clone().into()),
+            LoopBodyStatement::Match(val) => assert_eq!(val, match_statement.clone().into()),
+            LoopBodyStatement::Block(val) => assert_eq!(val, vec![loop_statement.clone().into()]),
             LoopBodyStatement::Return(val) => assert_eq!(val, return_statement.clone().into()),
             LoopBodyStatement::Break => assert_eq!(
                 LoopBodyStatement::Break,
@@ -91,6 +148,12 @@ fn loop_transform() {
                 >::Continue
                     .into()
             ),
+            LoopBodyStatement::BreakTo(val) | LoopBodyStatement::ContinueTo(val) => {
+                assert_eq!(val, loop_label.clone().into());
+            }
+            LoopBodyStatement::BreakValue(val) => {
+                assert_eq!(val, return_statement.clone().into());
+            }
         }
     }
 }
@@ -124,6 +187,7 @@ fn loop_statements() {
     });
     let loop_body_binding = ast::LoopBodyStatement::Binding(ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -133,7 +197,7 @@ fn loop_statements() {
         name: ast::FunctionName::new(Ident::new("fn2")),
         parameters: vec![],
     });
-    let loop_body_if = ast::LoopBodyStatement::If(ast::IfStatement {
+    let loop_body_if = ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
         condition: ast::IfCondition::Single(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -146,7 +210,7 @@ fn loop_statements() {
         )]),
         else_statement: None,
         else_if_statement: None,
-    });
+    }));
     let loop_body_loop = ast::LoopBodyStatement::Loop(vec![ast::LoopBodyStatement::FunctionCall(
         ast::FunctionCall {
             name: ast::FunctionName::new(Ident::new("fn2")),
@@ -512,3 +576,840 @@ fn loop_statements_with_return_invocation() {
 
     assert!(t.is_empty_error());
 }
+
+#[test]
+fn while_statement() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let condition = ast::IfCondition::Single(ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
+        operation: None,
+    });
+    let if_continue = ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
+        condition: condition.clone(),
+        body: ast::IfBodyStatements::Loop(vec![ast::IfLoopBodyStatement::Continue]),
+        else_statement: None,
+        else_if_statement: None,
+    }));
+    let while_stmt = ast::WhileStatement {
+        label: None,
+        condition,
+        body: vec![if_continue, ast::LoopBodyStatement::Break],
+    };
+    t.state.while_statement(&while_stmt, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let condition_result = ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::Bool),
+        expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Bool(true)),
+    };
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(ctx.len(), 12);
+    assert_eq!(
+        ctx[0],
+        SemanticStackContext::JumpTo {
+            label: label("loop_begin")
+        }
+    );
+    assert_eq!(
+        ctx[1],
+        SemanticStackContext::SetLabel {
+            label: label("loop_begin")
+        }
+    );
+    // Condition checked at loop begin
+    assert_eq!(
+        ctx[2],
+        SemanticStackContext::IfConditionExpression {
+            expr_result: condition_result.clone(),
+            label_if_begin: label("loop_body"),
+            label_if_end: label("loop_end"),
+        }
+    );
+    assert_eq!(
+        ctx[3],
+        SemanticStackContext::SetLabel {
+            label: label("loop_body")
+        }
+    );
+    assert_eq!(
+        ctx[4],
+        SemanticStackContext::IfConditionExpression {
+            expr_result: condition_result,
+            label_if_begin: label("if_begin"),
+            label_if_end: label("if_end"),
+        }
+    );
+    assert_eq!(
+        ctx[5],
+        SemanticStackContext::SetLabel {
+            label: label("if_begin")
+        }
+    );
+    // Continue jumps to loop begin with condition check
+    assert_eq!(
+        ctx[6],
+        SemanticStackContext::JumpTo {
+            label: label("loop_begin")
+        }
+    );
+    assert_eq!(
+        ctx[7],
+        SemanticStackContext::JumpTo {
+            label: label("if_end")
+        }
+    );
+    assert_eq!(
+        ctx[8],
+        SemanticStackContext::SetLabel {
+            label: label("if_end")
+        }
+    );
+    assert_eq!(
+        ctx[9],
+        SemanticStackContext::JumpTo {
+            label: label("loop_end")
+        }
+    );
+    assert_eq!(
+        ctx[10],
+        SemanticStackContext::JumpTo {
+            label: label("loop_begin")
+        }
+    );
+    assert_eq!(
+        ctx[11],
+        SemanticStackContext::SetLabel {
+            label: label("loop_end")
+        }
+    );
+}
+
+#[test]
+fn while_statement_with_return_invocation() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let while_stmt = ast::WhileStatement {
+        label: None,
+        condition: ast::IfCondition::Single(ast::Expression {
+            expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
+            operation: None,
+        }),
+        body: vec![ast::LoopBodyStatement::Return(ast::Expression {
+            expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
+            operation: None,
+        })],
+    };
+    t.state.while_statement(&while_stmt, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Loop end is reachable when condition is false
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(ctx.len(), 6);
+    assert!(matches!(
+        ctx[4],
+        SemanticStackContext::JumpFunctionReturn { .. }
+    ));
+    assert_eq!(
+        ctx[5],
+        SemanticStackContext::SetLabel {
+            label: String::from("loop_end").into()
+        }
+    );
+}
+
+#[test]
+fn while_statement_condition_wrong_type() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let while_stmt = ast::WhileStatement {
+        label: None,
+        condition: ast::IfCondition::Single(ast::Expression {
+            expression_value: ast::ExpressionValue::<
+                CustomExpressionInstruction,
+                CustomExpression<CustomExpressionInstruction>,
+            >::PrimitiveValue(ast::PrimitiveValue::Ptr),
+            operation: None,
+        }),
+        body: vec![],
+    };
+    t.state.while_statement(&while_stmt, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::IfConditionWrongType));
+}
+
+#[test]
+fn for_statement() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let for_stmt = ast::ForStatement {
+        label: None,
+        name: ast::ValueName::new(Ident::new("i")),
+        start: value_expr(ast::PrimitiveValue::U32(0)),
+        end: value_expr(ast::PrimitiveValue::U32(10)),
+        step: None,
+        body: vec![ast::LoopBodyStatement::Continue],
+    };
+    assert_eq!(for_stmt.location(), CodeLocation::new(1, 0));
+    t.state.for_statement(&for_stmt, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    // Induction value doesn't leak to the outer state
+    assert!(block_state.borrow().values.is_empty());
+    let loop_state = block_state.borrow().children[0].clone();
+    let value = loop_state
+        .borrow()
+        .get_value_name(&String::from("i").into())
+        .unwrap();
+    assert!(!value.mutable);
+    // Loop counter is internal mutable value
+    let counter = Value {
+        inner_name: "i.0".into(),
+        inner_type: Type::Primitive(PrimitiveTypes::U32),
+        mutable: true,
+        alloca: false,
+        malloc: false,
+    };
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let u32_result = |val| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::U32),
+        expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U32(val)),
+    };
+    let register_result = |reg| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::U32),
+        expr_value: ExpressionResultValue::Register(reg),
+    };
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx,
+        vec![
+            SemanticStackContext::LetBinding {
+                let_decl: counter.clone(),
+                expr_result: u32_result(0),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("loop_begin")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_begin")
+            },
+            SemanticStackContext::ExpressionValue {
+                expression: counter.clone(),
+                register_number: 1,
+            },
+            SemanticStackContext::ConditionExpression {
+                left_result: register_result(1),
+                right_result: u32_result(10),
+                condition: Condition::Less,
+                register_number: 2,
+            },
+            SemanticStackContext::IfConditionLogic {
+                label_if_begin: label("loop_body"),
+                label_if_end: label("loop_end"),
+                result_register: 2,
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_body")
+            },
+            // Immutable induction value from the counter
+            SemanticStackContext::LetBinding {
+                let_decl: value,
+                expr_result: register_result(1),
+            },
+            // Continue jumps to the counter increment
+            SemanticStackContext::JumpTo {
+                label: label("loop_increment")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_increment")
+            },
+            SemanticStackContext::ExpressionValue {
+                expression: counter.clone(),
+                register_number: 3,
+            },
+            // Counter increment overflow check
+            SemanticStackContext::ConditionExpression {
+                left_result: register_result(3),
+                right_result: u32_result(u32::MAX - 1),
+                condition: Condition::Great,
+                register_number: 4,
+            },
+            SemanticStackContext::IfConditionLogic {
+                label_if_begin: label("loop_end"),
+                label_if_end: label("loop_step"),
+                result_register: 4,
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_step")
+            },
+            SemanticStackContext::ExpressionOperation {
+                operation: ExpressionOperations::Plus,
+                left_value: register_result(3),
+                right_value: u32_result(1),
+                register_number: 5,
+            },
+            SemanticStackContext::Binding {
+                val: counter,
+                expr_result: register_result(5),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("loop_begin")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_end")
+            },
+        ]
+    );
+}
+
+#[test]
+fn for_statement_step_and_return() {
+    let mut t = SemanticTest::new();
+    let mut step_state = BlockState::new(None);
+    let step = Value {
+        inner_name: "step".into(),
+        inner_type: Type::Primitive(PrimitiveTypes::U8),
+        mutable: false,
+        alloca: false,
+        malloc: false,
+    };
+    step_state
+        .values
+        .insert(String::from("step").into(), step.clone());
+    let block_state = Rc::new(RefCell::new(step_state));
+
+    // Non-constant step
+    let mut for_stmt = ast::ForStatement {
+        label: None,
+        name: ast::ValueName::new(Ident::new("i")),
+        start: value_expr(ast::PrimitiveValue::U8(0)),
+        end: value_expr(ast::PrimitiveValue::U8(255)),
+        step: Some(name_expr("step")),
+        body: vec![],
+    };
+    t.state.for_statement(&for_stmt, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ForRangeStepNotConstant));
+    t.clean_errors();
+
+    // Constant step: step limit calculated at compile time
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    for_stmt.step = Some(value_expr(ast::PrimitiveValue::U8(2)));
+    t.state.for_statement(&for_stmt, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = block_state.borrow().get_context().get();
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::ConditionExpression {
+            right_result: ExpressionResult {
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(253)),
+                ..
+            },
+            condition: Condition::Great,
+            ..
+        }
+    )));
+
+    // Body always returns: counter increment is unreachable
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    for_stmt.step = None;
+    for_stmt.body = vec![ast::LoopBodyStatement::Return(value_expr(
+        ast::PrimitiveValue::U8(1),
+    ))];
+    t.state.for_statement(&for_stmt, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = block_state.borrow().get_context().get();
+    assert!(!ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::SetLabel { label } if label.to_string() == "loop_increment"
+    )));
+    assert_eq!(
+        ctx.last(),
+        Some(&SemanticStackContext::SetLabel {
+            label: String::from("loop_end").into()
+        })
+    );
+}
+
+#[test]
+fn for_statement_constant_step() {
+    let mut t = SemanticTest::new();
+    // const STEP: i8 = 2;
+    t.state.constant(&ast::Constant {
+        name: ast::ConstantName::new(Ident::new("STEP")),
+        constant_type: ast::Type::Primitive(ast::PrimitiveTypes::I8),
+        constant_value: ast::ConstantExpression {
+            value: ast::ConstantValue::Value(ast::PrimitiveValue::I8(2)),
+            operation: None,
+        },
+    });
+    let negate = |expression| ast::Expression {
+        expression_value: ast::ExpressionValue::UnaryOperation(ast::ExpressionUnaryOperation {
+            operation: ast::UnaryOperations::Minus,
+            expression: Box::new(expression),
+        }),
+        operation: None,
+    };
+    let i8_result = |expr_value| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::I8),
+        expr_value,
+    };
+    let for_stmt = |start, end, step| ast::ForStatement {
+        label: None,
+        name: ast::ValueName::new(Ident::new("i")),
+        start: value_expr(ast::PrimitiveValue::I8(start)),
+        end: value_expr(ast::PrimitiveValue::I8(end)),
+        step: Some(step),
+        body: vec![],
+    };
+
+    // Named constant step: for i in 0..10 step STEP
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    t.state
+        .for_statement(&for_stmt(0, 10, name_expr("STEP")), &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = block_state.borrow().get_context().get();
+    // Step limit: i8::MAX - 2
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::ConditionExpression {
+            right_result: ExpressionResult {
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(125)),
+                ..
+            },
+            condition: Condition::Great,
+            ..
+        }
+    )));
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::ExpressionOperation {
+            operation: ExpressionOperations::Plus,
+            right_value,
+            ..
+        } if *right_value == i8_result(ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(2)))
+    )));
+
+    // Negated constant step counts down: for i in 10..0 step -STEP
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    t.state
+        .for_statement(&for_stmt(10, 0, negate(name_expr("STEP"))), &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = block_state.borrow().get_context().get();
+    // Counter compared with range end
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::ConditionExpression {
+            right_result,
+            condition: Condition::Great,
+            ..
+        } if *right_result == i8_result(ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(0)))
+    )));
+    // Step limit: i8::MIN + 2
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::ConditionExpression {
+            right_result,
+            condition: Condition::Less,
+            ..
+        } if *right_result == i8_result(ExpressionResultValue::PrimitiveValue(PrimitiveValue::I8(-126)))
+    )));
+
+    // Negated literal step
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    t.state.for_statement(
+        &for_stmt(10, 0, negate(value_expr(ast::PrimitiveValue::I8(1)))),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Step direction doesn't reach range end
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    t.state
+        .for_statement(&for_stmt(0, 10, negate(name_expr("STEP"))), &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ForRangeStepWrong));
+    assert_eq!(t.state.errors[0].value, "-2");
+}
+
+#[test]
+fn for_statement_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let for_stmt = |start, end, step: Option<ast::PrimitiveValue>| ast::ForStatement {
+        label: None,
+        name: ast::ValueName::new(Ident::new("i")),
+        start: value_expr(start),
+        end: value_expr(end),
+        step: step.map(value_expr),
+        body: vec![],
+    };
+    for data in [
+        for_stmt(
+            ast::PrimitiveValue::F32(0.),
+            ast::PrimitiveValue::F32(1.),
+            None,
+        ),
+        for_stmt(
+            ast::PrimitiveValue::I8(0),
+            ast::PrimitiveValue::I16(1),
+            None,
+        ),
+        for_stmt(
+            ast::PrimitiveValue::I8(0),
+            ast::PrimitiveValue::I8(1),
+            Some(ast::PrimitiveValue::U8(1)),
+        ),
+        for_stmt(
+            ast::PrimitiveValue::I8(0),
+            ast::PrimitiveValue::I8(1),
+            Some(ast::PrimitiveValue::I8(0)),
+        ),
+    ] {
+        t.state.for_statement(&data, &block_state);
+    }
+    assert!(t.check_errors_len(4), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::ForRangeWrongType));
+    assert!(t.check_error_index(1, StateErrorKind::ForRangeWrongType));
+    assert!(t.check_error_index(2, StateErrorKind::ForRangeWrongType));
+    assert!(t.check_error_index(3, StateErrorKind::ForRangeStepWrong));
+    assert_eq!(t.state.errors[0].value, "f32");
+    assert_eq!(t.state.errors[1].value, "expected i8, found i16");
+
+    // Induction value is immutable
+    let mut data = for_stmt(
+        ast::PrimitiveValue::I8(0),
+        ast::PrimitiveValue::I8(1),
+        Some(ast::PrimitiveValue::I8(2)),
+    );
+    data.body = vec![ast::LoopBodyStatement::Binding(ast::Binding {
+        name: ast::ValueName::new(Ident::new("i")),
+        path: vec![],
+        value: Box::new(value_expr(ast::PrimitiveValue::I8(1))),
+    })];
+    t.state.for_statement(&data, &block_state);
+    assert!(t.check_errors_len(5), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(4, StateErrorKind::ValueIsNotMutable));
+
+    // Loop body analyzed for invalid range
+    data.end = value_expr(ast::PrimitiveValue::I16(1));
+    t.state.for_statement(&data, &block_state);
+    assert!(t.check_errors_len(7), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error_index(5, StateErrorKind::ForRangeWrongType));
+    assert!(t.check_error_index(6, StateErrorKind::ValueIsNotMutable));
+    data.end = value_expr(ast::PrimitiveValue::I8(1));
+    data.step = Some(name_expr("x"));
+    t.state.for_statement(&data, &block_state);
+    assert!(t.check_errors_len(9), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error_index(7, StateErrorKind::ValueNotFound));
+    assert!(t.check_error_index(8, StateErrorKind::ValueIsNotMutable));
+}
+
+#[test]
+fn labeled_loop_statement() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let label_outer = ast::LoopLabel::new(Ident::new("outer"));
+    let if_continue = ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
+        condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+        body: ast::IfBodyStatements::Loop(vec![ast::IfLoopBodyStatement::ContinueTo(
+            label_outer.clone(),
+        )]),
+        else_statement: None,
+        else_if_statement: None,
+    }));
+    let labeled_loop = ast::LabeledLoopStatement {
+        label: label_outer.clone(),
+        body: vec![ast::LoopBodyStatement::Loop(vec![
+            if_continue,
+            ast::LoopBodyStatement::BreakTo(label_outer),
+        ])],
+    };
+    t.state.labeled_loop_statement(&labeled_loop, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let outer_state = block_state.borrow().children[0].clone();
+    let outer_labels = LoopLabels {
+        name: Some(String::from("outer").into()),
+        label_continue: label("loop_begin"),
+        label_break: label("loop_end"),
+        expression_location: None,
+        value_type: None,
+    };
+    assert_eq!(outer_state.borrow().loop_labels, Some(outer_labels.clone()));
+    let inner_state = outer_state.borrow().children[0].clone();
+    assert_eq!(
+        inner_state.borrow().loop_labels,
+        Some(LoopLabels {
+            name: None,
+            label_continue: label("loop_begin.0"),
+            label_break: label("loop_end.0"),
+            expression_location: None,
+            value_type: None,
+        })
+    );
+    assert_eq!(
+        inner_state
+            .borrow()
+            .get_loop_labels(&String::from("outer").into()),
+        Some(outer_labels)
+    );
+    let inner_ctx = inner_state.borrow().get_context().get();
+    assert_eq!(
+        inner_ctx[inner_ctx.len() - 3..],
+        [
+            // Break outer loop
+            SemanticStackContext::JumpTo {
+                label: label("loop_end")
+            },
+            SemanticStackContext::JumpTo {
+                label: label("loop_begin.0")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_end.0")
+            },
+        ]
+    );
+    // Continue outer loop from the inner loop if-body
+    let if_state = inner_state.borrow().children[0].clone();
+    assert!(if_state
+        .borrow()
+        .get_context()
+        .get()
+        .contains(&SemanticStackContext::JumpTo {
+            label: label("loop_begin")
+        }));
+}
+
+#[test]
+fn labeled_while_and_for_statement() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let label_while = ast::LoopLabel::new(Ident::new("w"));
+    let label_for = ast::LoopLabel::new(Ident::new("f"));
+    let while_stmt = ast::WhileStatement {
+        label: Some(label_while.clone()),
+        condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+        body: vec![ast::LoopBodyStatement::For(Box::new(ast::ForStatement {
+            label: Some(label_for.clone()),
+            name: ast::ValueName::new(Ident::new("i")),
+            start: value_expr(ast::PrimitiveValue::U8(0)),
+            end: value_expr(ast::PrimitiveValue::U8(3)),
+            step: None,
+            body: vec![ast::LoopBodyStatement::Loop(vec![
+                ast::LoopBodyStatement::ContinueTo(label_for),
+                ast::LoopBodyStatement::ContinueTo(label_while),
+            ])],
+        }))],
+    };
+    t.state.while_statement(&while_stmt, &block_state);
+    // Code after `continue` is forbidden
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ForbiddenCodeAfterContinueDeprecated));
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let while_state = block_state.borrow().children[0].clone();
+    let for_state = while_state.borrow().children[0].clone();
+    let inner_state = for_state.borrow().children[0].clone();
+    let inner_ctx = inner_state.borrow().get_context().get();
+    assert_eq!(
+        inner_ctx[2..4],
+        [
+            // Continue `for` loop jumps to induction value increment
+            SemanticStackContext::JumpTo {
+                label: label("loop_increment")
+            },
+            // Continue `while` loop jumps to condition check
+            SemanticStackContext::JumpTo {
+                label: label("loop_begin")
+            },
+        ]
+    );
+}
+
+#[test]
+fn labeled_loop_label_not_found() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let label_outer = ast::LoopLabel::new(Ident::new("outer"));
+    let labeled_loop = ast::LabeledLoopStatement {
+        label: label_outer.clone(),
+        body: vec![ast::LoopBodyStatement::Break],
+    };
+    t.state.labeled_loop_statement(&labeled_loop, &block_state);
+    // Label of the previous loop isn't visible
+    let loop_stmt = vec![ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
+        condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+        body: ast::IfBodyStatements::Loop(vec![ast::IfLoopBodyStatement::BreakTo(
+            label_outer.clone(),
+        )]),
+        else_statement: None,
+        else_if_statement: None,
+    }))];
+    t.state.loop_statement(&loop_stmt, &block_state);
+    let loop_stmt = vec![ast::LoopBodyStatement::ContinueTo(label_outer)];
+    t.state.loop_statement(&loop_stmt, &block_state);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::LoopLabelNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::LoopLabelNotFound));
+    assert_eq!(t.state.errors[0].value, "outer");
+}
+
+#[test]
+fn loop_expression() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let if_break = ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
+        condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+        body: ast::IfBodyStatements::Loop(vec![ast::IfLoopBodyStatement::BreakValue(value_expr(
+            ast::PrimitiveValue::U8(1),
+        ))]),
+        else_statement: None,
+        else_if_statement: None,
+    }));
+    let expr = ast::Expression {
+        expression_value: ast::ExpressionValue::Loop(ast::ExpressionLoop {
+            body: vec![
+                if_break,
+                ast::LoopBodyStatement::BreakValue(value_expr(ast::PrimitiveValue::U8(2))),
+            ],
+            location: CodeLocation::new(1, 1),
+        }),
+        operation: None,
+    };
+    let expr_into: semantic_analyzer::types::expression::Expression = expr.clone().into();
+    assert_eq!(expr_into.to_string(), "loop { .. }");
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::U8),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+
+    let label = |name: &str| -> LabelName { String::from(name).into() };
+    let u8_result = |val| ExpressionResult {
+        expr_type: Type::Primitive(PrimitiveTypes::U8),
+        expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(val)),
+    };
+    let loop_state = block_state.borrow().children[0].clone();
+    let loop_labels = loop_state.borrow().loop_labels.clone().unwrap();
+    assert!(loop_labels.expression_location.is_some());
+    assert_eq!(
+        loop_labels.value_type,
+        Some(Type::Primitive(PrimitiveTypes::U8))
+    );
+    // Break value from the if-body
+    let if_ctx = loop_state.borrow().children[0].borrow().get_context().get();
+    assert!(if_ctx.contains(&SemanticStackContext::MergeValue {
+        label: label("loop_end"),
+        expr_result: u8_result(1),
+    }));
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx[ctx.len() - 5..],
+        [
+            SemanticStackContext::MergeValue {
+                label: label("loop_end"),
+                expr_result: u8_result(2),
+            },
+            SemanticStackContext::JumpTo {
+                label: label("loop_end")
+            },
+            SemanticStackContext::JumpTo {
+                label: label("loop_begin")
+            },
+            SemanticStackContext::SetLabel {
+                label: label("loop_end")
+            },
+            SemanticStackContext::MergeResult {
+                label: label("loop_end"),
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                register_number: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn loop_expression_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+
+    let loop_expr = |body| ast::ExpressionLoop {
+        body,
+        location: CodeLocation::new(2, 4),
+    };
+    // Loop expression never yields value
+    let data = loop_expr(vec![ast::LoopBodyStatement::Continue]);
+    assert!(t.state.loop_expression(&data, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::LoopExpressionWithoutValue));
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(2, 4));
+    t.clean_errors();
+
+    // Break without location reported at the loop expression location
+    let data = loop_expr(vec![ast::LoopBodyStatement::Break]);
+    assert!(t.state.loop_expression(&data, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::LoopExpressionBreakWithoutValue));
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(2, 4));
+    t.clean_errors();
+
+    let if_break = |stmt| {
+        ast::LoopBodyStatement::If(Box::new(ast::IfStatement {
+            condition: ast::IfCondition::Single(value_expr(ast::PrimitiveValue::Bool(true))),
+            body: ast::IfBodyStatements::Loop(vec![stmt]),
+            else_statement: None,
+            else_if_statement: None,
+        }))
+    };
+    let break_value =
+        |value| ast::LoopBodyStatement::BreakValue(value_expr(ast::PrimitiveValue::U8(value)));
+    // Break without value
+    let data = loop_expr(vec![ast::LoopBodyStatement::BreakAt(CodeLocation::new(
+        3, 8,
+    ))]);
+    assert!(t.state.loop_expression(&data, &block_state).is_none());
+    let data = loop_expr(vec![
+        if_break(ast::IfLoopBodyStatement::BreakAt(CodeLocation::new(5, 12))),
+        break_value(2),
+    ]);
+    assert!(t.state.loop_expression(&data, &block_state).is_some());
+    // Break values type mismatch
+    let data = loop_expr(vec![
+        if_break(ast::IfLoopBodyStatement::BreakValue(value_expr(
+            ast::PrimitiveValue::Bool(true),
+        ))),
+        break_value(2),
+    ]);
+    assert!(t.state.loop_expression(&data, &block_state).is_some());
+    // Break value of the loop statement
+    t.state.loop_statement(&[break_value(1)], &block_state);
+    let data = loop_expr(vec![ast::LoopBodyStatement::Loop(vec![break_value(1)])]);
+    assert!(t.state.loop_expression(&data, &block_state).is_none());
+
+    assert!(t.check_errors_len(5), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::LoopExpressionBreakWithoutValue));
+    assert!(t.check_error_index(1, StateErrorKind::LoopExpressionBreakWithoutValue));
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(3, 8));
+    assert_eq!(t.state.errors[1].location.0, CodeLocation::new(5, 12));
+    assert!(t.check_error_index(2, StateErrorKind::ExpressionBranchTypeMismatch));
+    assert!(t.check_error_index(3, StateErrorKind::BreakValueInLoopStatement));
+    assert!(t.check_error_index(4, StateErrorKind::BreakValueInLoopStatement));
+    assert_eq!(t.state.errors[2].value, "expected bool, found u8");
+    assert_eq!(t.state.errors[3].value, "1");
+}
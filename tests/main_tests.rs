@@ -1,3 +1,4 @@
+use crate::utils::fixtures::{name_expr, TestExpression};
 use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
 use semantic_analyzer::ast::{self, GetName, Ident};
 use semantic_analyzer::types::error::StateErrorKind;
@@ -6,7 +7,7 @@ use semantic_analyzer::types::{
     expression::{ExpressionResult, ExpressionResultValue},
     semantic::SemanticStackContext,
     types::{PrimitiveTypes, Type},
-    Function, PrimitiveValue, Value,
+    Function, FunctionName, PrimitiveValue, Value,
 };
 
 mod utils;
@@ -46,6 +47,7 @@ fn main_run() {
     });
     let body_binding = ast::BodyStatement::Binding(ast::Binding {
         name: ast::ValueName::new(Ident::new("x")),
+        path: vec![],
         value: Box::new(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -55,7 +57,7 @@ fn main_run() {
         name: ast::FunctionName::new(Ident::new("fn2")),
         parameters: vec![],
     });
-    let body_if = ast::BodyStatement::If(ast::IfStatement {
+    let body_if = ast::BodyStatement::If(Box::new(ast::IfStatement {
         condition: ast::IfCondition::Single(ast::Expression {
             expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
             operation: None,
@@ -68,7 +70,7 @@ fn main_run() {
         )]),
         else_statement: None,
         else_if_statement: None,
-    });
+    }));
     let body_loop = ast::BodyStatement::Loop(vec![ast::LoopBodyStatement::FunctionCall(
         ast::FunctionCall {
             name: ast::FunctionName::new(Ident::new("fn2")),
@@ -466,12 +468,12 @@ fn if_return_from_function() {
         expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::Bool(true)),
         operation: None,
     };
-    let body_if = ast::BodyStatement::If(ast::IfStatement {
+    let body_if = ast::BodyStatement::If(Box::new(ast::IfStatement {
         condition: ast::IfCondition::Single(if_expr),
         body: ast::IfBodyStatements::If(vec![ast::IfBodyStatement::Return(if_expr_return)]),
         else_statement: None,
         else_if_statement: None,
-    });
+    }));
     let fn1 = ast::FunctionStatement::new(
         ast::FunctionName::new(Ident::new("fn1")),
         vec![],
@@ -730,3 +732,300 @@ fn function_args_duplication() {
     assert!(t.check_errors_len(1));
     assert!(t.check_error(StateErrorKind::FunctionArgumentNameDuplicated));
 }
+
+fn method_call_expr(
+    receiver: TestExpression,
+    name: &'static str,
+    parameters: Vec<TestExpression>,
+) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::MethodCall(ast::ExpressionMethodCall {
+            receiver: Box::new(receiver),
+            name: ast::FunctionName::new(Ident::new(name)),
+            parameters,
+        }),
+        operation: None,
+    }
+}
+
+/// Type: `struct Point { x: i32 }`
+fn point_type() -> ast::StructTypes<'static> {
+    ast::StructTypes {
+        name: Ident::new("Point"),
+        attributes: vec![ast::StructType {
+            attr_name: Ident::new("x"),
+            attr_type: ast::Type::Primitive(ast::PrimitiveTypes::I32),
+        }],
+    }
+}
+
+fn method(
+    name: &'static str,
+    parameters: Vec<(&'static str, ast::Type<'static>)>,
+    body: TestExpression,
+) -> ast::FunctionStatement<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new(name)),
+        parameters
+            .into_iter()
+            .map(|(name, parameter_type)| ast::FunctionParameter {
+                name: ast::ParameterName::new(Ident::new(name)),
+                parameter_type,
+            })
+            .collect(),
+        ast::Type::Primitive(ast::PrimitiveTypes::I32),
+        vec![ast::BodyStatement::Return(body)],
+    )
+}
+
+#[test]
+fn struct_methods_call() {
+    let mut t = SemanticTest::new();
+    let point_ty = ast::Type::Struct(point_type());
+    let i32_ty = ast::Type::Primitive(ast::PrimitiveTypes::I32);
+    // impl Point {
+    //   fn get_x(self: Point) -> i32 { return self.x }
+    //   fn add(self: Point, v: i32) -> i32 { return v }
+    // }
+    let get_x = method(
+        "get_x",
+        vec![("self", point_ty.clone())],
+        ast::Expression {
+            expression_value: ast::ExpressionValue::StructValue(ast::ExpressionStructValue {
+                name: ast::ValueName::new(Ident::new("self")),
+                attribute: ast::ValueName::new(Ident::new("x")),
+                path: vec![],
+            }),
+            operation: None,
+        },
+    );
+    let add = method(
+        "add",
+        vec![("self", point_ty.clone()), ("v", i32_ty)],
+        name_expr("v"),
+    );
+    let struct_impl = ast::StructImpl {
+        name: Ident::new("Point"),
+        methods: vec![get_x, add],
+    };
+    assert_eq!(struct_impl.name(), "Point");
+    // fn main(p: Point) -> i32 { return p.add(p.get_x()) }
+    let main_fn = method(
+        "main",
+        vec![("p", point_ty)],
+        method_call_expr(
+            name_expr("p"),
+            "add",
+            vec![method_call_expr(name_expr("p"), "get_x", vec![])],
+        ),
+    );
+    let main_stm: ast::Main<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    > = vec![
+        ast::MainStatement::Types(point_type()),
+        ast::MainStatement::Impl(struct_impl),
+        ast::MainStatement::Function(main_fn),
+    ];
+    // For grcov
+    let _ = format!("{main_stm:#?}");
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Methods are declared as functions and wired into struct type
+    let point = t
+        .state
+        .global
+        .types
+        .get(&"Point".to_string().into())
+        .unwrap();
+    let get_x_name: FunctionName = "Point.get_x".to_string().into();
+    let add_name: FunctionName = "Point.add".to_string().into();
+    assert_eq!(point.get_struct().unwrap().methods.len(), 2);
+    assert_eq!(
+        point.get_struct().unwrap().methods.get("get_x"),
+        Some(&get_x_name)
+    );
+    assert!(t.state.global.functions.contains_key(&get_x_name));
+    assert!(t.state.global.functions.contains_key(&add_name));
+    // Struct type declaration contains methods
+    let global_ctx = t.state.global.context.clone().get();
+    assert_eq!(
+        global_ctx[0],
+        SemanticStackContext::Types {
+            type_decl: point.get_struct().unwrap()
+        }
+    );
+
+    assert_eq!(t.state.context.len(), 3);
+    let ctx = t.state.context[2].borrow().get_context().get();
+    let calls = ctx
+        .iter()
+        .filter_map(|instr| match instr {
+            SemanticStackContext::Call { call, params, .. } => Some((call, params)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(calls.len(), 2);
+    // Receiver passed as first argument
+    let point_ty = point.clone();
+    assert_eq!(calls[0].0.inner_name, get_x_name);
+    assert_eq!(calls[0].1.len(), 1);
+    assert_eq!(calls[0].1[0].expr_type, point_ty);
+    assert_eq!(calls[1].0.inner_name, add_name);
+    assert_eq!(calls[1].1.len(), 2);
+    assert_eq!(calls[1].1[0].expr_type, point_ty);
+    assert_eq!(
+        calls[1].1[1].expr_type,
+        Type::Primitive(PrimitiveTypes::I32)
+    );
+}
+
+#[test]
+fn struct_methods_call_type_declared_later() {
+    let mut t = SemanticTest::new();
+    let point_ty = ast::Type::Struct(point_type());
+    // struct Line { start: Point }
+    let line_type = ast::StructTypes {
+        name: Ident::new("Line"),
+        attributes: vec![ast::StructType {
+            attr_name: Ident::new("start"),
+            attr_type: point_ty.clone(),
+        }],
+    };
+    // impl Point { fn get_x(self: Point) -> i32 { return 1 } }
+    let get_x = method(
+        "get_x",
+        vec![("self", point_ty)],
+        ast::Expression {
+            expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(1)),
+            operation: None,
+        },
+    );
+    // fn main(l: Line) -> i32 { return l.start.get_x() }
+    let main_fn = method(
+        "main",
+        vec![("l", ast::Type::Struct(line_type.clone()))],
+        method_call_expr(
+            ast::Expression {
+                expression_value: ast::ExpressionValue::StructValue(ast::ExpressionStructValue {
+                    name: ast::ValueName::new(Ident::new("l")),
+                    attribute: ast::ValueName::new(Ident::new("start")),
+                    path: vec![],
+                }),
+                operation: None,
+            },
+            "get_x",
+            vec![],
+        ),
+    );
+    let main_stm: ast::Main<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    > = vec![
+        ast::MainStatement::Types(line_type),
+        ast::MainStatement::Types(point_type()),
+        ast::MainStatement::Impl(ast::StructImpl {
+            name: Ident::new("Point"),
+            methods: vec![get_x],
+        }),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Attribute type resolved to declared type with methods
+    let point = &t.state.global.types[&"Point".to_string().into()];
+    let line = t.state.global.types[&"Line".to_string().into()]
+        .get_struct()
+        .unwrap();
+    assert_eq!(
+        line.attributes[&"start".to_string().into()].attr_type,
+        *point
+    );
+    assert_eq!(point.get_struct().unwrap().methods.len(), 1);
+    // Type declaration context pushed after types it refers to
+    let global_ctx = t.state.global.context.clone().get();
+    assert_eq!(
+        global_ctx[0],
+        SemanticStackContext::Types {
+            type_decl: point.get_struct().unwrap()
+        }
+    );
+    assert_eq!(
+        global_ctx[1],
+        SemanticStackContext::Types { type_decl: line }
+    );
+}
+
+#[test]
+fn struct_methods_errors() {
+    let mut t = SemanticTest::new();
+    let point_ty = ast::Type::Struct(point_type());
+    let i32_ty = ast::Type::Primitive(ast::PrimitiveTypes::I32);
+    let result = || ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(ast::PrimitiveValue::I32(1)),
+        operation: None,
+    };
+    let get_x = || method("get_x", vec![("self", point_ty.clone())], result());
+    let struct_impl = |name, methods| {
+        ast::MainStatement::Impl(ast::StructImpl {
+            name: Ident::new(name),
+            methods,
+        })
+    };
+    let main_fn = method(
+        "main",
+        vec![("p", point_ty.clone()), ("n", i32_ty.clone())],
+        method_call_expr(name_expr("p"), "get_x", vec![]),
+    );
+    let main_stm: ast::Main<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    > = vec![
+        ast::MainStatement::Types(point_type()),
+        // Type not found
+        struct_impl("Unknown", vec![get_x()]),
+        struct_impl(
+            "Point",
+            vec![
+                get_x(),
+                // Method already exist
+                get_x(),
+                // Receiver missing
+                method("no_receiver", vec![], result()),
+                // Wrong receiver type
+                method("wrong_receiver", vec![("self", i32_ty)], result()),
+            ],
+        ),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.check_errors_len(4), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error_index(0, StateErrorKind::TypeNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::MethodAlreadyExist));
+    assert!(t.check_error_index(2, StateErrorKind::MethodReceiverWrongType));
+    assert!(t.check_error_index(3, StateErrorKind::MethodReceiverWrongType));
+
+    // Method calls in function body
+    t.clean_errors();
+    let body_state = t.state.context.last().unwrap().clone();
+    for expr in [
+        // Method not found
+        method_call_expr(name_expr("p"), "get_y", vec![]),
+        // Receiver is not struct
+        method_call_expr(name_expr("n"), "get_x", vec![]),
+        // Wrong parameters count
+        method_call_expr(name_expr("p"), "get_x", vec![name_expr("n")]),
+    ] {
+        assert!(t.state.expression(&expr, &body_state).is_none());
+    }
+    assert!(t.check_errors_len(3), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error_index(0, StateErrorKind::MethodNotFound));
+    assert!(t.check_error_index(1, StateErrorKind::MethodNotFound));
+    assert!(t.check_error_index(2, StateErrorKind::MethodParametersCountWrong));
+}
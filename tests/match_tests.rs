@@ -0,0 +1,405 @@
+use crate::utils::fixtures::{set_enum_type, value_expr, TestExpression, TestMain};
+use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, Ident};
+use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::types::condition::{IfBodyStatements, MatchPattern, MatchStatement};
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::expression::{ExpressionResult, ExpressionResultValue};
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type};
+use semantic_analyzer::types::{LabelName, PrimitiveValue};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod utils;
+
+type TestMatchArm = ast::MatchArm<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+
+fn arm(pattern: ast::MatchPattern<'static>) -> TestMatchArm {
+    arm_at(pattern, CodeLocation::new(1, 0))
+}
+
+fn arm_at(pattern: ast::MatchPattern<'static>, location: CodeLocation) -> TestMatchArm {
+    ast::MatchArm {
+        pattern,
+        body: ast::IfBodyStatements::If(vec![]),
+        location,
+    }
+}
+
+fn match_statement(
+    value: TestExpression,
+    arms: Vec<TestMatchArm>,
+) -> ast::MatchStatement<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+> {
+    ast::MatchStatement { value, arms }
+}
+
+fn label(name: &str) -> LabelName {
+    String::from(name).into()
+}
+
+#[test]
+fn match_transform() {
+    let data = match_statement(
+        value_expr(ast::PrimitiveValue::U8(1)),
+        vec![
+            arm(ast::MatchPattern::Value(ast::PrimitiveValue::U8(1))),
+            arm(ast::MatchPattern::EnumVariant(Ident::new("Some"))),
+            arm(ast::MatchPattern::Wildcard),
+        ],
+    );
+    assert_eq!(data.location(), CodeLocation::new(1, 0));
+    let data_into: MatchStatement = data.into();
+    // For grcov
+    let _ = format!("{data_into:?}");
+    assert_eq!(data_into.value.to_string(), "1");
+    assert_eq!(data_into.arms.len(), 3);
+    assert_eq!(
+        data_into.arms[0].pattern,
+        MatchPattern::Value(PrimitiveValue::U8(1))
+    );
+    assert_eq!(data_into.arms[0].body, IfBodyStatements::If(vec![]));
+    let patterns = data_into
+        .arms
+        .iter()
+        .map(|arm| arm.pattern.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(patterns, vec!["1", "Some", "_"]);
+}
+
+#[test]
+fn match_statement_integer() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let return_arm = ast::MatchArm {
+        pattern: ast::MatchPattern::Value(ast::PrimitiveValue::I32(2)),
+        body: ast::IfBodyStatements::If(vec![ast::IfBodyStatement::Return(value_expr(
+            ast::PrimitiveValue::I32(5),
+        ))]),
+        location: CodeLocation::new(3, 4),
+    };
+    let data = match_statement(
+        value_expr(ast::PrimitiveValue::I32(1)),
+        vec![
+            arm(ast::MatchPattern::Value(ast::PrimitiveValue::I32(1))),
+            return_arm,
+            arm(ast::MatchPattern::Wildcard),
+        ],
+    );
+    t.state.match_statement(&data, &block_state, None);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(block_state.borrow().children.len(), 1);
+    let match_state = block_state.borrow().children[0].clone();
+    assert_eq!(match_state.borrow().children.len(), 3);
+
+    let ctx = block_state.borrow().get_context().clone().get();
+    assert_eq!(ctx.len(), 8);
+    assert_eq!(
+        ctx[0],
+        SemanticStackContext::Switch {
+            value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(1)),
+            },
+            cases: vec![
+                (PrimitiveValue::I32(1), label("match_arm")),
+                (PrimitiveValue::I32(2), label("match_arm.0")),
+            ],
+            label_default: label("match_arm.1"),
+        }
+    );
+    assert_eq!(
+        ctx[1],
+        SemanticStackContext::SetLabel {
+            label: label("match_arm")
+        }
+    );
+    assert_eq!(
+        ctx[2],
+        SemanticStackContext::JumpTo {
+            label: label("match_end")
+        }
+    );
+    assert_eq!(
+        ctx[3],
+        SemanticStackContext::SetLabel {
+            label: label("match_arm.0")
+        }
+    );
+    // Arm with return doesn't jump to match end
+    assert_eq!(
+        ctx[4],
+        SemanticStackContext::JumpFunctionReturn {
+            expr_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::I32),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::I32(5)),
+            }
+        }
+    );
+    assert_eq!(
+        ctx[5],
+        SemanticStackContext::SetLabel {
+            label: label("match_arm.1")
+        }
+    );
+    assert_eq!(
+        ctx[6],
+        SemanticStackContext::JumpTo {
+            label: label("match_end")
+        }
+    );
+    assert_eq!(
+        ctx[7],
+        SemanticStackContext::SetLabel {
+            label: label("match_end")
+        }
+    );
+}
+
+#[test]
+fn match_statement_enum() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let enum_type = set_enum_type(&mut t);
+    let value = ast::Expression {
+        expression_value: ast::ExpressionValue::EnumVariant(ast::ExpressionEnumVariant {
+            name: Ident::new("Opt"),
+            variant: Ident::new("None"),
+            payload: vec![],
+        }),
+        operation: None,
+    };
+    // Exhaustive match without wildcard
+    let data = match_statement(
+        value,
+        vec![
+            arm(ast::MatchPattern::EnumVariant(Ident::new("Some"))),
+            arm(ast::MatchPattern::EnumVariant(Ident::new("None"))),
+        ],
+    );
+    t.state.match_statement(&data, &block_state, None);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let ctx = block_state.borrow().get_context().clone().get();
+    assert_eq!(ctx.len(), 8);
+    assert_eq!(
+        ctx[1],
+        SemanticStackContext::EnumDiscriminant {
+            value: ExpressionResult {
+                expr_type: enum_type,
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            register_number: 2,
+        }
+    );
+    assert_eq!(
+        ctx[2],
+        SemanticStackContext::Switch {
+            value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::Register(2),
+            },
+            cases: vec![
+                (PrimitiveValue::U8(1), label("match_arm")),
+                (PrimitiveValue::U8(0), label("match_arm.0")),
+            ],
+            label_default: label("match_end"),
+        }
+    );
+}
+
+#[test]
+fn match_statement_exhaustive_without_wildcard() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let data = match_statement(
+        value_expr(ast::PrimitiveValue::Bool(true)),
+        vec![
+            arm(ast::MatchPattern::Value(ast::PrimitiveValue::Bool(false))),
+            arm(ast::MatchPattern::Value(ast::PrimitiveValue::Bool(true))),
+        ],
+    );
+    t.state.match_statement(&data, &block_state, None);
+    let data = match_statement(
+        value_expr(ast::PrimitiveValue::U8(1)),
+        (0..=u8::MAX)
+            .map(|val| arm(ast::MatchPattern::Value(ast::PrimitiveValue::U8(val))))
+            .collect(),
+    );
+    t.state.match_statement(&data, &block_state, None);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+}
+
+#[test]
+fn match_statement_in_loop() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let break_arm = ast::MatchArm {
+        pattern: ast::MatchPattern::Wildcard,
+        body: ast::IfBodyStatements::Loop(vec![ast::IfLoopBodyStatement::Break]),
+        location: CodeLocation::new(2, 4),
+    };
+    let data = match_statement(value_expr(ast::PrimitiveValue::Char('a')), vec![break_arm]);
+    t.state
+        .loop_statement(&[ast::LoopBodyStatement::Match(data)], &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let ctx = block_state.borrow().get_context().clone().get();
+    assert_eq!(ctx.len(), 9);
+    assert_eq!(
+        ctx[2],
+        SemanticStackContext::Switch {
+            value: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::Char),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Char('a')),
+            },
+            cases: vec![],
+            label_default: label("match_arm"),
+        }
+    );
+    assert_eq!(
+        ctx[4],
+        SemanticStackContext::JumpTo {
+            label: label("loop_end")
+        }
+    );
+}
+
+#[test]
+fn match_statement_return_status() {
+    // fn main() -> u8 { match true { true => return 1, false => return 2 } }
+    let mut t = SemanticTest::new();
+    let return_arm = |pattern, value| ast::MatchArm {
+        pattern: ast::MatchPattern::Value(ast::PrimitiveValue::Bool(pattern)),
+        body: ast::IfBodyStatements::If(vec![ast::IfBodyStatement::Return(value_expr(
+            ast::PrimitiveValue::U8(value),
+        ))]),
+        location: CodeLocation::new(1, 0),
+    };
+    let data = match_statement(
+        value_expr(ast::PrimitiveValue::Bool(true)),
+        vec![return_arm(true, 1), return_arm(false, 2)],
+    );
+    let main_stm: TestMain = vec![ast::MainStatement::Function(ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("main")),
+        vec![],
+        ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        vec![ast::BodyStatement::Match(data.clone())],
+    ))];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    // Function return is set by return label
+    assert_eq!(
+        t.state.context[0].borrow().get_context().get().last(),
+        Some(&SemanticStackContext::FunctionReturnLabel {
+            return_type: Type::Primitive(PrimitiveTypes::U8),
+        })
+    );
+
+    // Not all arms return
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    assert!(t.state.match_statement(&data, &block_state, None));
+    let mut data = data;
+    data.arms[1].body = ast::IfBodyStatements::If(vec![]);
+    assert!(!t.state.match_statement(&data, &block_state, None));
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Arm with loop body outside of the loop
+    data.arms[1].body = ast::IfBodyStatements::Loop(vec![]);
+    data.arms[1].location = CodeLocation::new(3, 8);
+    assert!(!t.state.match_statement(&data, &block_state, None));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::MatchLoopArmOutsideLoop));
+    assert_eq!(t.state.errors[0].value, "false");
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(3, 8));
+}
+
+#[test]
+fn match_statement_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    set_enum_type(&mut t);
+    let enum_value = || ast::Expression {
+        expression_value: ast::ExpressionValue::EnumVariant(ast::ExpressionEnumVariant {
+            name: Ident::new("Opt"),
+            variant: Ident::new("None"),
+            payload: vec![],
+        }),
+        operation: None,
+    };
+    let int = |val| ast::MatchPattern::Value(ast::PrimitiveValue::I32(val));
+    let variant = |name| ast::MatchPattern::EnumVariant(Ident::new(name));
+    let i32_value = || value_expr(ast::PrimitiveValue::I32(1));
+    for data in [
+        match_statement(value_expr(ast::PrimitiveValue::F32(1.)), vec![]),
+        match_statement(
+            i32_value(),
+            vec![arm(ast::MatchPattern::Value(ast::PrimitiveValue::U8(1)))],
+        ),
+        match_statement(i32_value(), vec![arm(variant("None"))]),
+        match_statement(
+            enum_value(),
+            vec![arm_at(variant("Other"), CodeLocation::new(2, 4))],
+        ),
+        match_statement(
+            i32_value(),
+            vec![arm(int(1)), arm_at(int(1), CodeLocation::new(3, 4))],
+        ),
+        match_statement(
+            i32_value(),
+            vec![
+                arm(ast::MatchPattern::Wildcard),
+                arm_at(int(1), CodeLocation::new(4, 4)),
+            ],
+        ),
+        match_statement(i32_value(), vec![arm(int(1))]),
+        match_statement(enum_value(), vec![]),
+        match_statement(
+            value_expr(ast::PrimitiveValue::Bool(true)),
+            vec![arm(ast::MatchPattern::Value(ast::PrimitiveValue::Bool(
+                true,
+            )))],
+        ),
+        match_statement(
+            i32_value(),
+            vec![ast::MatchArm {
+                pattern: ast::MatchPattern::Wildcard,
+                body: ast::IfBodyStatements::Loop(vec![]),
+                location: CodeLocation::new(5, 4),
+            }],
+        ),
+    ] {
+        t.state.match_statement(&data, &block_state, None);
+    }
+    assert!(t.check_errors_len(10), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::MatchValueWrongType));
+    assert!(t.check_error_index(1, StateErrorKind::MatchPatternWrongType));
+    assert!(t.check_error_index(2, StateErrorKind::MatchPatternWrongType));
+    assert!(t.check_error_index(3, StateErrorKind::EnumVariantNotFound));
+    assert!(t.check_error_index(4, StateErrorKind::MatchArmDuplicate));
+    assert!(t.check_error_index(5, StateErrorKind::MatchArmUnreachable));
+    assert!(t.check_error_index(6, StateErrorKind::MatchNotExhaustive));
+    assert!(t.check_error_index(7, StateErrorKind::MatchNotExhaustive));
+    assert!(t.check_error_index(8, StateErrorKind::MatchNotExhaustive));
+    assert!(t.check_error_index(9, StateErrorKind::MatchLoopArmOutsideLoop));
+    assert_eq!(t.state.errors[1].value, "expected i32, found u8");
+    assert_eq!(t.state.errors[3].value, "Opt::Other");
+    assert_eq!(t.state.errors[4].value, "1");
+    // Arm errors are reported at the arm pattern location
+    assert_eq!(t.state.errors[3].location.0, CodeLocation::new(2, 4));
+    assert_eq!(t.state.errors[4].location.0, CodeLocation::new(3, 4));
+    assert_eq!(t.state.errors[5].location.0, CodeLocation::new(4, 4));
+    assert_eq!(t.state.errors[6].value, "_");
+    assert_eq!(t.state.errors[7].value, "Opt::None, Opt::Some");
+    assert_eq!(t.state.errors[8].value, "false");
+    assert_eq!(t.state.errors[9].value, "_");
+    assert_eq!(t.state.errors[9].location.0, CodeLocation::new(5, 4));
+}
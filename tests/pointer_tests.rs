@@ -0,0 +1,465 @@
+use crate::utils::fixtures::{let_binding, name_expr, param, u8_type, value_expr, TestExpression};
+use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, GetName, Ident};
+use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::expression::{
+    Expression, ExpressionAddressOf, ExpressionOperations, ExpressionResult, ExpressionResultValue,
+    ExpressionValue,
+};
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type, TypeClass};
+use semantic_analyzer::types::{PlaceAccess, PlaceIndex, PrimitiveValue};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod utils;
+
+type TestBinding = ast::Binding<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+
+/// Address-of expression: `&name` or `&mut name`
+fn address_of_expr(name: &'static str, mutable: bool) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::AddressOf(ast::ExpressionAddressOf {
+            value: ast::ValueName::new(Ident::new(name)),
+            mutable,
+        }),
+        operation: None,
+    }
+}
+
+/// Dereference expression: `*expression`
+fn deref_expr(expression: TestExpression) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::Dereference(ast::ExpressionDereference {
+            expression: Box::new(expression),
+            location: CodeLocation::new(2, 0),
+        }),
+        operation: None,
+    }
+}
+
+/// Binding through dereference: `*name = value`
+fn deref_binding(name: &'static str, value: TestExpression) -> TestBinding {
+    ast::Binding {
+        name: ast::ValueName::new(Ident::new(name)),
+        path: vec![ast::PlaceAccess::Deref],
+        value: Box::new(value),
+    }
+}
+
+#[test]
+fn pointer_types() {
+    let ty = ast::Type::Pointer(Box::new(u8_type()));
+    assert_eq!(ty.name(), "*u8");
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into.to_string(), "*u8");
+    assert_eq!(
+        ty_into.get_pointee(),
+        Some(Type::Primitive(PrimitiveTypes::U8))
+    );
+    assert!(ty_into.is_mutable_pointer());
+    assert_eq!(ty_into.type_class(), TypeClass::Pointer);
+
+    let ty = ast::Type::Reference(Box::new(u8_type()));
+    assert_eq!(ty.name(), "&u8");
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into.to_string(), "&u8");
+    assert!(!ty_into.is_mutable_pointer());
+    assert_eq!(ty_into.type_class(), TypeClass::Pointer);
+
+    let ty = ast::Type::MutableReference(Box::new(ast::Type::Reference(Box::new(u8_type()))));
+    assert_eq!(ty.name(), "&mut &u8");
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into.to_string(), "&mut &u8");
+    assert!(ty_into.is_mutable_pointer());
+    assert_eq!(
+        ty_into.get_pointee(),
+        Some(Type::Reference(Box::new(Type::Primitive(
+            PrimitiveTypes::U8
+        ))))
+    );
+    assert_eq!(Type::Primitive(PrimitiveTypes::U8).get_pointee(), None);
+    assert!(!Type::Primitive(PrimitiveTypes::Ptr).is_mutable_pointer());
+}
+
+#[test]
+fn pointer_expression_transform() {
+    let expr = address_of_expr("x", true);
+    let ast::ExpressionValue::AddressOf(address_of) = &expr.expression_value else {
+        panic!("expected address-of expression");
+    };
+    assert_eq!(address_of.location(), CodeLocation::new(1, 0));
+    let expr_into: Expression = expr.into();
+    assert_eq!(expr_into.to_string(), "&mut x");
+    assert_eq!(
+        expr_into.expression_value,
+        ExpressionValue::AddressOf(ExpressionAddressOf {
+            value: "x".into(),
+            mutable: true,
+        })
+    );
+    let expr_into: Expression = address_of_expr("x", false).into();
+    assert_eq!(expr_into.to_string(), "&x");
+
+    let expr = deref_expr(name_expr("p"));
+    let ast::ExpressionValue::Dereference(deref) = &expr.expression_value else {
+        panic!("expected dereference expression");
+    };
+    assert_eq!(deref.location(), CodeLocation::new(2, 0));
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "*p");
+
+    let access: PlaceAccess = ast::PlaceAccess::<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    >::Deref
+        .into();
+    assert_eq!(access, PlaceAccess::Deref);
+    assert_eq!(access.to_string(), ".*");
+}
+
+#[test]
+fn address_of_value() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", true, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+
+    let res = t
+        .state
+        .expression(&address_of_expr("x", true), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::MutableReference(Box::new(Type::Primitive(PrimitiveTypes::U8))),
+            expr_value: ExpressionResultValue::Register(1),
+        }
+    );
+    let x = block_state.borrow().get_value_name(&"x".into()).unwrap();
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx[1],
+        SemanticStackContext::AddressOf {
+            value: x,
+            register_number: 1,
+        }
+    );
+
+    let res = t
+        .state
+        .expression(&address_of_expr("x", false), &block_state)
+        .unwrap();
+    assert_eq!(
+        res.expr_type,
+        Type::Reference(Box::new(Type::Primitive(PrimitiveTypes::U8)))
+    );
+}
+
+#[test]
+fn address_of_value_alloca() {
+    let mut t = SemanticTest::new();
+    // fn fn1(a: u8) -> u8 { let mut x = 1; let y = 2; let p = &mut x; let q = &a; *p }
+    let fn1 = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("fn1")),
+        vec![param("a", u8_type())],
+        u8_type(),
+        vec![
+            ast::BodyStatement::LetBinding(let_binding(
+                "x",
+                true,
+                None,
+                value_expr(ast::PrimitiveValue::U8(1)),
+            )),
+            ast::BodyStatement::LetBinding(let_binding(
+                "y",
+                false,
+                None,
+                value_expr(ast::PrimitiveValue::U8(2)),
+            )),
+            ast::BodyStatement::LetBinding(let_binding(
+                "p",
+                false,
+                None,
+                address_of_expr("x", true),
+            )),
+            ast::BodyStatement::LetBinding(let_binding(
+                "q",
+                false,
+                None,
+                address_of_expr("a", false),
+            )),
+            ast::BodyStatement::Expression(deref_expr(name_expr("p"))),
+        ],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    // Values, that address is taken, are allocated in memory since
+    // their declaration
+    let ctx = t.state.context[0].borrow().get_context().get();
+    let SemanticStackContext::FunctionArg { value: a, .. } = &ctx[0] else {
+        panic!("expected function argument");
+    };
+    assert!(a.alloca);
+    let SemanticStackContext::LetBinding { let_decl: x, .. } = &ctx[1] else {
+        panic!("expected let binding");
+    };
+    assert!(x.alloca);
+    let SemanticStackContext::LetBinding { let_decl: y, .. } = &ctx[2] else {
+        panic!("expected let binding");
+    };
+    assert!(!y.alloca);
+    assert_eq!(
+        ctx[3],
+        SemanticStackContext::AddressOf {
+            value: x.clone(),
+            register_number: 1,
+        }
+    );
+}
+
+#[test]
+fn address_of_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", false, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    // Immutable reference for immutable value is valid
+    assert!(t
+        .state
+        .expression(&address_of_expr("x", false), &block_state)
+        .is_some());
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let res = t
+        .state
+        .expression(&address_of_expr("x", true), &block_state);
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueIsNotMutable));
+    t.clean_errors();
+
+    let res = t
+        .state
+        .expression(&address_of_expr("y", false), &block_state);
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueNotFound));
+}
+
+#[test]
+fn dereference_value() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", false, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    t.state.let_binding(
+        &let_binding("p", false, None, address_of_expr("x", false)),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let res = t
+        .state
+        .expression(&deref_expr(name_expr("p")), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::U8),
+            expr_value: ExpressionResultValue::Register(3),
+        }
+    );
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx.last().unwrap(),
+        &SemanticStackContext::Load {
+            value_type: Type::Primitive(PrimitiveTypes::U8),
+            address_register: 2,
+            register_number: 3,
+        }
+    );
+
+    // Dereference of non-pointer value
+    let res = t
+        .state
+        .expression(&deref_expr(name_expr("x")), &block_state);
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::DereferenceNotPointer));
+    t.clean_errors();
+
+    let res = t.state.expression(
+        &deref_expr(value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::DereferenceNotPointer));
+}
+
+#[test]
+fn binding_through_pointer() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", true, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    // Immutable value of mutable reference
+    t.state.let_binding(
+        &let_binding("p", false, None, address_of_expr("x", true)),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    t.state.binding(
+        &deref_binding("p", value_expr(ast::PrimitiveValue::U8(2))),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let p = block_state.borrow().get_value_name(&"p".into()).unwrap();
+    let ctx = block_state.borrow().get_context().get();
+    assert_eq!(
+        ctx.last().unwrap(),
+        &SemanticStackContext::Store {
+            val: p,
+            path: vec![PlaceIndex::Deref],
+            expr_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::U8),
+                expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(2)),
+            },
+        }
+    );
+
+    // Reference isn't raw pointer
+    t.state.let_binding(
+        &let_binding(
+            "r",
+            false,
+            Some(ast::Type::Pointer(Box::new(u8_type()))),
+            address_of_expr("x", true),
+        ),
+        &block_state,
+    );
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::WrongLetType));
+    t.clean_errors();
+
+    // Wrong value type
+    t.state.binding(
+        &deref_binding("p", value_expr(ast::PrimitiveValue::U16(2))),
+        &block_state,
+    );
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::WrongBindingType));
+}
+
+#[test]
+fn binding_through_pointer_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", true, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    t.state.let_binding(
+        &let_binding("p", false, None, address_of_expr("x", false)),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    // Store through immutable reference
+    t.state.binding(
+        &deref_binding("p", value_expr(ast::PrimitiveValue::U8(2))),
+        &block_state,
+    );
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ReferenceNotMutable));
+    t.clean_errors();
+
+    // Dereference of non-pointer value
+    t.state.binding(
+        &deref_binding("x", value_expr(ast::PrimitiveValue::U8(2))),
+        &block_state,
+    );
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::DereferenceNotPointer));
+}
+
+#[test]
+fn pointer_comparison() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    t.state.let_binding(
+        &let_binding("x", false, None, value_expr(ast::PrimitiveValue::U8(1))),
+        &block_state,
+    );
+    t.state.let_binding(
+        &let_binding("p", false, None, address_of_expr("x", false)),
+        &block_state,
+    );
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let compare = |operation| ast::Expression {
+        expression_value: ast::ExpressionValue::ValueName(ast::ValueName::new(Ident::new("p"))),
+        operation: Some((operation, Box::new(address_of_expr("x", false)))),
+    };
+    let res = t
+        .state
+        .expression(&compare(ast::ExpressionOperations::Eq), &block_state)
+        .unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(res.expr_type, Type::Primitive(PrimitiveTypes::Bool));
+    assert!(
+        ExpressionOperations::NotEq.is_valid_for_type(&Type::Pointer(Box::new(Type::Primitive(
+            PrimitiveTypes::U8
+        ))))
+    );
+
+    let res = t
+        .state
+        .expression(&compare(ast::ExpressionOperations::Less), &block_state);
+    assert!(res.is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::InvalidOperationForType));
+}
+
+#[test]
+fn binding_through_raw_pointer() {
+    let mut t = SemanticTest::new();
+    // fn fn1(r: *u8) -> u8 { *r = 2; *r }
+    let fn1 = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("fn1")),
+        vec![param("r", ast::Type::Pointer(Box::new(u8_type())))],
+        u8_type(),
+        vec![
+            ast::BodyStatement::Binding(deref_binding("r", value_expr(ast::PrimitiveValue::U8(2)))),
+            ast::BodyStatement::Expression(deref_expr(name_expr("r"))),
+        ],
+    );
+    t.state.function_declaration(&fn1);
+    t.state.function_body(&fn1);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let ctx = t.state.context[0].borrow().get_context().get();
+    assert!(ctx.iter().any(|ctx| matches!(
+        ctx,
+        SemanticStackContext::Store { path, .. } if path == &vec![PlaceIndex::Deref]
+    )));
+}
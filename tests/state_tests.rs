@@ -3,15 +3,15 @@ use semantic_analyzer::ast::{self, Ident};
 use semantic_analyzer::semantic::State;
 use semantic_analyzer::types::condition::{Condition, LogicCondition};
 use semantic_analyzer::types::expression::{
-    ExpressionOperations, ExpressionResult, ExpressionResultValue,
+    ExpressionOperations, ExpressionResult, ExpressionResultValue, UnaryOperations,
 };
 use semantic_analyzer::types::semantic::{ExtendedSemanticContext, SemanticContext};
 use semantic_analyzer::types::{
     block_state::BlockState,
     semantic::SemanticStack,
-    types::{PrimitiveTypes, Type},
+    types::{CastKind, PrimitiveTypes, Type},
     Constant, ConstantExpression, ConstantValue, Function, FunctionParameter, InnerValueName,
-    LabelName, Value, ValueName,
+    LabelName, PlaceIndex, PrimitiveValue, Value, ValueName,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -273,13 +273,29 @@ fn block_state_instructions_with_parent() {
         },
     };
     bst.expression_const(expr_const, 1);
-    bst.expression_struct_value(val.clone(), 1, 1);
+    bst.expression_struct_value(val.clone(), vec![1, 0], 1);
     let expr_op = ExpressionOperations::Plus;
     let expr_res = ExpressionResult {
         expr_type: Type::Primitive(PrimitiveTypes::Ptr),
         expr_value: ExpressionResultValue::Register(1),
     };
     bst.expression_operation(expr_op, expr_res.clone(), expr_res.clone(), 1);
+    bst.unary_operation(UnaryOperations::Minus, expr_res.clone(), 1);
+    bst.cast(
+        expr_res.clone(),
+        PrimitiveTypes::U8,
+        PrimitiveTypes::U16,
+        CastKind::IntegerExtend,
+        1,
+    );
+    let array_type = Type::Array(Box::new(Type::Primitive(PrimitiveTypes::Ptr)), 1);
+    bst.expression_array_value(vec![expr_res.clone()], array_type, 1);
+    bst.expression_struct_literal(Type::Primitive(PrimitiveTypes::Ptr), vec![], 1);
+    bst.expression_enum_variant(Type::Primitive(PrimitiveTypes::Ptr), 0, vec![], 1);
+    bst.enum_discriminant(expr_res.clone(), 1);
+    bst.array_element_address(expr_res.clone(), expr_res.clone(), 1);
+    bst.load(Type::Primitive(PrimitiveTypes::Ptr), 1, 2);
+    bst.environment_field(0, Type::Primitive(PrimitiveTypes::Ptr), 1);
     let call_fn = Function {
         inner_name: String::from("fn1").into(),
         inner_type: Type::Primitive(PrimitiveTypes::Ptr),
@@ -288,6 +304,8 @@ fn block_state_instructions_with_parent() {
     bst.call(call_fn, vec![], 1);
     bst.let_binding(val.clone(), expr_res.clone());
     bst.binding(val.clone(), expr_res.clone());
+    bst.store(val.clone(), vec![PlaceIndex::Field(0)], expr_res.clone());
+    bst.store_address(1, expr_res.clone());
     bst.expression_function_return(expr_res.clone());
     bst.expression_function_return_with_label(expr_res.clone());
     let label: LabelName = String::from("label").into();
@@ -296,10 +314,18 @@ fn block_state_instructions_with_parent() {
     bst.if_condition_expression(expr_res.clone(), label.clone(), label.clone());
     let condition = Condition::Great;
     bst.condition_expression(expr_res.clone(), expr_res.clone(), condition, 1);
-    bst.jump_function_return(expr_res);
+    bst.jump_function_return(expr_res.clone());
+    bst.function_return_label(Type::Primitive(PrimitiveTypes::Bool));
     let logic_condition = LogicCondition::And;
     bst.logic_condition(logic_condition, 1, 2, 3);
-    bst.if_condition_logic(label.clone(), label, 1);
+    bst.if_condition_logic(label.clone(), label.clone(), 1);
+    bst.switch(
+        expr_res.clone(),
+        vec![(PrimitiveValue::U8(1), label.clone())],
+        label.clone(),
+    );
+    bst.merge_value(label.clone(), expr_res);
+    bst.merge_result(label, Type::Primitive(PrimitiveTypes::U8), 1);
     let func_arg = FunctionParameter {
         name: ast::ParameterName::new(Ident::new("x")).into(),
         parameter_type: Type::Primitive(PrimitiveTypes::Ptr),
@@ -310,5 +336,5 @@ fn block_state_instructions_with_parent() {
     bst.extended_expression(&custom_instr);
 
     let parent_ctx = parent_bst.borrow().get_context().get();
-    assert_eq!(parent_ctx.len(), 18);
+    assert_eq!(parent_ctx.len(), 33);
 }
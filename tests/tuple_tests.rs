@@ -0,0 +1,391 @@
+use crate::utils::fixtures::{name_expr, value_expr, TestExpression};
+use crate::utils::{CustomExpression, CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, GetName, Ident};
+use semantic_analyzer::types::block_state::BlockState;
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::expression::{
+    Expression, ExpressionResult, ExpressionResultValue, ExpressionTupleElement, ExpressionValue,
+};
+use semantic_analyzer::types::semantic::SemanticStackContext;
+use semantic_analyzer::types::types::{PrimitiveTypes, Type, TypeClass};
+use semantic_analyzer::types::{LetTupleBinding, PrimitiveValue};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod utils;
+
+type TestLetTupleBinding = ast::LetTupleBinding<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+
+/// Tuple literal expression: `(values)`
+fn tuple_expr(values: Vec<TestExpression>) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::TupleValue(ast::ExpressionTupleValue {
+            values,
+            location: CodeLocation::new(1, 0),
+        }),
+        operation: None,
+    }
+}
+
+/// Tuple element expression: `tuple.index`
+fn tuple_element_expr(tuple: TestExpression, index: u32) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::TupleElement(ast::ExpressionTupleElement {
+            tuple: Box::new(tuple),
+            index,
+            location: CodeLocation::new(2, 3),
+        }),
+        operation: None,
+    }
+}
+
+/// Tuple pattern name: `[mut] name`
+fn tuple_name(name: &'static str, mutable: bool) -> ast::LetTupleName<'static> {
+    ast::LetTupleName {
+        name: ast::ValueName::new(Ident::new(name)),
+        mutable,
+    }
+}
+
+fn let_tuple_binding(
+    names: Vec<ast::LetTupleName<'static>>,
+    value_type: Option<ast::Type<'static>>,
+    value: TestExpression,
+) -> TestLetTupleBinding {
+    ast::LetTupleBinding {
+        names,
+        value_type,
+        value: Box::new(value),
+    }
+}
+
+fn pair_type() -> Type {
+    Type::Tuple(vec![
+        Type::Primitive(PrimitiveTypes::U8),
+        Type::Primitive(PrimitiveTypes::Bool),
+    ])
+}
+
+#[test]
+fn tuple_transform() {
+    let ty = ast::Type::Tuple(vec![
+        ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+    ]);
+    assert_eq!(ty.name(), "(u8, bool)");
+    let ty_into: Type = ty.into();
+    assert_eq!(ty_into, pair_type());
+    assert_eq!(ty_into.to_string(), "(u8, bool)");
+    assert_eq!(ty_into.type_class(), TypeClass::Aggregate);
+    assert_eq!(ty_into.get_tuple().unwrap().len(), 2);
+    assert!(Type::Primitive(PrimitiveTypes::U8).get_tuple().is_none());
+
+    let tuple = tuple_expr(vec![
+        value_expr(ast::PrimitiveValue::U8(1)),
+        value_expr(ast::PrimitiveValue::Bool(true)),
+    ]);
+    let tuple_into: Expression = tuple.clone().into();
+    assert_eq!(tuple_into.to_string(), "(1, true)");
+
+    let expr = tuple_element_expr(tuple, 1);
+    let ast::ExpressionValue::TupleElement(element) = &expr.expression_value else {
+        panic!("Tuple element expected");
+    };
+    assert_eq!(element.location(), CodeLocation::new(2, 3));
+    let expr_into: Expression = expr.into();
+    // For grcov
+    let _ = format!("{expr_into:?}");
+    assert_eq!(expr_into.to_string(), "(1, true).1");
+    let ExpressionValue::TupleElement(ExpressionTupleElement { index, .. }) =
+        expr_into.expression_value
+    else {
+        panic!("Tuple element expected");
+    };
+    assert_eq!(index, 1);
+
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("b", true)],
+        None,
+        name_expr("x"),
+    );
+    assert_eq!(data.location(), CodeLocation::new(1, 0));
+    let data_into: LetTupleBinding = data.into();
+    assert_eq!(data_into.to_string(), "(a, mut b)");
+    assert_eq!(data_into.names[0].name, "a".into());
+    assert!(data_into.names[1].mutable);
+}
+
+#[test]
+fn tuple_value_and_element() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // Expression: (1, true).1
+    let expr = tuple_element_expr(
+        tuple_expr(vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::Bool(true)),
+        ]),
+        1,
+    );
+    let res = t.state.expression(&expr, &block_state).unwrap();
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(
+        res,
+        ExpressionResult {
+            expr_type: Type::Primitive(PrimitiveTypes::Bool),
+            expr_value: ExpressionResultValue::Register(2),
+        }
+    );
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 2);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::ExpressionTupleValue {
+            values: vec![
+                ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::U8),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::U8(1)),
+                },
+                ExpressionResult {
+                    expr_type: Type::Primitive(PrimitiveTypes::Bool),
+                    expr_value: ExpressionResultValue::PrimitiveValue(PrimitiveValue::Bool(true)),
+                },
+            ],
+            tuple_type: pair_type(),
+            register_number: 1,
+        }
+    );
+    assert_eq!(
+        state[1],
+        SemanticStackContext::TupleElement {
+            tuple: ExpressionResult {
+                expr_type: pair_type(),
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            index: 1,
+            element_type: Type::Primitive(PrimitiveTypes::Bool),
+            register_number: 2,
+        }
+    );
+}
+
+#[test]
+fn tuple_element_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // Expression: (1, true).2
+    let expr = tuple_element_expr(
+        tuple_expr(vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::Bool(true)),
+        ]),
+        2,
+    );
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TupleElementIndexOutOfBounds));
+    assert_eq!(t.state.errors[0].location.0, CodeLocation::new(2, 3));
+    t.clean_errors();
+
+    // Expression: 1.0
+    let expr = tuple_element_expr(value_expr(ast::PrimitiveValue::U8(1)), 0);
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueNotTuple));
+    t.clean_errors();
+
+    // Expression: (x, 1)
+    let expr = tuple_expr(vec![name_expr("x"), value_expr(ast::PrimitiveValue::U8(1))]);
+    assert!(t.state.expression(&expr, &block_state).is_none());
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueNotFound));
+}
+
+#[test]
+fn let_tuple_binding_values() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    // let (a, mut b): (u8, bool) = (1, true)
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("b", true)],
+        Some(ast::Type::Tuple(vec![
+            ast::Type::Primitive(ast::PrimitiveTypes::U8),
+            ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+        ])),
+        tuple_expr(vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::Bool(true)),
+        ]),
+    );
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let a = block_state.borrow().get_value_name(&"a".into()).unwrap();
+    assert_eq!(a.inner_name, "a.0".into());
+    assert_eq!(a.inner_type, Type::Primitive(PrimitiveTypes::U8));
+    assert!(!a.mutable);
+    let b = block_state.borrow().get_value_name(&"b".into()).unwrap();
+    assert_eq!(b.inner_name, "b.0".into());
+    assert_eq!(b.inner_type, Type::Primitive(PrimitiveTypes::Bool));
+    assert!(b.mutable);
+
+    let state = block_state.borrow().get_context().clone().get();
+    assert_eq!(state.len(), 5);
+    assert!(matches!(
+        state[0],
+        SemanticStackContext::ExpressionTupleValue { .. }
+    ));
+    assert_eq!(
+        state[3],
+        SemanticStackContext::TupleElement {
+            tuple: ExpressionResult {
+                expr_type: pair_type(),
+                expr_value: ExpressionResultValue::Register(1),
+            },
+            index: 1,
+            element_type: Type::Primitive(PrimitiveTypes::Bool),
+            register_number: 3,
+        }
+    );
+    assert_eq!(
+        state[4],
+        SemanticStackContext::LetBinding {
+            let_decl: b,
+            expr_result: ExpressionResult {
+                expr_type: Type::Primitive(PrimitiveTypes::Bool),
+                expr_value: ExpressionResultValue::Register(3),
+            },
+        }
+    );
+
+    // Shadowed value has own inner name: let (a, c) = (a, 2)
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("c", false)],
+        None,
+        tuple_expr(vec![name_expr("a"), value_expr(ast::PrimitiveValue::U8(2))]),
+    );
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let a = block_state.borrow().get_value_name(&"a".into()).unwrap();
+    assert_eq!(a.inner_name, "a.1".into());
+    assert!(block_state
+        .borrow()
+        .inner_values_name
+        .contains(&"a.0".into()));
+}
+
+#[test]
+fn let_tuple_binding_errors() {
+    let block_state = Rc::new(RefCell::new(BlockState::new(None)));
+    let mut t = SemanticTest::new();
+    let pair = || {
+        tuple_expr(vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::Bool(true)),
+        ])
+    };
+
+    // let (a) = (1, true)
+    let data = let_tuple_binding(vec![tuple_name("a", false)], None, pair());
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TuplePatternArityMismatch));
+    t.clean_errors();
+
+    // let (a, a) = (1, true)
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("a", true)],
+        None,
+        pair(),
+    );
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TuplePatternDuplicateName));
+    t.clean_errors();
+
+    // let (a, b) = 1
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("b", false)],
+        None,
+        value_expr(ast::PrimitiveValue::U8(1)),
+    );
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::ValueNotTuple));
+    t.clean_errors();
+
+    // let (a, b): (u8, u8) = (1, true)
+    let data = let_tuple_binding(
+        vec![tuple_name("a", false), tuple_name("b", false)],
+        Some(ast::Type::Tuple(vec![
+            ast::Type::Primitive(ast::PrimitiveTypes::U8),
+            ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        ])),
+        pair(),
+    );
+    t.state.let_tuple_binding(&data, &block_state);
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::WrongLetType));
+
+    // No values declared for invalid bindings
+    assert!(block_state.borrow().values.is_empty());
+}
+
+#[test]
+fn let_tuple_binding_function_result() {
+    let mut t = SemanticTest::new();
+    let pair_ty = ast::Type::Tuple(vec![
+        ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+    ]);
+    // fn pair() -> (u8, bool) { (1, true) }
+    let pair_fn = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("pair")),
+        vec![],
+        pair_ty,
+        vec![ast::BodyStatement::Expression(tuple_expr(vec![
+            value_expr(ast::PrimitiveValue::U8(1)),
+            value_expr(ast::PrimitiveValue::Bool(true)),
+        ]))],
+    );
+    // fn main() -> bool { let (a, b) = pair(); b }
+    let main_fn = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("main")),
+        vec![],
+        ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+        vec![
+            ast::BodyStatement::LetTupleBinding(let_tuple_binding(
+                vec![tuple_name("a", false), tuple_name("b", false)],
+                None,
+                ast::Expression {
+                    expression_value: ast::ExpressionValue::FunctionCall(ast::FunctionCall {
+                        name: ast::FunctionName::new(Ident::new("pair")),
+                        parameters: vec![],
+                    }),
+                    operation: None,
+                },
+            )),
+            ast::BodyStatement::Expression(name_expr("b")),
+        ],
+    );
+    let main_stm: ast::Main<
+        CustomExpressionInstruction,
+        CustomExpression<CustomExpressionInstruction>,
+    > = vec![
+        ast::MainStatement::Function(pair_fn),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let pair = t
+        .state
+        .global
+        .functions
+        .get(&String::from("pair").into())
+        .unwrap();
+    assert_eq!(pair.inner_type, pair_type());
+}
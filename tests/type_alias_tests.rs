@@ -0,0 +1,314 @@
+use crate::utils::fixtures::{
+    call_expr, function, let_stm, name_expr, param, value_expr, TestMain,
+};
+use crate::utils::SemanticTest;
+use semantic_analyzer::ast::{self, CodeLocation, GetLocation, GetName, Ident};
+use semantic_analyzer::types::error::StateErrorKind;
+use semantic_analyzer::types::types::{PrimitiveTypes, StructTypes, Type, TypeClass};
+use semantic_analyzer::types::FunctionName;
+use std::collections::HashMap;
+
+mod utils;
+
+fn f64_type() -> ast::Type<'static> {
+    ast::Type::Primitive(ast::PrimitiveTypes::F64)
+}
+
+/// Type referred by name: declared struct type or type alias
+fn named_type(name: &'static str) -> ast::Type<'static> {
+    ast::Type::Struct(ast::StructTypes {
+        name: Ident::new(name),
+        attributes: vec![],
+    })
+}
+
+/// `type name = alias_type;`
+fn type_alias(name: &'static str, alias_type: ast::Type<'static>) -> ast::TypeAlias<'static> {
+    ast::TypeAlias {
+        name: Ident::new(name),
+        alias_type,
+    }
+}
+
+fn meters() -> Type {
+    Type::Alias(
+        "Meters".to_string(),
+        Box::new(Type::Primitive(PrimitiveTypes::F64)),
+    )
+}
+
+#[test]
+fn type_alias_transform() {
+    let data = type_alias("Meters", f64_type());
+    assert_eq!(data.name(), "Meters");
+    assert_eq!(data.location(), CodeLocation::new(1, 0));
+
+    let ty = meters();
+    // For grcov
+    let _ = format!("{ty:?}");
+    assert_eq!(ty.to_string(), "Meters");
+    assert_eq!(ty.name(), "Meters".to_string().into());
+    assert_eq!(ty.type_class(), TypeClass::Float);
+    assert_eq!(ty, Type::Primitive(PrimitiveTypes::F64));
+    assert_eq!(Type::Primitive(PrimitiveTypes::F64), ty);
+    assert_ne!(ty, Type::Primitive(PrimitiveTypes::F32));
+
+    // Alias of alias
+    let km = Type::Alias("Km".to_string(), Box::new(ty.clone()));
+    assert_eq!(km, ty);
+    assert_eq!(km.unalias(), &Type::Primitive(PrimitiveTypes::F64));
+    let array = Type::Array(Box::new(km), 2);
+    assert_eq!(array.to_string(), "[\"Km\";2]");
+    assert_eq!(
+        array.without_aliases().to_string(),
+        Type::Array(Box::new(Type::Primitive(PrimitiveTypes::F64)), 2).to_string()
+    );
+
+    let point = StructTypes {
+        name: "Point".to_string(),
+        attributes: HashMap::new(),
+        methods: HashMap::new(),
+        instance: None,
+    };
+    let ty = Type::Alias("P".to_string(), Box::new(Type::Struct(point.clone())));
+    assert_eq!(ty.get_struct(), Some(point));
+    assert_eq!(ty.type_class(), TypeClass::Aggregate);
+    let ty = Type::Alias(
+        "Ptr".to_string(),
+        Box::new(Type::Pointer(Box::new(meters()))),
+    );
+    assert!(ty.is_mutable_pointer());
+    assert_eq!(ty.get_pointee(), Some(meters()));
+}
+
+#[test]
+fn type_alias_declaration() {
+    let mut t = SemanticTest::new();
+    t.state.type_alias(&type_alias("Meters", f64_type()));
+    // type Track = [Meters; 3];
+    t.state.type_alias(&type_alias(
+        "Track",
+        ast::Type::Array(Box::new(named_type("Meters")), 3),
+    ));
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    assert_eq!(t.state.global.types[&"Meters".to_string().into()], meters());
+    let track = &t.state.global.types[&"Track".to_string().into()];
+    assert_eq!(track.to_string(), "Track");
+    let Type::Array(element_type, 3) = track.unalias() else {
+        panic!("expected array type");
+    };
+    assert_eq!(element_type.to_string(), "Meters");
+    // Alias isn't declared in global context
+    assert!(t.state.global.context.clone().get().is_empty());
+
+    t.state.type_alias(&type_alias("Meters", f64_type()));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeAlreadyExist));
+    t.clean_errors();
+
+    t.state.types(&ast::StructTypes {
+        name: Ident::new("Meters"),
+        attributes: vec![],
+    });
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeAlreadyExist));
+    t.clean_errors();
+
+    // Aliased type not declared
+    t.state.type_alias(&type_alias(
+        "Path",
+        ast::Type::Array(Box::new(named_type("Point")), 2),
+    ));
+    assert!(t.check_errors_len(1), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error(StateErrorKind::TypeNotFound));
+    assert!(!t
+        .state
+        .global
+        .types
+        .contains_key(&"Path".to_string().into()));
+}
+
+#[test]
+fn type_alias_transparent() {
+    let mut t = SemanticTest::new();
+    // fn fn1(x: Meters, track: Track) -> f64 {
+    //     let y: f64 = x; let z: Meters = track[1]; -z
+    // }
+    let fn1 = function(
+        "fn1",
+        vec![
+            param("x", named_type("Meters")),
+            param("track", named_type("Track")),
+        ],
+        f64_type(),
+        vec![
+            let_stm("y", Some(f64_type()), name_expr("x")),
+            let_stm(
+                "z",
+                Some(named_type("Meters")),
+                ast::Expression {
+                    expression_value: ast::ExpressionValue::ArrayElement(
+                        ast::ExpressionArrayElement {
+                            array: Box::new(name_expr("track")),
+                            index: Box::new(value_expr(ast::PrimitiveValue::U32(1))),
+                        },
+                    ),
+                    operation: None,
+                },
+            ),
+            ast::BodyStatement::Expression(ast::Expression {
+                expression_value: ast::ExpressionValue::UnaryOperation(
+                    ast::ExpressionUnaryOperation {
+                        operation: ast::UnaryOperations::Minus,
+                        expression: Box::new(name_expr("z")),
+                    },
+                ),
+                operation: None,
+            }),
+        ],
+    );
+    // fn fn2(p: P) -> u8 { p.x }
+    let fn2 = function(
+        "fn2",
+        vec![param("p", named_type("P"))],
+        ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        vec![ast::BodyStatement::Expression(ast::Expression {
+            expression_value: ast::ExpressionValue::StructValue(ast::ExpressionStructValue {
+                name: ast::ValueName::new(Ident::new("p")),
+                attribute: ast::ValueName::new(Ident::new("x")),
+                path: vec![],
+            }),
+            operation: None,
+        })],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::TypeAlias(type_alias("Meters", f64_type())),
+        ast::MainStatement::TypeAlias(type_alias(
+            "Track",
+            ast::Type::Array(Box::new(named_type("Meters")), 3),
+        )),
+        ast::MainStatement::Types(ast::StructTypes {
+            name: Ident::new("Point"),
+            attributes: vec![ast::StructType {
+                attr_name: Ident::new("x"),
+                attr_type: ast::Type::Primitive(ast::PrimitiveTypes::U8),
+            }],
+        }),
+        ast::MainStatement::TypeAlias(type_alias("P", named_type("Point"))),
+        ast::MainStatement::Function(fn1),
+        ast::MainStatement::Function(fn2),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let fn1 = &t.state.global.functions[&String::from("fn1").into()];
+    assert_eq!(fn1.parameters[0].to_string(), "Meters");
+    assert_eq!(fn1.parameters[1].to_string(), "Track");
+    let fn2 = &t.state.global.functions[&String::from("fn2").into()];
+    assert_eq!(fn2.parameters[0].to_string(), "P");
+    assert!(fn2.parameters[0].get_struct().is_some());
+}
+
+#[test]
+fn type_alias_diagnostics() {
+    let mut t = SemanticTest::new();
+    // fn fn1(x: Meters) -> bool { !x }
+    let fn1 = function(
+        "fn1",
+        vec![param("x", named_type("Meters"))],
+        ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+        vec![ast::BodyStatement::Expression(ast::Expression {
+            expression_value: ast::ExpressionValue::UnaryOperation(ast::ExpressionUnaryOperation {
+                operation: ast::UnaryOperations::Not,
+                expression: Box::new(name_expr("x")),
+            }),
+            operation: None,
+        })],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::TypeAlias(type_alias("Meters", f64_type())),
+        ast::MainStatement::Function(fn1),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.check_error(StateErrorKind::UnaryOperationWrongType));
+    assert_eq!(t.state.errors[0].value, "Meters");
+}
+
+#[test]
+fn type_alias_generic_instantiation() {
+    let mut t = SemanticTest::new();
+    // fn id<T>(x: T) -> T { x }
+    let id_fn = function(
+        "id",
+        vec![param(
+            "x",
+            ast::Type::Parameter(ast::TypeParameterName::new(Ident::new("T"))),
+        )],
+        ast::Type::Parameter(ast::TypeParameterName::new(Ident::new("T"))),
+        vec![ast::BodyStatement::Expression(name_expr("x"))],
+    )
+    .with_type_parameters(vec![ast::TypeParameterName::new(Ident::new("T"))]);
+    // fn main(x: Meters, y: f64) -> f64 { id(x); id(y) }
+    let main_fn = function(
+        "main",
+        vec![param("x", named_type("Meters")), param("y", f64_type())],
+        f64_type(),
+        vec![
+            ast::BodyStatement::FunctionCall(ast::FunctionCall {
+                name: ast::FunctionName::new(Ident::new("id")),
+                parameters: vec![name_expr("x")],
+            }),
+            ast::BodyStatement::Expression(call_expr("id", vec![name_expr("y")])),
+        ],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::TypeAlias(type_alias("Meters", f64_type())),
+        ast::MainStatement::Function(id_fn),
+        ast::MainStatement::Function(main_fn),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    // Aliased type has the same instantiation
+    assert_eq!(t.state.global.instantiations.len(), 1);
+    let id: FunctionName = String::from("id<f64>").into();
+    assert!(t.state.global.instantiations.contains_key(&id));
+}
+
+#[test]
+fn type_alias_cycle() {
+    let mut t = SemanticTest::new();
+    // type A = B; type B = A; type C = *C;
+    // type NodePtr = *Node; struct Node { next: NodePtr }
+    let main_stm: TestMain = vec![
+        ast::MainStatement::TypeAlias(type_alias("A", named_type("B"))),
+        ast::MainStatement::TypeAlias(type_alias("B", named_type("A"))),
+        ast::MainStatement::TypeAlias(type_alias(
+            "C",
+            ast::Type::Pointer(Box::new(named_type("C"))),
+        )),
+        ast::MainStatement::TypeAlias(type_alias(
+            "NodePtr",
+            ast::Type::Pointer(Box::new(named_type("Node"))),
+        )),
+        ast::MainStatement::Types(ast::StructTypes {
+            name: Ident::new("Node"),
+            attributes: vec![ast::StructType {
+                attr_name: Ident::new("next"),
+                attr_type: named_type("NodePtr"),
+            }],
+        }),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors);
+    assert!(t.check_error_index(0, StateErrorKind::TypeAliasCycle));
+    assert!(t.check_error_index(1, StateErrorKind::TypeAliasCycle));
+    assert_eq!(t.state.errors[0].value, "A -> B -> A");
+    assert_eq!(t.state.errors[1].value, "C -> C");
+    for name in ["A", "B", "C"] {
+        assert!(!t.state.global.types.contains_key(&name.to_string().into()));
+    }
+    assert!(t
+        .state
+        .global
+        .types
+        .contains_key(&"NodePtr".to_string().into()));
+}
@@ -1,9 +1,11 @@
+use crate::utils::fixtures::{let_stm, name_expr, param, value_expr, TestMain};
 use crate::utils::SemanticTest;
 use semantic_analyzer::ast::{self, GetLocation, GetName, Ident};
 use semantic_analyzer::types::error::StateErrorKind;
 use semantic_analyzer::types::semantic::SemanticStackContext;
 use semantic_analyzer::types::types::{
-    PrimitiveTypes, StructAttributeType, StructTypes, Type, TypeAttributes,
+    CastKind, EnumTypes, EnumVariantType, PrimitiveTypes, StructAttributeType, StructTypes, Type,
+    TypeAttributes, TypeClass,
 };
 use semantic_analyzer::types::ValueName;
 
@@ -349,3 +351,254 @@ fn code_location() {
     assert_eq!(x.line(), 10);
     assert_eq!(x.offset(), 100);
 }
+
+#[test]
+fn primitive_types_cast_kind() {
+    use PrimitiveTypes as P;
+    // Integers
+    assert_eq!(P::U8.cast_kind(&P::I64), Some(CastKind::IntegerExtend));
+    assert_eq!(P::I16.cast_kind(&P::U32), Some(CastKind::IntegerExtend));
+    assert_eq!(P::I64.cast_kind(&P::U8), Some(CastKind::IntegerTruncate));
+    assert_eq!(P::I32.cast_kind(&P::U32), Some(CastKind::NoOp));
+    assert_eq!(P::U64.cast_kind(&P::U64), Some(CastKind::NoOp));
+    // Floats
+    assert_eq!(P::F32.cast_kind(&P::F64), Some(CastKind::FloatExtend));
+    assert_eq!(P::F64.cast_kind(&P::F32), Some(CastKind::FloatTruncate));
+    assert_eq!(P::F64.cast_kind(&P::F64), Some(CastKind::NoOp));
+    // Integer and float
+    assert_eq!(P::I32.cast_kind(&P::F64), Some(CastKind::IntegerToFloat));
+    assert_eq!(P::F32.cast_kind(&P::U8), Some(CastKind::FloatToInteger));
+    // Bool and char
+    assert_eq!(P::Bool.cast_kind(&P::U8), Some(CastKind::BoolToInteger));
+    assert_eq!(P::Char.cast_kind(&P::U32), Some(CastKind::CharToInteger));
+    assert_eq!(P::U8.cast_kind(&P::Char), Some(CastKind::IntegerToChar));
+    assert_eq!(P::Bool.cast_kind(&P::Bool), Some(CastKind::NoOp));
+    assert_eq!(P::Char.cast_kind(&P::Char), Some(CastKind::NoOp));
+    assert_eq!(P::Ptr.cast_kind(&P::Ptr), Some(CastKind::NoOp));
+    // Invalid casts
+    assert_eq!(P::U32.cast_kind(&P::Char), None);
+    assert_eq!(P::I32.cast_kind(&P::Bool), None);
+    assert_eq!(P::F32.cast_kind(&P::Bool), None);
+    assert_eq!(P::Bool.cast_kind(&P::F32), None);
+    assert_eq!(P::Char.cast_kind(&P::F32), None);
+    assert_eq!(P::Ptr.cast_kind(&P::U64), None);
+    assert_eq!(P::None.cast_kind(&P::None), None);
+    assert_eq!(P::U8.cast_kind(&P::None), None);
+}
+
+#[test]
+fn enum_types_declaration() {
+    let mut t = SemanticTest::new();
+    // enum Opt { None, Some(i32, bool) }
+    let type_decl = ast::EnumTypes {
+        name: Ident::new("Opt"),
+        variants: vec![
+            ast::EnumVariant {
+                name: Ident::new("None"),
+                payload: vec![],
+            },
+            ast::EnumVariant {
+                name: Ident::new("Some"),
+                payload: vec![
+                    ast::Type::Primitive(ast::PrimitiveTypes::I32),
+                    ast::Type::Primitive(ast::PrimitiveTypes::Bool),
+                ],
+            },
+        ],
+    };
+    assert_eq!(type_decl.name(), "Opt");
+    assert_eq!(type_decl.location().offset(), 0);
+    assert_eq!(type_decl.variants[1].name(), "Some");
+    assert_eq!(type_decl.variants[1].location().offset(), 0);
+    let ast_ty = ast::Type::Enum(type_decl.clone());
+    assert_eq!(ast_ty.name(), "Opt");
+
+    let enum_type: EnumTypes = type_decl.clone().into();
+    assert_eq!(
+        enum_type.variants,
+        vec![
+            EnumVariantType {
+                name: "None".to_string(),
+                discriminant: 0,
+                payload: vec![],
+            },
+            EnumVariantType {
+                name: "Some".to_string(),
+                discriminant: 1,
+                payload: vec![
+                    Type::Primitive(PrimitiveTypes::I32),
+                    Type::Primitive(PrimitiveTypes::Bool),
+                ],
+            },
+        ]
+    );
+    assert_eq!(enum_type.get_variant("Some").unwrap().discriminant, 1);
+    assert!(enum_type.get_variant("Other").is_none());
+    assert_eq!(enum_type.discriminant_type(), PrimitiveTypes::U8);
+    let ty: Type = ast_ty.into();
+    assert_eq!(ty, Type::Enum(enum_type.clone()));
+    assert_eq!(ty.to_string(), "Opt");
+    assert_eq!(ty.get_enum(), Some(enum_type.clone()));
+    assert!(ty.get_struct().is_none());
+    assert_eq!(ty.type_class(), TypeClass::Aggregate);
+    assert!(!ty.is_attribute(&ValueName::from("None".to_string())));
+
+    t.state.enum_types(&type_decl);
+    assert!(t.is_empty_error());
+    assert_eq!(t.state.global.types.get(&ty.name()), Some(&ty));
+    let state = t.state.global.context.clone().get();
+    assert_eq!(state.len(), 1);
+    assert_eq!(
+        state[0],
+        SemanticStackContext::EnumTypes {
+            type_decl: enum_type
+        }
+    );
+
+    // Type already exists
+    t.state.enum_types(&type_decl);
+    // Variant already exists
+    let mut type_decl2 = type_decl;
+    type_decl2.name = Ident::new("Opt2");
+    type_decl2.variants.push(type_decl2.variants[0].clone());
+    t.state.enum_types(&type_decl2);
+    assert!(t.check_errors_len(2), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(0, StateErrorKind::TypeAlreadyExist));
+    assert!(t.check_error_index(1, StateErrorKind::EnumVariantAlreadyExist));
+    assert_eq!(t.state.errors[1].value, "Opt2::None");
+    assert_eq!(t.state.global.context.clone().get().len(), 1);
+
+    // Variant payload type not found
+    let type_decl3 = ast::EnumTypes {
+        name: Ident::new("Opt3"),
+        variants: vec![ast::EnumVariant {
+            name: Ident::new("Some"),
+            payload: vec![ast::Type::Struct(ast::StructTypes {
+                name: Ident::new("Missing"),
+                attributes: vec![],
+            })],
+        }],
+    };
+    t.state.enum_types(&type_decl3);
+    assert!(t.check_errors_len(3), "Errors: {:?}", t.state.errors.len());
+    assert!(t.check_error_index(2, StateErrorKind::TypeNotFound));
+    assert_eq!(t.state.errors[2].value, "Opt3::Some");
+    assert!(!t
+        .state
+        .global
+        .types
+        .contains_key(&"Opt3".to_string().into()));
+    assert_eq!(t.state.global.context.clone().get().len(), 1);
+}
+
+#[test]
+fn enum_types_by_name() {
+    let mut t = SemanticTest::new();
+    // enum E { A, B(u8) }
+    let type_decl = ast::EnumTypes {
+        name: Ident::new("E"),
+        variants: vec![
+            ast::EnumVariant {
+                name: Ident::new("A"),
+                payload: vec![],
+            },
+            ast::EnumVariant {
+                name: Ident::new("B"),
+                payload: vec![ast::Type::Primitive(ast::PrimitiveTypes::U8)],
+            },
+        ],
+    };
+    let enum_by_name = ast::Type::Struct(ast::StructTypes {
+        name: Ident::new("E"),
+        attributes: vec![],
+    });
+    // fn fn1(p: E) -> E { let x: E = E::B(1); p }
+    let fn1 = ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new("fn1")),
+        vec![param("p", enum_by_name.clone())],
+        enum_by_name.clone(),
+        vec![
+            let_stm(
+                "x",
+                Some(enum_by_name),
+                ast::Expression {
+                    expression_value: ast::ExpressionValue::EnumVariant(
+                        ast::ExpressionEnumVariant {
+                            name: Ident::new("E"),
+                            variant: Ident::new("B"),
+                            payload: vec![value_expr(ast::PrimitiveValue::U8(1))],
+                        },
+                    ),
+                    operation: None,
+                },
+            ),
+            ast::BodyStatement::Expression(name_expr("p")),
+        ],
+    );
+    let main_stm: TestMain = vec![
+        ast::MainStatement::Enum(type_decl.clone()),
+        ast::MainStatement::Function(fn1),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+    let enum_type: EnumTypes = type_decl.into();
+    let fn1 = &t.state.global.functions[&String::from("fn1").into()];
+    assert_eq!(fn1.parameters[0], Type::Enum(enum_type.clone()));
+    assert_eq!(fn1.inner_type, Type::Enum(enum_type.clone()));
+    let x = t.state.context[0]
+        .borrow()
+        .get_value_name(&String::from("x").into())
+        .unwrap();
+    assert_eq!(x.inner_type, Type::Enum(enum_type));
+}
+
+#[test]
+fn types_declared_later() {
+    let mut t = SemanticTest::new();
+    let type_by_name = |name| {
+        ast::Type::Struct(ast::StructTypes {
+            name: Ident::new(name),
+            attributes: vec![],
+        })
+    };
+    // type Alias = E;
+    let alias_decl = ast::TypeAlias {
+        name: Ident::new("Alias"),
+        alias_type: type_by_name("E"),
+    };
+    // enum E { A(S) }
+    let enum_decl = ast::EnumTypes {
+        name: Ident::new("E"),
+        variants: vec![ast::EnumVariant {
+            name: Ident::new("A"),
+            payload: vec![type_by_name("S")],
+        }],
+    };
+    // struct S { x: u8 }
+    let struct_decl = ast::StructTypes {
+        name: Ident::new("S"),
+        attributes: vec![ast::StructType {
+            attr_name: Ident::new("x"),
+            attr_type: ast::Type::Primitive(ast::PrimitiveTypes::U8),
+        }],
+    };
+    let main_stm: TestMain = vec![
+        ast::MainStatement::TypeAlias(alias_decl),
+        ast::MainStatement::Enum(enum_decl),
+        ast::MainStatement::Types(struct_decl.clone()),
+    ];
+    t.state.run(&main_stm);
+    assert!(t.is_empty_error(), "Errors: {:?}", t.state.errors);
+
+    let struct_type = Type::Struct(struct_decl.into());
+    let enum_type = &t.state.global.types[&"E".to_string().into()];
+    assert_eq!(
+        enum_type.get_enum().unwrap().variants[0].payload,
+        vec![struct_type.clone()]
+    );
+    let Type::Alias(_, alias_type) = &t.state.global.types[&"Alias".to_string().into()] else {
+        panic!("expected type alias");
+    };
+    assert_eq!(alias_type.get_enum(), enum_type.get_enum());
+    assert_eq!(t.state.global.types[&"S".to_string().into()], struct_type);
+}
@@ -0,0 +1,146 @@
+//! AST fixtures shared by tests.
+
+use super::{CustomExpression, CustomExpressionInstruction, SemanticTest};
+use semantic_analyzer::ast::{self, Ident};
+use semantic_analyzer::types::types::Type;
+
+pub type TestExpression = ast::Expression<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+pub type TestBodyStatement = ast::BodyStatement<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+pub type TestLetBinding = ast::LetBinding<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+pub type TestFunction = ast::FunctionStatement<
+    'static,
+    CustomExpressionInstruction,
+    CustomExpression<CustomExpressionInstruction>,
+>;
+pub type TestMain =
+    ast::Main<'static, CustomExpressionInstruction, CustomExpression<CustomExpressionInstruction>>;
+
+/// Primitive value expression
+pub fn value_expr(value: ast::PrimitiveValue) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::PrimitiveValue(value),
+        operation: None,
+    }
+}
+
+/// Value name expression
+pub fn name_expr(name: &'static str) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::ValueName(ast::ValueName::new(Ident::new(name))),
+        operation: None,
+    }
+}
+
+/// Let binding: `let [mut] name[: value_type] = value`
+pub fn let_binding(
+    name: &'static str,
+    mutable: bool,
+    value_type: Option<ast::Type<'static>>,
+    value: TestExpression,
+) -> TestLetBinding {
+    ast::LetBinding {
+        name: ast::ValueName::new(Ident::new(name)),
+        mutable,
+        value_type,
+        value: Box::new(value),
+    }
+}
+
+/// Immutable let binding function body statement
+pub fn let_stm(
+    name: &'static str,
+    value_type: Option<ast::Type<'static>>,
+    value: TestExpression,
+) -> TestBodyStatement {
+    ast::BodyStatement::LetBinding(let_binding(name, false, value_type, value))
+}
+
+/// Function parameter: `name: ty`
+pub fn param(name: &'static str, ty: ast::Type<'static>) -> ast::FunctionParameter<'static> {
+    ast::FunctionParameter {
+        name: ast::ParameterName::new(Ident::new(name)),
+        parameter_type: ty,
+    }
+}
+
+/// Primitive type: `u8`
+pub fn u8_type() -> ast::Type<'static> {
+    ast::Type::Primitive(ast::PrimitiveTypes::U8)
+}
+
+/// Type parameter type: `name`
+pub fn type_param(name: &'static str) -> ast::Type<'static> {
+    ast::Type::Parameter(ast::TypeParameterName::new(Ident::new(name)))
+}
+
+/// Function: `fn name(parameters) -> result_type { body }`
+pub fn function(
+    name: &'static str,
+    parameters: Vec<ast::FunctionParameter<'static>>,
+    result_type: ast::Type<'static>,
+    body: Vec<TestBodyStatement>,
+) -> TestFunction {
+    ast::FunctionStatement::new(
+        ast::FunctionName::new(Ident::new(name)),
+        parameters,
+        result_type,
+        body,
+    )
+}
+
+/// Function call expression: `name(parameters)`
+pub fn call_expr(name: &'static str, parameters: Vec<TestExpression>) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::FunctionCall(ast::FunctionCall {
+            name: ast::FunctionName::new(Ident::new(name)),
+            parameters,
+        }),
+        operation: None,
+    }
+}
+
+/// Indirect call expression: `callee(parameters)`
+pub fn indirect_call_expr(
+    callee: TestExpression,
+    parameters: Vec<TestExpression>,
+) -> TestExpression {
+    ast::Expression {
+        expression_value: ast::ExpressionValue::IndirectCall(ast::ExpressionIndirectCall {
+            callee: Box::new(callee),
+            parameters,
+        }),
+        operation: None,
+    }
+}
+
+/// Declare `enum Opt { None, Some(u8) }` type
+pub fn set_enum_type(t: &mut SemanticTest<CustomExpressionInstruction>) -> Type {
+    let e_ty = ast::EnumTypes {
+        name: Ident::new("Opt"),
+        variants: vec![
+            ast::EnumVariant {
+                name: Ident::new("None"),
+                payload: vec![],
+            },
+            ast::EnumVariant {
+                name: Ident::new("Some"),
+                payload: vec![ast::Type::Primitive(ast::PrimitiveTypes::U8)],
+            },
+        ],
+    };
+    t.state.enum_types(&e_ty);
+    assert!(t.is_empty_error());
+    Type::Enum(e_ty.into())
+}
@@ -78,3 +78,7 @@ impl SemanticTest<CustomExpressionInstruction> {
         self.state.errors.get(index).unwrap().kind == err_kind
     }
 }
+
+/// Each test crate uses only part of fixtures.
+#[allow(dead_code)]
+pub mod fixtures;